@@ -0,0 +1,78 @@
+//! Pointer based `LinkedList` against index based `ArenaList`.
+//!
+//! Run with `cargo bench --bench arena_list`, every row is the best of a few rounds.
+
+use dsa_sport::datastruct::arena_struct::ArenaList;
+use dsa_sport::datastruct::list_struct::LinkedList;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 5;
+
+fn best_of<F: FnMut() -> u64>(mut f: F) -> (Duration, u64) {
+    let mut best = Duration::MAX;
+    let mut check = 0;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        check = f();
+        best = best.min(start.elapsed());
+    }
+    (best, check)
+}
+
+fn row(name: &str, pointer: (Duration, u64), arena: (Duration, u64)) {
+    assert_eq!(pointer.1, arena.1, "{} computed different results", name);
+    println!(
+        "| {:<28} | {:>12.3?} | {:>12.3?} |",
+        name, pointer.0, arena.0
+    );
+}
+
+fn main() {
+    for &n in [10_000u64, 100_000, 1_000_000].iter() {
+        println!("\nn = {}", n);
+        println!(
+            "| {:<28} | {:>12} | {:>12} |",
+            "operation", "LinkedList", "ArenaList"
+        );
+        println!("|{:-<30}|{:-<14}|{:-<14}|", "", "", "");
+
+        row(
+            "build",
+            best_of(|| (0..n).collect::<LinkedList<u64>>().iter().count() as u64),
+            best_of(|| (0..n).collect::<ArenaList<u64>>().iter().count() as u64),
+        );
+
+        let pointer: LinkedList<u64> = (0..n).collect();
+        let arena: ArenaList<u64> = (0..n).collect();
+        row(
+            "iterate and sum",
+            best_of(|| pointer.iter().sum()),
+            best_of(|| arena.iter().sum()),
+        );
+
+        row(
+            "insert after every member",
+            best_of(|| {
+                let mut list: LinkedList<u64> = (0..n).collect();
+                let mut cursor = list.cursor_front_mut();
+                while let Some(x) = cursor.current() {
+                    let copy = *x;
+                    cursor.insert_after(copy);
+                    cursor.move_next();
+                    cursor.move_next();
+                }
+                list.iter().sum()
+            }),
+            best_of(|| {
+                let mut list: ArenaList<u64> = (0..n).collect();
+                let mut handle = list.front();
+                while let Some(h) = handle {
+                    let copy = *list.get(h).unwrap();
+                    let inserted = list.insert_after(h, copy).unwrap();
+                    handle = list.next(inserted);
+                }
+                list.iter().sum()
+            }),
+        );
+    }
+}
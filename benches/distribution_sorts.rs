@@ -0,0 +1,125 @@
+//! Counting and radix sort against comparison sorts, to find where O(n + k) stops beating
+//! O(n log n).
+//!
+//! Run with `cargo bench --bench distribution_sorts`, every cell is the best of a few rounds.
+//! The first table keeps n fixed and widens the key range k, counting sort pays for its k slots
+//! and loses once k is far beyond n. The second table grows n with keys of the full `u32` range,
+//! where radix sort pays a fixed number of passes and wins once log n exceeds them.
+
+use dsa_sport::prng::XorShift64;
+use dsa_sport::sort_analysis::counting_sort::counting_sort;
+use dsa_sport::sort_analysis::pigeonhole_sort::pigeonhole_sort;
+use dsa_sport::sort_analysis::quick_sort::quick_sort;
+use dsa_sport::sort_analysis::radix_sort::{lsd_radix_sort_with, msd_radix_sort};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 3;
+
+type Sort = (&'static str, fn(&mut [u32]));
+
+/// best time of sorting a fresh copy of `input`, checked against `sort_unstable`
+fn best_of(input: &[u32], expected: &[u32], name: &str, sort: fn(&mut [u32])) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let mut v = input.to_vec();
+        let start = Instant::now();
+        sort(black_box(&mut v));
+        best = best.min(start.elapsed());
+        assert_eq!(v, expected, "{} did not sort", name);
+    }
+    best
+}
+
+/// print one table row per input and the first row where `fast` loses to `slow`
+fn table(
+    title: &str,
+    column: &str,
+    inputs: Vec<(u64, Vec<u32>)>,
+    sorts: &[Sort],
+    fast: usize,
+    slow: usize,
+) {
+    println!("\n{}", title);
+    print!("| {:>10} |", column);
+    for (name, _) in sorts.iter() {
+        print!(" {:>14} |", name);
+    }
+    println!();
+    print!("|{:-<12}|", "");
+    for _ in sorts.iter() {
+        print!("{:-<16}|", "");
+    }
+    println!();
+    let mut first = None;
+    let mut crossover = None;
+    for (x, input) in inputs.iter() {
+        let mut expected = input.clone();
+        expected.sort_unstable();
+        let times: Vec<Duration> = sorts
+            .iter()
+            .map(|&(name, sort)| best_of(input, &expected, name, sort))
+            .collect();
+        print!("| {:>10} |", x);
+        for time in times.iter() {
+            print!(" {:>14.3?} |", time);
+        }
+        println!();
+        let fast_wins = times[fast] < times[slow];
+        match first {
+            None => first = Some(fast_wins),
+            Some(won) if won != fast_wins && crossover.is_none() => crossover = Some(*x),
+            _ => {}
+        }
+    }
+    match crossover {
+        Some(x) => println!(
+            "{} and {} swap places at {} = {}",
+            sorts[fast].0, sorts[slow].0, column, x
+        ),
+        None => println!("{} and {} never swap places", sorts[fast].0, sorts[slow].0),
+    }
+}
+
+fn main() {
+    let mut rng = XorShift64::new(44);
+    let sorts: [Sort; 6] = [
+        ("counting", counting_sort),
+        ("pigeonhole", pigeonhole_sort),
+        ("lsd radix 8", |v| lsd_radix_sort_with(v, 8)),
+        ("msd radix 8", msd_radix_sort),
+        ("quick", quick_sort),
+        ("sort_unstable", <[u32]>::sort_unstable),
+    ];
+
+    let n = 100_000;
+    let inputs = (4..=26)
+        .step_by(2)
+        .map(|log_k| {
+            let k = 1u64 << log_k;
+            let v = (0..n).map(|_| (rng.next_u64() % k) as u32).collect();
+            (k, v)
+        })
+        .collect();
+    table(
+        &format!("n = {}, keys in 0..k", n),
+        "k",
+        inputs,
+        &sorts,
+        0,
+        4,
+    );
+
+    let radix: [Sort; 5] = [
+        ("lsd radix 4", |v| lsd_radix_sort_with(v, 4)),
+        ("lsd radix 8", |v| lsd_radix_sort_with(v, 8)),
+        ("lsd radix 16", |v| lsd_radix_sort_with(v, 16)),
+        ("quick", quick_sort),
+        ("sort_unstable", <[u32]>::sort_unstable),
+    ];
+    let inputs = [100u64, 1_000, 10_000, 100_000, 1_000_000]
+        .iter()
+        .map(|&n| (n, (0..n).map(|_| rng.next_u64() as u32).collect()))
+        .collect();
+    table("random u32 keys", "n", inputs, &radix, 1, 3);
+}
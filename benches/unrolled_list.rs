@@ -0,0 +1,129 @@
+//! `LinkedList`, `Vector` and `UnrolledList` on the same workloads, to show the cache effect of
+//! keeping several elements per member.
+//!
+//! Run with `cargo bench --bench unrolled_list`, every row is the best of a few rounds. `Vector`
+//! has no insertion in the middle, that cell stays empty.
+
+use dsa_sport::datastruct::list_struct::LinkedList;
+use dsa_sport::datastruct::unrolled_struct::UnrolledList;
+use dsa_sport::datastruct::vec_struct::Vector;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 5;
+const B: usize = 64;
+const SAMPLES: u64 = 200;
+
+fn best_of<F: FnMut() -> u64>(mut f: F) -> (Duration, u64) {
+    let mut best = Duration::MAX;
+    let mut check = 0;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        check = black_box(f());
+        best = best.min(start.elapsed());
+    }
+    (best, check)
+}
+
+fn row(
+    name: &str,
+    list: (Duration, u64),
+    vector: Option<(Duration, u64)>,
+    unrolled: (Duration, u64),
+) {
+    assert_eq!(list.1, unrolled.1, "{} computed different results", name);
+    let vector = match vector {
+        Some((time, check)) => {
+            assert_eq!(list.1, check, "{} computed different results", name);
+            format!("{:.3?}", time)
+        }
+        None => String::from("-"),
+    };
+    println!(
+        "| {:<24} | {:>12.3?} | {:>12} | {:>14.3?} |",
+        name, list.0, vector, unrolled.0
+    );
+}
+
+fn main() {
+    for &n in [10_000u64, 100_000].iter() {
+        println!("\nn = {}, B = {}", n, B);
+        println!(
+            "| {:<24} | {:>12} | {:>12} | {:>14} |",
+            "operation", "LinkedList", "Vector", "UnrolledList"
+        );
+        println!("|{:-<26}|{:-<14}|{:-<14}|{:-<16}|", "", "", "", "");
+
+        row(
+            "build",
+            best_of(|| (0..n).collect::<LinkedList<u64>>().iter().count() as u64),
+            Some(best_of(|| {
+                let mut vector = Vector::new();
+                for i in 0..n {
+                    vector.push(i);
+                }
+                vector.len() as u64
+            })),
+            best_of(|| (0..n).collect::<UnrolledList<u64, B>>().len() as u64),
+        );
+
+        let list: LinkedList<u64> = (0..n).collect();
+        let mut vector = Vector::new();
+        for i in 0..n {
+            vector.push(i);
+        }
+        let unrolled: UnrolledList<u64, B> = (0..n).collect();
+        row(
+            "iterate and sum",
+            best_of(|| black_box(&list).iter().sum()),
+            Some(best_of(|| {
+                (0..vector.len())
+                    .map(|i| *black_box(&vector).get(i).unwrap())
+                    .sum()
+            })),
+            best_of(|| black_box(&unrolled).iter().sum()),
+        );
+
+        let indices: Vec<usize> = (0..SAMPLES).map(|i| (i * n / SAMPLES) as usize).collect();
+        row(
+            "index every n/200",
+            best_of(|| {
+                indices
+                    .iter()
+                    .map(|&i| *black_box(&list).get_at(i).unwrap())
+                    .sum()
+            }),
+            Some(best_of(|| {
+                indices
+                    .iter()
+                    .map(|&i| *black_box(&vector).get(i).unwrap())
+                    .sum()
+            })),
+            best_of(|| {
+                indices
+                    .iter()
+                    .map(|&i| *black_box(&unrolled).get(i).unwrap())
+                    .sum()
+            }),
+        );
+
+        row(
+            "200 inserts at n/2",
+            best_of(|| {
+                let mut list: LinkedList<u64> = (0..n).collect();
+                for i in 0..SAMPLES {
+                    list.insert_node_at((n / 2) as usize, i);
+                }
+                list.iter().sum()
+            }),
+            None,
+            best_of(|| {
+                let mut list: UnrolledList<u64, B> = (0..n).collect();
+                for i in 0..SAMPLES {
+                    list.insert((n / 2) as usize, i);
+                }
+                list.iter().sum()
+            }),
+        );
+    }
+}
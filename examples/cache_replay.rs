@@ -0,0 +1,33 @@
+//! Replay a trace file against both caches and print their hit rates.
+//!
+//! Run with `cargo run --example cache_replay -- <trace file> [capacity]`, the file holds one
+//! key per line.
+
+use dsa_sport::cache::{replay_file, LfuCache, LruCache};
+use std::env;
+use std::process;
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let path = match args.next() {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: cache_replay <trace file> [capacity]");
+            process::exit(2);
+        }
+    };
+    let capacity = args.next().and_then(|c| c.parse().ok()).unwrap_or(64);
+
+    print!("lru({}) ", capacity);
+    let mut lru: LruCache<String, ()> = LruCache::new(capacity);
+    if let Err(err) = replay_file(&mut lru, &path) {
+        eprintln!("cannot replay {}: {}", path, err);
+        process::exit(1);
+    }
+    print!("lfu({}) ", capacity);
+    let mut lfu: LfuCache<String, ()> = LfuCache::new(capacity);
+    if let Err(err) = replay_file(&mut lfu, &path) {
+        eprintln!("cannot replay {}: {}", path, err);
+        process::exit(1);
+    }
+}
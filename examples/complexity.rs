@@ -0,0 +1,24 @@
+//! Fit the growth class of every sort on every input distribution and print a markdown table.
+//!
+//! Run with `cargo run --release --example complexity -- [csv file]`. With a file name the raw
+//! measurements are also written as CSV. The quadratic sorts keep the sizes small.
+
+use dsa_sport::sort_analysis::bench::{sorters, Experiment};
+use std::env;
+use std::fs;
+use std::process;
+
+const SIZES: [usize; 5] = [128, 256, 512, 1024, 2048];
+
+fn main() {
+    let report = Experiment::new(&SIZES).run(&sorters());
+    println!("{}", report.to_markdown());
+
+    if let Some(path) = env::args().nth(1) {
+        if let Err(err) = fs::write(&path, report.to_csv()) {
+            eprintln!("cannot write {}: {}", path, err);
+            process::exit(1);
+        }
+        println!("measurements written to {}", path);
+    }
+}
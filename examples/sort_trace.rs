@@ -0,0 +1,53 @@
+//! Trace `bubble_sort_improved` on sorted and shuffled input and draw the end of every pass.
+//!
+//! Run with `cargo run --example sort_trace -- [svg file]`. The sorted input stops after a single
+//! pass without a swap. With a file name the shuffled run is also written as an animated SVG.
+
+use dsa_sport::prng::XorShift64;
+use dsa_sport::sort_analysis::bub_sort::bubble_sort_improved_tracked;
+use dsa_sport::sort_analysis::render::{animated_svg, ascii_frames};
+use dsa_sport::sort_analysis::trace::{record, SortEvent, Trace};
+use std::env;
+use std::fs;
+use std::process;
+
+const ROWS: usize = 6;
+
+fn show(name: &str, trace: &Trace<u32>) {
+    let swaps = trace
+        .events()
+        .iter()
+        .filter(|event| matches!(event, SortEvent::Swap(_, _)))
+        .count();
+    println!(
+        "== {}: {} events, {} swaps, {} passes\n",
+        name,
+        trace.events().len(),
+        swaps,
+        trace.passes()
+    );
+    let frames = ascii_frames(trace, ROWS, |&x| x as f64);
+    for (frame, event) in frames.iter().skip(1).zip(trace.events()) {
+        if *event == SortEvent::PassBoundary {
+            println!("{}\n", frame);
+        }
+    }
+}
+
+fn main() {
+    let mut sorted: Vec<u32> = (1..=12).collect();
+    let mut shuffled = sorted.clone();
+    XorShift64::new(46).shuffle(&mut shuffled);
+
+    show("sorted", &record(&mut sorted, bubble_sort_improved_tracked));
+    let trace = record(&mut shuffled, bubble_sort_improved_tracked);
+    show("shuffled", &trace);
+
+    if let Some(path) = env::args().nth(1) {
+        if let Err(err) = fs::write(&path, animated_svg(&trace, |&x| x as f64, 80)) {
+            eprintln!("cannot write {}: {}", path, err);
+            process::exit(1);
+        }
+        println!("animation written to {}", path);
+    }
+}
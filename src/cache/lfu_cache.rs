@@ -0,0 +1,330 @@
+//! least frequently used cache `LfuCache<K, V>`
+//!
+//!Every entry counts how often it was used, inserting counts as the first use. Keys with the same
+//!count share a frequency bucket, an [`ArenaList`] ordered from the most to the least recently
+//!used key, and the cache remembers the smallest count that has a bucket. A hit moves the key
+//!from bucket `f` to the front of bucket `f + 1`, eviction pops the back of the smallest bucket,
+//!so ties between equally frequent keys go to the least recently used one. Everything is O(1).
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::cache::LfuCache;
+//! let mut cache = LfuCache::new(2);
+//! cache.put("a", 1);
+//! cache.put("b", 2);
+//! assert_eq!(cache.get(&"a"), Some(&1));
+//! assert_eq!(cache.get(&"a"), Some(&1));
+//! cache.put("c", 3);
+//! assert_eq!(cache.get(&"b"), None);
+//! assert_eq!(cache.frequency(&"a"), Some(3));
+//! assert_eq!(cache.frequency(&"c"), Some(1));
+//! ```
+//!
+//! [`ArenaList`]: crate::datastruct::arena_struct::ArenaList
+
+use super::{CacheStats, EvictionCallback, Replay};
+use crate::datastruct::arena_struct::{ArenaList, Handle};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub struct LfuCache<K, V> {
+    capacity: usize,
+    entries: HashMap<K, Entry<V>>,
+    buckets: HashMap<usize, ArenaList<K>>,
+    /// smallest frequency with a bucket, only read by `evict` and so only kept right while the
+    /// cache is full. A removal leaves room, and the insertion that fills it again sets it to 1.
+    min_frequency: usize,
+    stats: CacheStats,
+    on_evict: Option<EvictionCallback<K, V>>,
+}
+
+struct Entry<V> {
+    value: V,
+    frequency: usize,
+    /// member of the key in `buckets[frequency]`
+    handle: Handle,
+}
+
+impl<K, V> LfuCache<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a cache must hold at least one entry");
+        Self {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            buckets: HashMap::new(),
+            min_frequency: 0,
+            stats: CacheStats::default(),
+            on_evict: None,
+        }
+    }
+
+    /// call `callback` with every entry evicted from now on
+    pub fn set_eviction_callback<F: FnMut(K, V) + 'static>(&mut self, callback: F) {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    /// how often a key was used since it was inserted
+    pub fn frequency(&self, key: &K) -> Option<usize> {
+        self.entries.get(key).map(|entry| entry.frequency)
+    }
+
+    /// look a key up in O(1), a hit raises its frequency by one
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if !self.entries.contains_key(key) {
+            self.stats.misses += 1;
+            return None;
+        }
+        self.stats.hits += 1;
+        self.touch(key);
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// look a key up without counting it or changing its frequency
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Insert an entry with frequency one, or update the value of a present key and raise its
+    /// frequency. A full cache evicts its least frequently used entry first. The old value of the
+    /// key is returned.
+    /// ```
+    /// # use dsa_sport::cache::LfuCache;
+    /// let mut cache = LfuCache::new(2);
+    /// cache.put('x', 1);
+    /// assert_eq!(cache.put('x', 2), Some(1));
+    /// cache.put('y', 1);
+    /// cache.put('z', 1);
+    /// assert!(cache.contains_key(&'x') && !cache.contains_key(&'y'));
+    /// ```
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+            let entry = self.entries.get_mut(&key)?;
+            return Some(std::mem::replace(&mut entry.value, value));
+        }
+        if self.entries.len() == self.capacity {
+            self.evict();
+        }
+        let handle = self.buckets.entry(1).or_default().push_front(key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                frequency: 1,
+                handle,
+            },
+        );
+        self.min_frequency = 1;
+        None
+    }
+
+    /// drop an entry without counting it as an eviction
+    /// ```
+    /// # use dsa_sport::cache::LfuCache;
+    /// let mut cache = LfuCache::new(2);
+    /// cache.put('a', 1);
+    /// cache.get(&'a');
+    /// cache.put('b', 2);
+    /// assert_eq!(cache.remove(&'b'), Some(2));
+    /// cache.put('c', 3);
+    /// cache.put('d', 4);
+    /// assert!(cache.contains_key(&'a') && !cache.contains_key(&'c'));
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+        self.unlink(entry.frequency, entry.handle);
+        Some(entry.value)
+    }
+
+    /// move a present key from its bucket to the front of the next one
+    fn touch(&mut self, key: &K) {
+        let entry = match self.entries.get_mut(key) {
+            Some(entry) => entry,
+            None => return,
+        };
+        let frequency = entry.frequency;
+        let handle = entry.handle;
+        entry.frequency += 1;
+        entry.handle = self
+            .buckets
+            .entry(frequency + 1)
+            .or_default()
+            .push_front(key.clone());
+        self.unlink(frequency, handle);
+        if self.min_frequency == frequency && !self.buckets.contains_key(&frequency) {
+            self.min_frequency = frequency + 1;
+        }
+    }
+
+    /// remove a key from its bucket and drop the bucket once it is empty
+    fn unlink(&mut self, frequency: usize, handle: Handle) {
+        if let Some(bucket) = self.buckets.get_mut(&frequency) {
+            bucket.remove(handle);
+            if bucket.is_empty() {
+                self.buckets.remove(&frequency);
+            }
+        }
+    }
+
+    fn evict(&mut self) {
+        let frequency = self.min_frequency;
+        let bucket = match self.buckets.get_mut(&frequency) {
+            Some(bucket) => bucket,
+            None => return,
+        };
+        let key = match bucket.pop_back() {
+            Some(key) => key,
+            None => return,
+        };
+        if bucket.is_empty() {
+            self.buckets.remove(&frequency);
+        }
+        if let Some(entry) = self.entries.remove(&key) {
+            self.stats.evictions += 1;
+            if let Some(callback) = self.on_evict.as_mut() {
+                callback(key, entry.value);
+            }
+        }
+    }
+}
+
+impl<K, V> Replay<K> for LfuCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Default,
+{
+    fn access(&mut self, key: K) -> bool {
+        if self.get(&key).is_some() {
+            return true;
+        }
+        self.put(key, V::default());
+        false
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prng::XorShift64;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn evicts_least_frequent_then_least_recent() {
+        let evicted = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&evicted);
+        let mut cache = LfuCache::new(3);
+        cache.set_eviction_callback(move |k, _| sink.borrow_mut().push(k));
+        for key in "abc".chars() {
+            cache.put(key, ());
+        }
+        cache.get(&'a');
+        cache.get(&'b');
+        cache.put('d', ());
+        cache.put('e', ());
+        cache.get(&'e');
+        cache.put('f', ());
+        // 'e' emptied bucket one, so the older of the twice used 'a' and 'b' goes
+        assert_eq!(*evicted.borrow(), vec!['c', 'd', 'a']);
+        assert_eq!(cache.stats().evictions, 3);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn removing_the_least_frequent_entries_keeps_eviction_right() {
+        let evicted = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&evicted);
+        let mut cache = LfuCache::new(2);
+        cache.set_eviction_callback(move |k, _| sink.borrow_mut().push(k));
+        cache.put('a', ());
+        cache.put('b', ());
+        cache.get(&'a');
+        cache.get(&'b');
+        // both used twice, removing 'a' leaves only the bucket of 'b'
+        cache.remove(&'a');
+        cache.put('c', ());
+        cache.get(&'c');
+        cache.put('d', ());
+        assert_eq!(*evicted.borrow(), vec!['b']);
+        cache.remove(&'d');
+        cache.remove(&'c');
+        assert!(cache.is_empty());
+        cache.put('e', ());
+        cache.put('f', ());
+        cache.get(&'f');
+        cache.put('g', ());
+        assert_eq!(*evicted.borrow(), vec!['b', 'e']);
+    }
+
+    #[test]
+    fn matches_counting_model() {
+        let mut rng = XorShift64::new(23);
+        let mut cache = LfuCache::new(5);
+        // key, frequency and time of the last use, the evicted key minimises (frequency, time)
+        let mut model: Vec<(usize, usize, usize)> = Vec::new();
+        for time in 0..4000 {
+            let key = rng.below(12);
+            match model.iter().position(|&(k, _, _)| k == key) {
+                Some(i) => {
+                    model[i].1 += 1;
+                    model[i].2 = time;
+                    assert!(cache.get(&key).is_some());
+                }
+                None => {
+                    if model.len() == 5 {
+                        let victim = (0..model.len())
+                            .min_by_key(|&i| (model[i].1, model[i].2))
+                            .unwrap();
+                        let (gone, _, _) = model.remove(victim);
+                        assert!(cache.contains_key(&gone));
+                        cache.put(key, time);
+                        assert!(!cache.contains_key(&gone));
+                    } else {
+                        cache.put(key, time);
+                    }
+                    model.push((key, 1, time));
+                }
+            }
+            if time % 97 == 0 {
+                let (k, _, _) = model.remove(0);
+                assert!(cache.remove(&k).is_some());
+            }
+            for &(k, f, _) in model.iter() {
+                assert_eq!(cache.frequency(&k), Some(f));
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! least recently used cache `LruCache<K, V>`
+//!
+//!Entries live in an [`ArenaList`] ordered from the most to the least recently used one, and a
+//!`HashMap` maps every key to the [`Handle`] of its member. A lookup finds the member through the
+//!map and relinks it in front of the head, eviction pops the tail, so `get`, `put` and eviction
+//!are all O(1).
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::cache::LruCache;
+//! let mut cache = LruCache::new(2);
+//! cache.put("a", 1);
+//! cache.put("b", 2);
+//! assert_eq!(cache.get(&"a"), Some(&1));
+//! cache.put("c", 3);
+//! assert_eq!(cache.get(&"b"), None);
+//! assert_eq!(format!("{:?}",cache), format!("{{\"c\": 3, \"a\": 1}}"));
+//! assert_eq!((cache.stats().hits, cache.stats().misses), (1, 1));
+//! ```
+//!
+//! [`ArenaList`]: crate::datastruct::arena_struct::ArenaList
+//! [`Handle`]: crate::datastruct::arena_struct::Handle
+
+use super::{CacheStats, EvictionCallback, Replay};
+use crate::datastruct::arena_struct::{ArenaList, Handle};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub struct LruCache<K, V> {
+    capacity: usize,
+    order: ArenaList<(K, V)>,
+    index: HashMap<K, Handle>,
+    stats: CacheStats,
+    on_evict: Option<EvictionCallback<K, V>>,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a cache must hold at least one entry");
+        Self {
+            capacity,
+            order: ArenaList::new(),
+            index: HashMap::with_capacity(capacity),
+            stats: CacheStats::default(),
+            on_evict: None,
+        }
+    }
+
+    /// call `callback` with every entry evicted from now on
+    /// ```
+    /// # use dsa_sport::cache::LruCache;
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// let evicted = Rc::new(RefCell::new(Vec::new()));
+    /// let sink = Rc::clone(&evicted);
+    /// let mut cache = LruCache::new(1);
+    /// cache.set_eviction_callback(move |k, v| sink.borrow_mut().push((k, v)));
+    /// cache.put(1, 'a');
+    /// cache.put(2, 'b');
+    /// assert_eq!(*evicted.borrow(), vec![(1, 'a')]);
+    /// ```
+    pub fn set_eviction_callback<F: FnMut(K, V) + 'static>(&mut self, callback: F) {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    /// look a key up in O(1), a hit makes it the most recently used entry
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        match self.index.get(key) {
+            Some(&handle) => {
+                self.stats.hits += 1;
+                self.order.move_to_front(handle);
+                self.order.get(handle).map(|(_, v)| v)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// look a key up without counting it or changing the recency order
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        let handle = *self.index.get(key)?;
+        self.order.get(handle).map(|(_, v)| v)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// Insert or update an entry in O(1), it becomes the most recently used one. A full cache
+    /// evicts its least recently used entry first. The old value of the key is returned.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&handle) = self.index.get(&key) {
+            self.order.move_to_front(handle);
+            let (_, old) = self.order.get_mut(handle)?;
+            return Some(std::mem::replace(old, value));
+        }
+        if self.order.len() == self.capacity {
+            self.evict();
+        }
+        let handle = self.order.push_front((key.clone(), value));
+        self.index.insert(key, handle);
+        None
+    }
+
+    /// drop an entry without counting it as an eviction
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let handle = self.index.remove(key)?;
+        self.order.remove(handle).map(|(_, v)| v)
+    }
+
+    /// entries from the most to the least recently used one
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.order.iter().map(|(k, v)| (k, v))
+    }
+
+    fn evict(&mut self) {
+        if let Some((key, value)) = self.order.pop_back() {
+            self.index.remove(&key);
+            self.stats.evictions += 1;
+            if let Some(callback) = self.on_evict.as_mut() {
+                callback(key, value);
+            }
+        }
+    }
+}
+
+impl<K, V> Replay<K> for LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Default,
+{
+    fn access(&mut self, key: K) -> bool {
+        if self.get(&key).is_some() {
+            return true;
+        }
+        self.put(key, V::default());
+        false
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.stats
+    }
+}
+
+impl<K, V> std::fmt::Debug for LruCache<K, V>
+where
+    K: Eq + Hash + Clone + std::fmt::Debug,
+    V: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prng::XorShift64;
+
+    /// the same cache as a plain vector, most recently used entry first
+    fn model_put(model: &mut Vec<(u32, u32)>, key: u32, value: u32, capacity: usize) {
+        if let Some(i) = model.iter().position(|&(k, _)| k == key) {
+            model.remove(i);
+        } else if model.len() == capacity {
+            model.pop();
+        }
+        model.insert(0, (key, value));
+    }
+
+    #[test]
+    fn matches_vec_model() {
+        let mut rng = XorShift64::new(17);
+        let mut cache = LruCache::new(8);
+        let mut model = Vec::new();
+        for step in 0..5000 {
+            let key = rng.below(20) as u32;
+            if rng.below(2) == 0 {
+                let expected = model.iter().position(|&(k, _)| k == key).map(|i| {
+                    let entry = model.remove(i);
+                    model.insert(0, entry);
+                    entry.1
+                });
+                assert_eq!(cache.get(&key).copied(), expected);
+            } else {
+                cache.put(key, step);
+                model_put(&mut model, key, step, 8);
+            }
+            assert_eq!(
+                cache.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+                model
+            );
+        }
+        let stats = cache.stats();
+        assert!(stats.hits > 0 && stats.misses > 0 && stats.evictions > 0);
+        cache.reset_stats();
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn remove_and_peek_leave_order_alone() {
+        let mut cache = LruCache::new(3);
+        for (k, v) in [(1, "one"), (2, "two"), (3, "three")].iter() {
+            cache.put(*k, *v);
+        }
+        assert_eq!(cache.peek(&1), Some(&"one"));
+        assert_eq!(cache.remove(&2), Some("two"));
+        assert_eq!(cache.put(3, "THREE"), Some("three"));
+        cache.put(4, "four");
+        cache.put(5, "five");
+        assert!(!cache.contains_key(&1));
+        assert_eq!(cache.len(), cache.capacity());
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(
+            format!("{:?}", cache),
+            "{5: \"five\", 4: \"four\", 3: \"THREE\"}"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! # cache
+//!
+//! Fixed capacity caches built from the crate's own lists plus a `HashMap` index.
+//!
+//! [`LruCache`] evicts the least recently used entry, [`LfuCache`] the least frequently used one.
+//! Both count hits and misses in [`CacheStats`], can hand every evicted entry to a callback and
+//! implement [`Replay`] so the same access trace can be run against either of them with
+//! [`replay`] or [`replay_file`].
+
+pub mod lfu_cache;
+pub mod lru_cache;
+
+pub use self::lfu_cache::LfuCache;
+pub use self::lru_cache::LruCache;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// called with every entry a cache evicts to make room
+pub type EvictionCallback<K, V> = Box<dyn FnMut(K, V)>;
+
+/// Hit and miss counters of a cache, only lookups through `get` are counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+}
+
+impl CacheStats {
+    pub fn accesses(&self) -> usize {
+        self.hits + self.misses
+    }
+
+    /// fraction of lookups that were hits, zero before the first lookup
+    pub fn hit_rate(&self) -> f64 {
+        if self.accesses() == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / self.accesses() as f64
+    }
+}
+
+impl std::fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} accesses, {} hits, {} misses, {} evictions, hit rate {:.2}%",
+            self.accesses(),
+            self.hits,
+            self.misses,
+            self.evictions,
+            100.0 * self.hit_rate()
+        )
+    }
+}
+
+/// A cache which can be driven by a trace of keys alone.
+pub trait Replay<K> {
+    /// look `key` up and load it on a miss, returns whether it was a hit
+    fn access(&mut self, key: K) -> bool;
+
+    fn stats(&self) -> CacheStats;
+}
+
+/// access every key of `trace` in turn and return the statistics of the cache afterwards
+/// ```
+/// # use dsa_sport::cache::{replay, LruCache};
+/// let mut cache: LruCache<char, ()> = LruCache::new(2);
+/// let stats = replay(&mut cache, "abacab".chars());
+/// assert_eq!((stats.hits, stats.misses), (2, 4));
+/// ```
+pub fn replay<K, C, I>(cache: &mut C, trace: I) -> CacheStats
+where
+    C: Replay<K>,
+    I: IntoIterator<Item = K>,
+{
+    for key in trace {
+        cache.access(key);
+    }
+    cache.stats()
+}
+
+/// Replay a trace file and print the hit rate. Every line holds one key, surrounding whitespace
+/// is ignored and so are empty lines and lines starting with `#`.
+pub fn replay_file<C, P>(cache: &mut C, path: P) -> io::Result<CacheStats>
+where
+    C: Replay<String>,
+    P: AsRef<Path>,
+{
+    let reader = BufReader::new(File::open(path.as_ref())?);
+    for line in reader.lines() {
+        let line = line?;
+        let key = line.trim();
+        if key.is_empty() || key.starts_with('#') {
+            continue;
+        }
+        cache.access(key.to_string());
+    }
+    let stats = cache.stats();
+    println!("{}: {}", path.as_ref().display(), stats);
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prng::XorShift64;
+    use std::io::Write;
+
+    #[test]
+    fn replays_a_trace_file() {
+        let path = std::env::temp_dir().join(format!("dsa_sport_trace_{}.txt", std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, "# page requests").unwrap();
+        for key in "a b c a b d a b".split(' ') {
+            writeln!(file, "  {}", key).unwrap();
+        }
+        drop(file);
+
+        let mut lru: LruCache<String, ()> = LruCache::new(3);
+        let stats = replay_file(&mut lru, &path).unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (4, 4, 1));
+        let mut lfu: LfuCache<String, ()> = LfuCache::new(3);
+        let stats = replay_file(&mut lfu, &path).unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (4, 4, 1));
+        std::fs::remove_file(&path).unwrap();
+        assert!(replay_file(&mut lru, &path).is_err());
+    }
+
+    #[test]
+    fn lfu_wins_on_a_hot_set_with_scans() {
+        let mut rng = XorShift64::new(5);
+        let mut trace = Vec::new();
+        for round in 0..50 {
+            for _ in 0..20 {
+                trace.push(rng.below(4));
+            }
+            // a one off scan flushes an LRU cache but not the counted hot keys
+            trace.extend(100 + round * 10..100 + round * 10 + 8);
+        }
+        let lru = replay(&mut LruCache::<usize, ()>::new(6), trace.iter().copied());
+        let lfu = replay(&mut LfuCache::<usize, ()>::new(6), trace.iter().copied());
+        assert_eq!(lru.accesses(), trace.len());
+        assert!(lfu.hit_rate() > lru.hit_rate());
+    }
+}
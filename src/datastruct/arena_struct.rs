@@ -0,0 +1,940 @@
+//! linked list `ArenaList<T>` whose members live in a [`Vector`] and link to each other by index
+//!
+//![`LinkedList`] allocates every member on its own and follows raw pointers, which is why it is
+//!full of `unsafe` blocks. Here all members are slots of one [`Vector`], a link is just the index
+//!of another slot and a removed member's slot goes on a free list to be reused by the next
+//!insertion. The borrow checker is happy with indices, so this file has no `unsafe` at all and
+//!the members end up close to each other in memory.
+//!
+//!Every member links to both neighbours and insertion returns a [`Handle`] to the new member.
+//!Handles stay valid however many members are inserted or removed elsewhere, each slot keeps a
+//!generation counter so a handle to a removed member is refused instead of silently reading the
+//!member that reused the slot.
+//!
+//!The operations of [`LinkedList`] which work on single members are here under the same names:
+//!`add_node`, `push_front`, `get_at`, `take_mid`, `find_node`, `insert_node_at`,
+//!`delete_node_at`, `swape_nodes`, their checked `try_*` forms with the same [`ListError`],
+//!`eliminate_dup`, `is_palindrome`, `reverse_iterative`, `rotate_from_k`, `memory_footprint`,
+//!`iter`, `iter_mut` and the owning `into_iter`. Handles take the place of its cursor.
+//!
+//!The cycle methods are here as well and, unlike on [`LinkedList`], a cycle may stay in place:
+//![`ArenaList::link_tail_to`] points the tail's link back to a member by its index, which can
+//!never leave a dangling link. Members keep their order for iteration and `len`, and every method
+//!that relinks members cuts the cycle first.
+//!
+//!This list does not try to cover the whole of [`LinkedList`]. The `_rec` variants only exist
+//!there to compare recursion with iteration, and the sorts, partitions, group-wise transforms and
+//!two-list algorithms (merges, intersection, digit arithmetic) are exercises in relinking
+//!pointers, so they stay with the pointer list.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::datastruct::arena_struct::ArenaList;
+//! let mut list = ArenaList::new();
+//! list.add_node(1);
+//! let two = list.add_node(2);
+//! list.add_node(3);
+//! assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> x"));
+//! list.push_front(0);
+//! assert_eq!(list.remove(two), Some(2));
+//! assert_eq!(list.get(two), None);
+//! assert_eq!(format!("{:?}",list), format!("0 -> 1 -> 3 -> x"));
+//! ```
+//!
+//! [`LinkedList`]: crate::datastruct::list_struct::LinkedList
+
+use crate::datastruct::list_struct::ListError;
+use crate::datastruct::vec_struct::Vector;
+use core::mem;
+
+/// marks a missing link, the index no slot will ever have
+const NIL: usize = usize::MAX;
+
+/// Stable reference to a member of an [`ArenaList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    index: usize,
+    generation: u32,
+}
+
+pub struct ArenaList<T> {
+    slots: Vector<Slot<T>>,
+    head: usize,
+    tail: usize,
+    free: usize,
+    length: usize,
+}
+
+struct Slot<T> {
+    data: Option<T>,
+    generation: u32,
+    prev: usize,
+    next: usize,
+}
+
+impl<T> ArenaList<T> {
+    pub fn new() -> Self {
+        Self {
+            slots: Vector::new(),
+            head: NIL,
+            tail: NIL,
+            free: NIL,
+            length: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// add node from the tail position
+    pub fn add_node(&mut self, element: T) -> Handle {
+        let tail = self.tail;
+        self.link_between(tail, NIL, element)
+    }
+
+    /// add node from the head position
+    pub fn push_front(&mut self, element: T) -> Handle {
+        let head = self.head;
+        self.link_between(NIL, head, element)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head;
+        self.unlink(head)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail;
+        self.unlink(tail)
+    }
+
+    /// handle of the head member
+    pub fn front(&self) -> Option<Handle> {
+        self.handle_of(self.head)
+    }
+
+    /// handle of the tail member
+    pub fn back(&self) -> Option<Handle> {
+        self.handle_of(self.tail)
+    }
+
+    /// element of a live member, `None` once the member was removed
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.live_slot(handle)?;
+        self.slot(handle.index).data.as_ref()
+    }
+
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        self.live_slot(handle)?;
+        self.slot_mut(handle.index).data.as_mut()
+    }
+
+    /// handle of the member after `handle`
+    pub fn next(&self, handle: Handle) -> Option<Handle> {
+        let index = self.live_slot(handle)?;
+        self.handle_of(self.slot(index).next)
+    }
+
+    /// handle of the member before `handle`
+    pub fn prev(&self, handle: Handle) -> Option<Handle> {
+        let index = self.live_slot(handle)?;
+        self.handle_of(self.slot(index).prev)
+    }
+
+    /// link a new member right after `handle` in O(1)
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list = ArenaList::new();
+    /// let one = list.add_node(1);
+    /// list.add_node(3);
+    /// let two = list.insert_after(one, 2).unwrap();
+    /// list.insert_before(two, 0);
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 0 -> 2 -> 3 -> x"));
+    /// ```
+    pub fn insert_after(&mut self, handle: Handle, element: T) -> Option<Handle> {
+        let index = self.live_slot(handle)?;
+        let next = self.slot(index).next;
+        Some(self.link_between(index, next, element))
+    }
+
+    /// link a new member right before `handle` in O(1)
+    pub fn insert_before(&mut self, handle: Handle, element: T) -> Option<Handle> {
+        let index = self.live_slot(handle)?;
+        let prev = self.slot(index).prev;
+        Some(self.link_between(prev, index, element))
+    }
+
+    /// unlink a member in O(1) and return its element, its slot is reused by a later insertion
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let index = self.live_slot(handle)?;
+        self.unlink(index)
+    }
+
+    /// relink a member in front of the head in O(1), its handle stays valid
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list = ArenaList::new();
+    /// list.add_node('a');
+    /// let b = list.add_node('b');
+    /// list.add_node('c');
+    /// list.move_to_front(b);
+    /// assert_eq!(format!("{:?}",list), format!("'b' -> 'a' -> 'c' -> x"));
+    /// assert_eq!(list.get(b), Some(&'b'));
+    /// ```
+    pub fn move_to_front(&mut self, handle: Handle) {
+        let index = match self.live_slot(handle) {
+            Some(index) => index,
+            None => return,
+        };
+        if index == self.head {
+            return;
+        }
+        self.break_cycle();
+        self.detach(index);
+        let head = self.head;
+        self.attach(NIL, head, index);
+    }
+
+    /// get the borrow of the element at index
+    pub fn get_at(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// return an option of mid element, the lower one when the length is even
+    pub fn take_mid(&self) -> Option<&T> {
+        if self.length == 0 {
+            return None;
+        }
+        self.iter().nth((self.length - 1) / 2)
+    }
+
+    /// Find a node in the list and return its index position if found else returns None
+    pub fn find_node(&self, element: T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|x| *x == element)
+    }
+
+    /// insert node at given position, `index == len` appends at the tail and a larger index is
+    /// ignored
+    pub fn insert_node_at(&mut self, index: usize, element: T) {
+        if index == self.length {
+            self.add_node(element);
+        } else if let Some(handle) = self.handle_at(index) {
+            self.insert_before(handle, element);
+        }
+    }
+
+    /// delete node at given position, an index past the tail is ignored
+    pub fn delete_node_at(&mut self, index: usize) {
+        if let Some(handle) = self.handle_at(index) {
+            self.remove(handle);
+        }
+    }
+
+    /// remove consecutive duplicates
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<i32> = vec![1, 1, 2, 3, 3, 3].into_iter().collect();
+    /// list.eliminate_dup();
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> x"));
+    /// ```
+    pub fn eliminate_dup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.break_cycle();
+        let mut current = self.head;
+        while current != NIL {
+            let next = self.slot(current).next;
+            if next != NIL && self.slot(current).data == self.slot(next).data {
+                self.unlink(next);
+            } else {
+                current = next;
+            }
+        }
+    }
+
+    /// returns true if the list reads the same from both ends
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .zip(self.iter().rev())
+            .take(self.length / 2)
+            .all(|(a, b)| a == b)
+    }
+
+    /// reverse the list by swapping the two links of every member, handles stay valid
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<i32> = (1..=4).collect();
+    /// list.reverse_iterative();
+    /// assert_eq!(format!("{:?}",list), format!("4 -> 3 -> 2 -> 1 -> x"));
+    /// ```
+    pub fn reverse_iterative(&mut self) {
+        self.break_cycle();
+        let mut current = self.head;
+        while current != NIL {
+            let slot = self.slot_mut(current);
+            std::mem::swap(&mut slot.prev, &mut slot.next);
+            current = slot.prev;
+        }
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// take last k element and append it to the front of the list
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<i32> = (1..=5).collect();
+    /// list.rotate_from_k(2);
+    /// assert_eq!(format!("{:?}",list), format!("4 -> 5 -> 1 -> 2 -> 3 -> x"));
+    /// ```
+    pub fn rotate_from_k(&mut self, last_n: usize) {
+        if last_n == 0 || last_n >= self.length {
+            return;
+        }
+        self.break_cycle();
+        let mut new_tail = self.tail;
+        for _ in 0..last_n {
+            new_tail = self.slot(new_tail).prev;
+        }
+        let new_head = self.slot(new_tail).next;
+        let (head, tail) = (self.head, self.tail);
+        self.slot_mut(tail).next = head;
+        self.slot_mut(head).prev = tail;
+        self.slot_mut(new_tail).next = NIL;
+        self.slot_mut(new_head).prev = NIL;
+        self.head = new_head;
+        self.tail = new_tail;
+    }
+
+    /// swap the members at positions `i` and `j` by exchanging their elements, an index past the
+    /// tail is ignored
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<i32> = (1..=3).collect();
+    /// list.swape_nodes(0, 2);
+    /// assert_eq!(format!("{:?}",list), format!("3 -> 2 -> 1 -> x"));
+    /// ```
+    pub fn swape_nodes(&mut self, i: usize, j: usize) {
+        if i == j {
+            return;
+        }
+        let (a, b) = match (self.handle_at(i), self.handle_at(j)) {
+            (Some(a), Some(b)) => (a.index, b.index),
+            _ => return,
+        };
+        let first = self.slot_mut(a).data.take();
+        let second = mem::replace(&mut self.slot_mut(b).data, first);
+        self.slot_mut(a).data = second;
+    }
+
+    /// checked version of [`ArenaList::get_at`]
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// # use dsa_sport::datastruct::list_struct::ListError;
+    /// let mut list = ArenaList::new();
+    /// assert_eq!(list.try_get_at(0), Err(ListError::EmptyList));
+    /// list.add_node('a');
+    /// assert_eq!(list.try_get_at(1), Err(ListError::IndexOutOfBounds { index: 1, len: 1 }));
+    /// ```
+    pub fn try_get_at(&self, index: usize) -> Result<&T, ListError> {
+        self.check_index(index)?;
+        Ok(self.get_at(index).unwrap())
+    }
+
+    /// checked version of [`ArenaList::insert_node_at`], `index == len` appends at the tail
+    pub fn try_insert_node_at(&mut self, index: usize, element: T) -> Result<(), ListError> {
+        if index > self.length {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.length,
+            });
+        }
+        self.insert_node_at(index, element);
+        Ok(())
+    }
+
+    /// checked version of [`ArenaList::delete_node_at`] which also hands back the removed element
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<i32> = (1..=3).collect();
+    /// assert_eq!(list.try_delete_node_at(1), Ok(2));
+    /// assert!(list.try_delete_node_at(2).is_err());
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 3 -> x"));
+    /// ```
+    pub fn try_delete_node_at(&mut self, index: usize) -> Result<T, ListError> {
+        self.check_index(index)?;
+        let handle = self.handle_at(index).unwrap();
+        Ok(self.remove(handle).unwrap())
+    }
+
+    /// checked version of [`ArenaList::swape_nodes`]
+    pub fn try_swape_nodes(&mut self, i: usize, j: usize) -> Result<(), ListError> {
+        self.check_index(i)?;
+        self.check_index(j)?;
+        self.swape_nodes(i, j);
+        Ok(())
+    }
+
+    /// Point the tail's link back to the member at `index`, the list stays cyclic until
+    /// [`ArenaList::break_cycle`] or a method that relinks members. A cycle left by an earlier
+    /// call is replaced.
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<i32> = (1..=6).collect();
+    /// list.link_tail_to(2).unwrap();
+    /// assert!(list.has_cycle());
+    /// assert_eq!(list.cycle_entry(), Some(2));
+    /// assert_eq!(list.cycle_len(), Some(4));
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> 4 -> 5 -> 6 -> x"));
+    /// list.add_node(7);
+    /// assert!(!list.has_cycle_brent());
+    /// ```
+    pub fn link_tail_to(&mut self, index: usize) -> Result<(), ListError> {
+        self.check_index(index)?;
+        let target = self.handle_at(index).unwrap().index;
+        let tail = self.tail;
+        self.slot_mut(tail).next = target;
+        Ok(())
+    }
+
+    /// Floyd's tortoise and hare, like [`LinkedList::has_cycle`]
+    ///
+    /// [`LinkedList::has_cycle`]: crate::datastruct::list_struct::LinkedList::has_cycle
+    pub fn has_cycle(&self) -> bool {
+        self.floyd_meeting() != NIL
+    }
+
+    /// Brent's cycle detection, like [`LinkedList::has_cycle_brent`]
+    ///
+    /// [`LinkedList::has_cycle_brent`]: crate::datastruct::list_struct::LinkedList::has_cycle_brent
+    pub fn has_cycle_brent(&self) -> bool {
+        self.brent().is_some()
+    }
+
+    /// position of the member where the cycle starts, from Floyd's meeting point
+    pub fn cycle_entry(&self) -> Option<usize> {
+        let mut meeting = self.floyd_meeting();
+        if meeting == NIL {
+            return None;
+        }
+        let mut from_head = self.head;
+        let mut index = 0;
+        while from_head != meeting {
+            from_head = self.slot(from_head).next;
+            meeting = self.slot(meeting).next;
+            index += 1;
+        }
+        Some(index)
+    }
+
+    /// number of members on the cycle, found with Brent's algorithm
+    pub fn cycle_len(&self) -> Option<usize> {
+        self.brent().map(|(length, _)| length)
+    }
+
+    /// cut the link which closes the cycle, does nothing on an acyclic list. Only the tail can
+    /// close a cycle here, so this is O(1).
+    pub fn break_cycle(&mut self) {
+        let tail = self.tail;
+        if tail != NIL {
+            self.slot_mut(tail).next = NIL;
+        }
+    }
+
+    /// bytes taken by the list header and every slot of the arena, free ones included
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<u64> = (0..3).collect();
+    /// let full = list.memory_footprint();
+    /// list.pop_front();
+    /// assert_eq!(list.memory_footprint(), full);
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        mem::size_of::<Self>() + self.slots.capacity() * mem::size_of::<Slot<T>>()
+    }
+
+    /// borrow every element from head to tail
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            head: self.head,
+            tail: self.tail,
+            len: self.length,
+        }
+    }
+
+    /// Mutably borrow every element from head to tail. The slots are handed out in the order they
+    /// are stored and sorted into list order first, which takes O(n) extra memory.
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let mut list: ArenaList<i32> = (1..=3).collect();
+    /// list.push_front(0);
+    /// for x in list.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(format!("{:?}",list), format!("0 -> 10 -> 20 -> 30 -> x"));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let mut position = vec![0; self.slots.len()];
+        let mut current = self.head;
+        for i in 0..self.length {
+            position[current] = i;
+            current = self.slot(current).next;
+        }
+        let mut ordered: Vec<Option<&mut T>> = Vec::with_capacity(self.length);
+        ordered.resize_with(self.length, || None);
+        for (index, slot) in self.slots.as_mut_slice().iter_mut().enumerate() {
+            if let Some(data) = slot.data.as_mut() {
+                ordered[position[index]] = Some(data);
+            }
+        }
+        IterMut {
+            inner: ordered
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .into_iter(),
+        }
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), ListError> {
+        if self.length == 0 {
+            return Err(ListError::EmptyList);
+        }
+        if index >= self.length {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.length,
+            });
+        }
+        Ok(())
+    }
+
+    /// meeting point of Floyd's tortoise and hare, `NIL` without a cycle
+    fn floyd_meeting(&self) -> usize {
+        let mut slow = self.head;
+        let mut fast = self.head;
+        while fast != NIL && self.slot(fast).next != NIL {
+            slow = self.slot(slow).next;
+            fast = self.slot(self.slot(fast).next).next;
+            if slow == fast {
+                return slow;
+            }
+        }
+        NIL
+    }
+
+    /// returns the cycle length and the position of the cycle entry
+    fn brent(&self) -> Option<(usize, usize)> {
+        if self.head == NIL {
+            return None;
+        }
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = self.head;
+        let mut hare = self.slot(self.head).next;
+        while tortoise != hare {
+            if hare == NIL {
+                return None;
+            }
+            if power == length {
+                tortoise = hare;
+                power *= 2;
+                length = 0;
+            }
+            hare = self.slot(hare).next;
+            length += 1;
+        }
+        tortoise = self.head;
+        hare = self.head;
+        for _ in 0..length {
+            hare = self.slot(hare).next;
+        }
+        let mut entry = 0;
+        while tortoise != hare {
+            tortoise = self.slot(tortoise).next;
+            hare = self.slot(hare).next;
+            entry += 1;
+        }
+        Some((length, entry))
+    }
+
+    fn handle_at(&self, index: usize) -> Option<Handle> {
+        if index >= self.length {
+            return None;
+        }
+        let mut current = self.head;
+        for _ in 0..index {
+            current = self.slot(current).next;
+        }
+        self.handle_of(current)
+    }
+
+    fn handle_of(&self, index: usize) -> Option<Handle> {
+        if index == NIL {
+            return None;
+        }
+        Some(Handle {
+            index,
+            generation: self.slot(index).generation,
+        })
+    }
+
+    /// index of the slot `handle` points to while its member is still in the list
+    fn live_slot(&self, handle: Handle) -> Option<usize> {
+        let slot = self.slots.get(handle.index)?;
+        if slot.generation == handle.generation && slot.data.is_some() {
+            Some(handle.index)
+        } else {
+            None
+        }
+    }
+
+    fn slot(&self, index: usize) -> &Slot<T> {
+        self.slots.get(index).expect("dangling link")
+    }
+
+    fn slot_mut(&mut self, index: usize) -> &mut Slot<T> {
+        self.slots.get_mut(index).expect("dangling link")
+    }
+
+    /// store `element` in a free slot (or a new one) and link it between `prev` and `next`
+    fn link_between(&mut self, prev: usize, next: usize, element: T) -> Handle {
+        self.break_cycle();
+        let index = if self.free == NIL {
+            self.slots.push(Slot {
+                data: None,
+                generation: 0,
+                prev: NIL,
+                next: NIL,
+            });
+            self.slots.len() - 1
+        } else {
+            let index = self.free;
+            self.free = self.slot(index).next;
+            index
+        };
+        self.slot_mut(index).data = Some(element);
+        self.attach(prev, next, index);
+        self.length += 1;
+        Handle {
+            index,
+            generation: self.slot(index).generation,
+        }
+    }
+
+    fn attach(&mut self, prev: usize, next: usize, index: usize) {
+        {
+            let slot = self.slot_mut(index);
+            slot.prev = prev;
+            slot.next = next;
+        }
+        if prev == NIL {
+            self.head = index;
+        } else {
+            self.slot_mut(prev).next = index;
+        }
+        if next == NIL {
+            self.tail = index;
+        } else {
+            self.slot_mut(next).prev = index;
+        }
+    }
+
+    fn detach(&mut self, index: usize) {
+        let (prev, next) = {
+            let slot = self.slot(index);
+            (slot.prev, slot.next)
+        };
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.slot_mut(prev).next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.slot_mut(next).prev = prev;
+        }
+    }
+
+    /// take the member out of the list and put its slot on the free list
+    fn unlink(&mut self, index: usize) -> Option<T> {
+        if index == NIL {
+            return None;
+        }
+        self.break_cycle();
+        self.detach(index);
+        self.length -= 1;
+        let free = self.free;
+        let slot = self.slot_mut(index);
+        slot.generation = slot.generation.wrapping_add(1);
+        slot.prev = NIL;
+        slot.next = free;
+        self.free = index;
+        self.slot_mut(index).data.take()
+    }
+}
+
+/// Borrowing iterator returned by [`ArenaList::iter`].
+pub struct Iter<'a, T> {
+    list: &'a ArenaList<T>,
+    head: usize,
+    tail: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.list.slot(self.head);
+        self.head = slot.next;
+        slot.data.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.list.slot(self.tail);
+        self.tail = slot.prev;
+        slot.data.as_ref()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// Mutably borrowing iterator returned by [`ArenaList::iter_mut`].
+pub struct IterMut<'a, T> {
+    inner: std::vec::IntoIter<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+/// Owning iterator returned by [`ArenaList::into_iter`], it pops from either end.
+pub struct IntoIter<T> {
+    list: ArenaList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for ArenaList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// move every element out from head to tail
+    /// ```
+    /// # use dsa_sport::datastruct::arena_struct::ArenaList;
+    /// let list: ArenaList<String> = vec!["a".to_string(), "b".to_string()].into_iter().collect();
+    /// assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec!["b", "a"]);
+    /// ```
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArenaList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArenaList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> std::iter::FromIterator<T> for ArenaList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = ArenaList::new();
+        for element in iter {
+            list.add_node(element);
+        }
+        list
+    }
+}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for ArenaList<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        for x in self.iter() {
+            out += &format!("{:?} -> ", x);
+        }
+        out += "x";
+        write!(f, "{}", out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_survive_insertions_and_refuse_reused_slots() {
+        let mut list = ArenaList::new();
+        let handles: Vec<Handle> = (0..10).map(|i| list.add_node(i)).collect();
+        for i in 0..100 {
+            list.push_front(100 + i);
+            list.insert_node_at(list.len() / 2, 1000 + i);
+        }
+        for (i, handle) in handles.iter().enumerate() {
+            assert_eq!(list.get(*handle), Some(&i));
+        }
+        assert_eq!(list.remove(handles[3]), Some(3));
+        let reused = list.add_node(42);
+        assert_eq!(reused.index, handles[3].index);
+        assert_eq!(list.get(handles[3]), None);
+        assert_eq!(list.remove(handles[3]), None);
+        assert_eq!(list.get(reused), Some(&42));
+        assert_eq!(list.len(), 210);
+    }
+
+    #[test]
+    fn matches_the_pointer_list() {
+        use crate::datastruct::list_struct::LinkedList;
+        let mut arena: ArenaList<i32> = (1..=9).collect();
+        let mut pointer: LinkedList<i32> = (1..=9).collect();
+        arena.insert_node_at(3, 0);
+        pointer.insert_node_at(3, 0);
+        arena.delete_node_at(7);
+        pointer.delete_node_at(7);
+        arena.rotate_from_k(4);
+        pointer.rotate_from_k(4);
+        arena.reverse_iterative();
+        pointer.reverse_iterative();
+        assert_eq!(format!("{:?}", arena), format!("{:?}", pointer));
+        assert_eq!(arena.take_mid(), pointer.take_mid());
+        assert_eq!(arena.find_node(0), pointer.find_node(0));
+        assert_eq!(arena.get_at(2), pointer.get_at(2));
+        assert_eq!(arena.is_palindrome(), pointer.is_palindrome());
+        assert_eq!(arena.len(), pointer.len());
+        assert_eq!(arena.try_swape_nodes(1, 5), pointer.try_swape_nodes(1, 5));
+        assert_eq!(arena.try_swape_nodes(0, 9), pointer.try_swape_nodes(0, 9));
+        assert_eq!(
+            arena.try_insert_node_at(9, 7),
+            pointer.try_insert_node_at(9, 7)
+        );
+        assert_eq!(
+            arena.try_insert_node_at(11, 7),
+            pointer.try_insert_node_at(11, 7)
+        );
+        assert_eq!(arena.try_delete_node_at(2), pointer.try_delete_node_at(2));
+        assert_eq!(arena.try_get_at(3), pointer.try_get_at(3));
+        assert_eq!(arena.try_get_at(9), pointer.try_get_at(9));
+        for (a, p) in arena.iter_mut().zip(pointer.iter_mut()) {
+            *a *= 3;
+            *p *= 3;
+        }
+        assert_eq!(format!("{:?}", arena), format!("{:?}", pointer));
+        let owned: ArenaList<i32> = arena.iter().copied().collect();
+        assert!(owned.into_iter().rev().eq(pointer.iter().rev().copied()));
+        while arena.pop_back().is_some() {}
+        assert_eq!(arena.front(), None);
+        assert_eq!(format!("{:?}", arena), "x");
+    }
+
+    #[test]
+    fn cycles_agree_with_the_pointer_list_and_are_cut_by_relinking() {
+        use crate::datastruct::list_struct::LinkedList;
+        for len in 2..12 {
+            let mut arena: ArenaList<usize> = (0..len).collect();
+            let mut pointer: LinkedList<usize> = (0..len).collect();
+            assert!(!arena.has_cycle() && arena.cycle_entry().is_none());
+            for entry in 0..len {
+                arena.link_tail_to(entry).unwrap();
+                let found = pointer
+                    .link_tail_to(entry, |cycle| (cycle.cycle_entry(), cycle.cycle_len()))
+                    .unwrap();
+                assert!(arena.has_cycle() && arena.has_cycle_brent());
+                assert_eq!((arena.cycle_entry(), arena.cycle_len()), found);
+                assert_eq!(arena.iter().count(), len);
+            }
+            assert_eq!(
+                arena.link_tail_to(len),
+                Err(ListError::IndexOutOfBounds { index: len, len })
+            );
+            arena.pop_back();
+            assert!(!arena.has_cycle());
+            arena.link_tail_to(0).unwrap();
+            arena.reverse_iterative();
+            assert!(!arena.has_cycle_brent());
+            assert!(arena.iter().copied().eq((0..len - 1).rev()));
+        }
+        let mut arena: ArenaList<u8> = ArenaList::new();
+        assert_eq!(arena.link_tail_to(0), Err(ListError::EmptyList));
+    }
+}
@@ -0,0 +1,342 @@
+//! circular singly linked list `CircularLinkedList<T>` and the Josephus problem
+//!
+//!The tail member links back to the head, so there is no end to fall off while walking the
+//!list. Only the tail is stored, the head is always one step after it and that makes both
+//!pushing at either end and removing the head O(1).
+//!
+//!The head doubles as the "current" member: [`CircularLinkedList::advance`] and
+//![`CircularLinkedList::rotate`] move it around the ring, [`CircularLinkedList::remove_current`]
+//!takes it out. Iteration stops after one lap.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::datastruct::clist_struct::CircularLinkedList;
+//! let mut ring = CircularLinkedList::new();
+//! ring.add_node(1);
+//! ring.add_node(2);
+//! ring.add_node(3);
+//! assert_eq!(format!("{:?}",ring), format!("1 -> 2 -> 3 -> (1)"));
+//! ring.rotate(4);
+//! assert_eq!(format!("{:?}",ring), format!("2 -> 3 -> 1 -> (2)"));
+//! ```
+
+use crate::datastruct::node;
+use core::marker::PhantomData;
+use core::ptr;
+use std::iter::FromIterator;
+
+pub struct CircularLinkedList<T> {
+    tail: *mut Member<T>,
+    length: usize,
+}
+
+struct Member<T> {
+    data: T,
+    next: *mut Member<T>,
+}
+
+impl<T> CircularLinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    pub fn new() -> Self {
+        Self {
+            tail: ptr::null_mut(),
+            length: 0,
+        }
+    }
+
+    /// add node behind the tail, it becomes the new tail and still links to the head
+    pub fn add_node(&mut self, element: T) {
+        self.push_front(element);
+        self.tail = unsafe { (*self.tail).next };
+    }
+
+    /// add node in front of the head, it becomes the new head
+    pub fn push_front(&mut self, element: T) {
+        let node_ptr = Member::allocate_memory(element);
+        unsafe {
+            if self.tail.is_null() {
+                (*node_ptr).next = node_ptr;
+                self.tail = node_ptr;
+            } else {
+                (*node_ptr).next = (*self.tail).next;
+                (*self.tail).next = node_ptr;
+            }
+        }
+        self.length += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// move the head one member forward, the old head becomes the tail
+    pub fn advance(&mut self) {
+        if !self.tail.is_null() {
+            self.tail = unsafe { (*self.tail).next };
+        }
+    }
+
+    /// move the head `k` members forward in O(min(k, len)), nothing is relinked
+    /// ```
+    /// # use dsa_sport::datastruct::clist_struct::CircularLinkedList;
+    /// let mut ring: CircularLinkedList<i32> = (1..=5).collect();
+    /// ring.rotate(2);
+    /// assert_eq!(ring.current(), Some(&3));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 1, 2]);
+    /// ring.rotate(usize::MAX);
+    /// assert_eq!(ring.current(), Some(&3));
+    /// ```
+    pub fn rotate(&mut self, k: usize) {
+        if self.length == 0 {
+            return;
+        }
+        for _ in 0..k % self.length {
+            self.advance();
+        }
+    }
+
+    /// unlink the head and return its element, the member after it becomes the head
+    /// ```
+    /// # use dsa_sport::datastruct::clist_struct::CircularLinkedList;
+    /// let mut ring: CircularLinkedList<i32> = (1..=3).collect();
+    /// ring.advance();
+    /// assert_eq!(ring.remove_current(), Some(2));
+    /// assert_eq!(format!("{:?}",ring), format!("3 -> 1 -> (3)"));
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe {
+            let head = (*self.tail).next;
+            if head == self.tail {
+                self.tail = ptr::null_mut();
+            } else {
+                (*self.tail).next = (*head).next;
+            }
+            self.length -= 1;
+            Some(Member::take_data(head))
+        }
+    }
+}
+
+impl<T> CircularLinkedList<T> {
+    /// the head of the ring
+    pub fn current(&self) -> Option<&T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe { Some(&(*(*self.tail).next).data) }
+    }
+
+    /// borrow every element once, starting at the head
+    pub fn iter(&self) -> Iter<'_, T> {
+        let head = if self.tail.is_null() {
+            ptr::null_mut()
+        } else {
+            unsafe { (*self.tail).next }
+        };
+        Iter {
+            head,
+            len: self.length,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Elimination order of the Josephus problem: `n` people numbered `1..=n` stand in a circle
+/// and, counting from the first one, every `k`-th person still standing leaves. The circle is
+/// simulated with a [`CircularLinkedList`] in O(nk).
+///
+/// # Panics
+/// Panics if `k` is zero.
+/// ```
+/// # use dsa_sport::datastruct::clist_struct::josephus;
+/// assert_eq!(josephus(7, 3), vec![3, 6, 2, 7, 5, 1, 4]);
+/// ```
+pub fn josephus(n: usize, k: usize) -> Vec<usize> {
+    assert!(k > 0, "the count must start from one");
+    let mut ring: CircularLinkedList<usize> = (1..=n).collect();
+    let mut order = Vec::with_capacity(n);
+    while !ring.is_empty() {
+        ring.rotate(k - 1);
+        order.extend(ring.remove_current());
+    }
+    order
+}
+
+/// The last person standing in [`josephus`], computed in O(n) without simulating the circle.
+/// Numbering from zero, the survivor of `i` people is the survivor of `i - 1` people shifted by
+/// `k` seats: `J(1) = 0` and `J(i) = (J(i - 1) + k) mod i`.
+///
+/// # Panics
+/// Panics if `n` or `k` is zero.
+/// ```
+/// # use dsa_sport::datastruct::clist_struct::josephus_survivor;
+/// assert_eq!(josephus_survivor(7, 3), 4);
+/// assert_eq!(josephus_survivor(41, 3), 31);
+/// ```
+pub fn josephus_survivor(n: usize, k: usize) -> usize {
+    assert!(n > 0 && k > 0, "the circle and the count must not be empty");
+    let mut survivor = 0;
+    for i in 2..=n {
+        survivor = (survivor + k) % i;
+    }
+    survivor + 1
+}
+
+/// Borrowing iterator returned by [`CircularLinkedList::iter`], it ends after one lap.
+pub struct Iter<'a, T> {
+    head: *mut Member<T>,
+    len: usize,
+    marker: PhantomData<&'a Member<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            Some(&(*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<T> FromIterator<T> for CircularLinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ring = CircularLinkedList::new();
+        for element in iter {
+            ring.add_node(element);
+        }
+        ring
+    }
+}
+
+impl<T> Default for CircularLinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for CircularLinkedList<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        match self.current() {
+            None => out += "x",
+            Some(head) => {
+                for x in self.iter() {
+                    out += &format!("{:?} -> ", x);
+                }
+                out += &format!("({:?})", head);
+            }
+        }
+        write!(f, "{}", out)
+    }
+}
+
+impl<T> Drop for CircularLinkedList<T> {
+    fn drop(&mut self) {
+        if self.tail.is_null() {
+            return;
+        }
+        unsafe {
+            let mut node = (*self.tail).next;
+            (*self.tail).next = ptr::null_mut();
+            while !node.is_null() {
+                let next = (*node).next;
+                drop(Member::take_data(node));
+                node = next;
+            }
+        }
+    }
+}
+
+impl<T> Member<T> {
+    fn allocate_memory(element: T) -> *mut Member<T> {
+        node::allocate(Member {
+            data: element,
+            next: ptr::null_mut(),
+        })
+    }
+
+    unsafe fn take_data(node: *mut Member<T>) -> T {
+        node::free(node).data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulation_matches_recurrence() {
+        for n in 1..60 {
+            for k in 1..12 {
+                let order = josephus(n, k);
+                assert_eq!(order.len(), n);
+                assert_eq!(*order.last().unwrap(), josephus_survivor(n, k));
+                let mut seen = order.clone();
+                seen.sort_unstable();
+                assert_eq!(seen, (1..=n).collect::<Vec<_>>());
+            }
+        }
+        assert!(josephus(0, 3).is_empty());
+    }
+
+    #[test]
+    fn ring_survives_being_emptied() {
+        let mut ring: CircularLinkedList<String> =
+            ["a", "b"].iter().map(|s| s.to_string()).collect();
+        ring.push_front("z".to_string());
+        assert_eq!(ring.iter().count(), 3);
+        ring.rotate(7);
+        assert_eq!(ring.current().map(String::as_str), Some("a"));
+        assert_eq!(ring.remove_current().as_deref(), Some("a"));
+        assert_eq!(ring.remove_current().as_deref(), Some("b"));
+        assert_eq!(ring.remove_current().as_deref(), Some("z"));
+        assert_eq!(ring.remove_current(), None);
+        ring.advance();
+        ring.rotate(usize::MAX);
+        assert_eq!(format!("{:?}", ring), "x");
+        ring.add_node("c".to_string());
+        ring.add_node("d".to_string());
+        assert_eq!(format!("{:?}", ring), "\"c\" -> \"d\" -> (\"c\")");
+    }
+
+    #[test]
+    fn rotate_wraps_large_steps() {
+        let mut ring: CircularLinkedList<usize> = (0..3).collect();
+        // usize::MAX is a multiple of 3 on 16, 32 and 64 bit targets
+        ring.rotate(usize::MAX);
+        assert_eq!(ring.current(), Some(&0));
+        ring.rotate(usize::MAX - 1);
+        assert_eq!(ring.current(), Some(&2));
+    }
+}
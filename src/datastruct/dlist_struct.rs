@@ -0,0 +1,845 @@
+//! doubly linked counterpart of [`LinkedList`] written `DoublyLinkedList<T>`
+//!
+//!Every member stores the address of the previous member as well as the next one. That extra
+//!pointer per member is exactly the space [`LinkedList`] saves, in exchange the list can be
+//!walked in both directions and all the operations at either end are O(1).
+//!
+//!Reversing does not need a third pointer walking along the list, swapping the two links of
+//!every member and then swapping head and tail is enough.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+//! let mut list = DoublyLinkedList::new();
+//! list.add_node(2);
+//! list.add_node(3);
+//! list.push_front(1);
+//! assert_eq!(format!("{:?}",list), format!("x <- 1 <-> 2 <-> 3 -> x"));
+//! assert_eq!(list.pop_back(), Some(3));
+//! assert_eq!(list.len(), 2);
+//! ```
+//!
+//! [`LinkedList`]: crate::datastruct::list_struct::LinkedList
+
+use crate::datastruct::node;
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr;
+use std::iter::FromIterator;
+
+pub struct DoublyLinkedList<T> {
+    head: *mut Member<T>,
+    tail: *mut Member<T>,
+    length: usize,
+}
+
+struct Member<T> {
+    data: T,
+    prev: *mut Member<T>,
+    next: *mut Member<T>,
+}
+
+impl<T> DoublyLinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    pub fn new() -> Self {
+        Self {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            length: 0,
+        }
+    }
+
+    /// add node from the tail position, same as [`DoublyLinkedList::push_back`]
+    pub fn add_node(&mut self, element: T) {
+        self.push_back(element);
+    }
+
+    /// add node from the tail position in O(1)
+    pub fn push_back(&mut self, element: T) {
+        let node_ptr = Member::allocate_memory(element);
+        unsafe {
+            (*node_ptr).prev = self.tail;
+            if self.tail.is_null() {
+                self.head = node_ptr;
+            } else {
+                (*self.tail).next = node_ptr;
+            }
+        }
+        self.tail = node_ptr;
+        self.length += 1;
+    }
+
+    /// add node from the head position in O(1)
+    pub fn push_front(&mut self, element: T) {
+        let node_ptr = Member::allocate_memory(element);
+        unsafe {
+            (*node_ptr).next = self.head;
+            if self.head.is_null() {
+                self.tail = node_ptr;
+            } else {
+                (*self.head).prev = node_ptr;
+            }
+        }
+        self.head = node_ptr;
+        self.length += 1;
+    }
+
+    /// remove the tail member in O(1), a singly linked list has to walk to the member before it
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+    /// assert_eq!(list.pop_back(), Some(3));
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(format!("{:?}",list), format!("x <- 2 -> x"));
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        Some(self.unlink(self.tail))
+    }
+
+    /// remove the head member in O(1)
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+        Some(self.unlink(self.head))
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe { Some(&(*self.head).data) }
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe { Some(&(*self.tail).data) }
+    }
+
+    /// the length is kept up to date by every operation so this is O(1)
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// get the borrow of the element at index, the walk starts from whichever end is closer
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let list: DoublyLinkedList<char> = "abcde".chars().collect();
+    /// assert_eq!(list.get_at(3), Some(&'d'));
+    /// assert_eq!(list.get_at(5), None);
+    /// ```
+    pub fn get_at(&self, index: usize) -> Option<&T> {
+        let node = self.member_at(index);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(&(*node).data) }
+    }
+
+    /// return an option of mid element, the two ends walk towards each other until they meet
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let list: DoublyLinkedList<char> = "abcd".chars().collect();
+    /// assert_eq!(list.take_mid(), Some(&'b'));
+    /// ```
+    pub fn take_mid(&self) -> Option<&T> {
+        if self.head.is_null() {
+            return None;
+        }
+        let mut front = self.head;
+        let mut back = self.tail;
+        unsafe {
+            while front != back && (*front).next != back {
+                front = (*front).next;
+                back = (*back).prev;
+            }
+            Some(&(*front).data)
+        }
+    }
+
+    /// Find a node in linked list and return its index position if found else returns None
+    pub fn find_node(&self, element: T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|x| *x == element)
+    }
+
+    /// insert node at given position, `index == len` appends at the tail and a larger index is
+    /// ignored like in [`LinkedList::insert_node_at`]
+    ///
+    /// [`LinkedList::insert_node_at`]: crate::datastruct::list_struct::LinkedList::insert_node_at
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+    /// list.insert_node_at(1, 4);
+    /// assert_eq!(format!("{:?}",list), format!("x <- 1 <-> 4 <-> 2 <-> 3 -> x"));
+    /// ```
+    pub fn insert_node_at(&mut self, index: usize, element: T) {
+        if index == 0 {
+            self.push_front(element);
+        } else if index == self.length {
+            self.push_back(element);
+        } else if index < self.length {
+            let after = self.member_at(index);
+            let node_ptr = Member::allocate_memory(element);
+            unsafe {
+                let before = (*after).prev;
+                (*node_ptr).prev = before;
+                (*node_ptr).next = after;
+                (*before).next = node_ptr;
+                (*after).prev = node_ptr;
+            }
+            self.length += 1;
+        }
+    }
+
+    /// delete node at given position, O(1) once the member is reached because it knows its
+    /// predecessor
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+    /// list.delete_node_at(1);
+    /// assert_eq!(format!("{:?}",list), format!("x <- 1 <-> 3 -> x"));
+    /// ```
+    pub fn delete_node_at(&mut self, index: usize) {
+        let node = self.member_at(index);
+        if !node.is_null() {
+            self.unlink(node);
+        }
+    }
+
+    /// swap the elements stored at two indices
+    pub fn swape_nodes(&mut self, i: usize, j: usize) {
+        let a = self.member_at(i);
+        let b = self.member_at(j);
+        if a.is_null() || b.is_null() || a == b {
+            return;
+        }
+        unsafe {
+            ptr::swap(&mut (*a).data, &mut (*b).data);
+        }
+    }
+
+    /// remove consecutive duplicates
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let mut list: DoublyLinkedList<i32> = vec![1, 2, 2, 3, 4, 4].into_iter().collect();
+    /// list.eliminate_dup();
+    /// assert_eq!(format!("{:?}",list), format!("x <- 1 <-> 2 <-> 3 <-> 4 -> x"));
+    /// assert_eq!(list.back(), Some(&4));
+    /// ```
+    pub fn eliminate_dup(&mut self)
+    where
+        T: PartialEq,
+    {
+        if self.head.is_null() {
+            return;
+        }
+        let mut current = self.head;
+        unsafe {
+            while !(*current).next.is_null() {
+                let next = (*current).next;
+                if (*current).data == (*next).data {
+                    self.unlink(next);
+                } else {
+                    current = next;
+                }
+            }
+        }
+    }
+
+    /// returns true if the list is a palindrome. Unlike [`LinkedList::is_palindrome`] nothing has
+    /// to be reversed, one pointer walks forward from the head while the other walks back from the
+    /// tail.
+    ///
+    /// [`LinkedList::is_palindrome`]: crate::datastruct::list_struct::LinkedList::is_palindrome
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let list: DoublyLinkedList<char> = "level".chars().collect();
+    /// assert_eq!(list.is_palindrome(), true);
+    /// let list: DoublyLinkedList<char> = "levels".chars().collect();
+    /// assert_eq!(list.is_palindrome(), false);
+    /// ```
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .zip(self.iter().rev())
+            .take(self.length / 2)
+            .all(|(a, b)| a == b)
+    }
+
+    /// reverse the list in O(n) by swapping the `prev` and `next` link of every member
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+    /// list.reverse();
+    /// assert_eq!(format!("{:?}",list), format!("x <- 4 <-> 3 <-> 2 <-> 1 -> x"));
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
+        unsafe {
+            while !current.is_null() {
+                let member = &mut *current;
+                mem::swap(&mut member.prev, &mut member.next);
+                current = member.prev;
+            }
+        }
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// take last k element and append it to the front of the list. Only the new tail has to be
+    /// found, it is reached from the tail when that is closer.
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let mut list: DoublyLinkedList<i32> = (1..=5).collect();
+    /// list.rotate_from_k(2);
+    /// assert_eq!(format!("{:?}",list), format!("x <- 4 <-> 5 <-> 1 <-> 2 <-> 3 -> x"));
+    /// ```
+    pub fn rotate_from_k(&mut self, last_n: usize) {
+        if last_n == 0 || last_n >= self.length {
+            return;
+        }
+        let new_tail = self.member_at(self.length - last_n - 1);
+        unsafe {
+            let new_head = (*new_tail).next;
+            (*self.tail).next = self.head;
+            (*self.head).prev = self.tail;
+            (*new_tail).next = ptr::null_mut();
+            (*new_head).prev = ptr::null_mut();
+            self.head = new_head;
+            self.tail = new_tail;
+        }
+    }
+
+    /// cursor resting on the head, see [`CursorMut`]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let index = if self.head.is_null() { None } else { Some(0) };
+        CursorMut {
+            current: self.head,
+            index,
+            list: self,
+        }
+    }
+
+    /// cursor resting on the tail, see [`CursorMut`]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let index = self.length.checked_sub(1);
+        CursorMut {
+            current: self.tail,
+            index,
+            list: self,
+        }
+    }
+
+    fn member_at(&self, index: usize) -> *mut Member<T> {
+        if index >= self.length {
+            return ptr::null_mut();
+        }
+        unsafe {
+            if index < self.length / 2 {
+                let mut node = self.head;
+                for _ in 0..index {
+                    node = (*node).next;
+                }
+                node
+            } else {
+                let mut node = self.tail;
+                for _ in index + 1..self.length {
+                    node = (*node).prev;
+                }
+                node
+            }
+        }
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// borrow every element, the iterator can be consumed from both ends in O(1)
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// let list: DoublyLinkedList<i32> = (1..=3).collect();
+    /// assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.length,
+            marker: PhantomData,
+        }
+    }
+
+    /// mutably borrow every element
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.length,
+            marker: PhantomData,
+        }
+    }
+
+    /// bytes taken by the list header and all of its members
+    /// ```
+    /// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let doubly: DoublyLinkedList<u64> = (0..100).collect();
+    /// let singly: LinkedList<u64> = (0..100).collect();
+    /// assert!(doubly.memory_footprint() > singly.memory_footprint());
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        mem::size_of::<Self>() + self.length * mem::size_of::<Member<T>>()
+    }
+
+    /// detach `node` from its neighbours and free it, returning its element
+    fn unlink(&mut self, node: *mut Member<T>) -> T {
+        unsafe {
+            let prev = (*node).prev;
+            let next = (*node).next;
+            if prev.is_null() {
+                self.head = next;
+            } else {
+                (*prev).next = next;
+            }
+            if next.is_null() {
+                self.tail = prev;
+            } else {
+                (*next).prev = prev;
+            }
+            self.length -= 1;
+            Member::take_data(node)
+        }
+    }
+
+    /// link a new member between `prev` and `next`, either of which may be null at an end
+    fn link_between(&mut self, prev: *mut Member<T>, next: *mut Member<T>, element: T) {
+        let node_ptr = Member::allocate_memory(element);
+        unsafe {
+            (*node_ptr).prev = prev;
+            (*node_ptr).next = next;
+            if prev.is_null() {
+                self.head = node_ptr;
+            } else {
+                (*prev).next = node_ptr;
+            }
+            if next.is_null() {
+                self.tail = node_ptr;
+            } else {
+                (*next).prev = node_ptr;
+            }
+        }
+        self.length += 1;
+    }
+}
+
+/// Cursor over a [`DoublyLinkedList`] which can move in both directions.
+///
+/// Like the cursor of [`LinkedList`] it may rest on a "ghost" position between the tail and the
+/// head. All edits next to the cursor are O(1) in both directions.
+///
+/// [`LinkedList`]: crate::datastruct::list_struct::LinkedList
+/// ```
+/// # use dsa_sport::datastruct::dlist_struct::DoublyLinkedList;
+/// let mut list: DoublyLinkedList<i32> = vec![1, 3].into_iter().collect();
+/// let mut cursor = list.cursor_back_mut();
+/// cursor.insert_before(2);
+/// cursor.move_prev();
+/// assert_eq!(cursor.current(), Some(&mut 2));
+/// assert_eq!(cursor.index(), Some(1));
+/// cursor.move_next();
+/// cursor.move_next();
+/// cursor.insert_after(0);
+/// assert_eq!(format!("{:?}",list), format!("x <- 0 <-> 1 <-> 2 <-> 3 -> x"));
+/// ```
+pub struct CursorMut<'a, T> {
+    current: *mut Member<T>,
+    index: Option<usize>,
+    list: &'a mut DoublyLinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// index of the current member, `None` on the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// move towards the tail, from the tail to the ghost and from the ghost to the head
+    pub fn move_next(&mut self) {
+        if self.current.is_null() {
+            self.current = self.list.head;
+            self.index = if self.current.is_null() {
+                None
+            } else {
+                Some(0)
+            };
+        } else {
+            self.current = unsafe { (*self.current).next };
+            self.index = if self.current.is_null() {
+                None
+            } else {
+                self.index.map(|i| i + 1)
+            };
+        }
+    }
+
+    /// move towards the head, from the head to the ghost and from the ghost to the tail
+    pub fn move_prev(&mut self) {
+        if self.current.is_null() {
+            self.current = self.list.tail;
+            self.index = self.list.length.checked_sub(1);
+        } else {
+            self.current = unsafe { (*self.current).prev };
+            self.index = if self.current.is_null() {
+                None
+            } else {
+                self.index.map(|i| i - 1)
+            };
+        }
+    }
+
+    /// element under the cursor, `None` on the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        if self.current.is_null() {
+            return None;
+        }
+        unsafe { Some(&mut (*self.current).data) }
+    }
+
+    /// link a new member after the cursor, at the head when the cursor is on the ghost
+    pub fn insert_after(&mut self, element: T) {
+        if self.current.is_null() {
+            let head = self.list.head;
+            self.list.link_between(ptr::null_mut(), head, element);
+        } else {
+            let next = unsafe { (*self.current).next };
+            self.list.link_between(self.current, next, element);
+        }
+    }
+
+    /// link a new member before the cursor, at the tail when the cursor is on the ghost
+    pub fn insert_before(&mut self, element: T) {
+        if self.current.is_null() {
+            let tail = self.list.tail;
+            self.list.link_between(tail, ptr::null_mut(), element);
+        } else {
+            let prev = unsafe { (*self.current).prev };
+            self.list.link_between(prev, self.current, element);
+            self.index = self.index.map(|i| i + 1);
+        }
+    }
+
+    /// unlink the current member and return its element, the cursor moves to the next member
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current.is_null() {
+            return None;
+        }
+        let node = self.current;
+        self.current = unsafe { (*node).next };
+        if self.current.is_null() {
+            self.index = None;
+        }
+        Some(self.list.unlink(node))
+    }
+}
+
+/// Borrowing iterator returned by [`DoublyLinkedList::iter`].
+pub struct Iter<'a, T> {
+    head: *mut Member<T>,
+    tail: *mut Member<T>,
+    len: usize,
+    marker: PhantomData<&'a Member<T>>,
+}
+
+/// Mutably borrowing iterator returned by [`DoublyLinkedList::iter_mut`].
+pub struct IterMut<'a, T> {
+    head: *mut Member<T>,
+    tail: *mut Member<T>,
+    len: usize,
+    marker: PhantomData<&'a mut Member<T>>,
+}
+
+/// Owning iterator returned by `DoublyLinkedList::into_iter`.
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            Some(&(*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.tail;
+            self.tail = (*node).prev;
+            Some(&(*node).data)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            Some(&mut (*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.tail;
+            self.tail = (*node).prev;
+            Some(&mut (*node).data)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.list.head.is_null() {
+            return None;
+        }
+        let head = self.list.head;
+        Some(self.list.unlink(head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.length, Some(self.list.length))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.list.tail.is_null() {
+            return None;
+        }
+        let tail = self.list.tail;
+        Some(self.list.unlink(tail))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for DoublyLinkedList<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        if self.head.is_null() {
+            out += "x";
+        } else {
+            out += "x <- ";
+            for (i, x) in self.iter().enumerate() {
+                if i > 0 {
+                    out += " <-> ";
+                }
+                out += &format!("{:?}", x);
+            }
+            out += " -> x";
+        }
+        write!(f, "{}", out)
+    }
+}
+
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        let mut head = self.head;
+        while !head.is_null() {
+            unsafe {
+                let next = (*head).next;
+                drop(Member::take_data(head));
+                head = next;
+            }
+        }
+    }
+}
+
+impl<T> Member<T> {
+    fn allocate_memory(element: T) -> *mut Member<T> {
+        node::allocate(Member {
+            data: element,
+            prev: ptr::null_mut(),
+            next: ptr::null_mut(),
+        })
+    }
+
+    unsafe fn take_data(node: *mut Member<T>) -> T {
+        node::free(node).data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datastruct::list_struct::LinkedList;
+
+    #[test]
+    fn doubly_linked_members_cost_one_pointer_more() {
+        let doubly: DoublyLinkedList<u64> = (0..1000).collect();
+        let singly: LinkedList<u64> = (0..1000).collect();
+        let extra = doubly.memory_footprint() - singly.memory_footprint();
+        let header = mem::size_of::<DoublyLinkedList<u64>>() - mem::size_of::<LinkedList<u64>>();
+        assert_eq!(extra, header + 1000 * mem::size_of::<*mut u8>());
+
+        let doubly: DoublyLinkedList<u8> = (0..10).collect();
+        let singly: LinkedList<u8> = (0..10).collect();
+        assert!(doubly.memory_footprint() > singly.memory_footprint());
+    }
+
+    #[test]
+    fn links_stay_consistent_in_both_directions() {
+        let mut list: DoublyLinkedList<i32> = (1..=6).collect();
+        list.rotate_from_k(4);
+        list.reverse();
+        list.delete_node_at(0);
+        list.insert_node_at(5, 9);
+        list.insert_node_at(7, 10);
+        list.swape_nodes(0, 4);
+        let forward: Vec<i32> = list.iter().copied().collect();
+        let mut backward: Vec<i32> = list.iter().rev().copied().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+        assert_eq!(forward, vec![3, 6, 5, 4, 1, 9]);
+        assert_eq!(list.len(), forward.len());
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(9));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(list.back(), Some(&1));
+        assert_eq!(
+            list.into_iter().rev().collect::<Vec<_>>(),
+            vec![1, 4, 5, 6, 3]
+        );
+    }
+
+    #[test]
+    fn into_iter_drops_what_is_left() {
+        use std::rc::Rc;
+        let shared = Rc::new(());
+        let list: DoublyLinkedList<Rc<()>> = (0..5).map(|_| Rc::clone(&shared)).collect();
+        let mut it = list.into_iter();
+        it.next();
+        it.next_back();
+        drop(it);
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+}
@@ -21,15 +21,47 @@
 //! ```
 //!
 
+use core::cmp::Ordering;
+use core::marker::PhantomData;
 use core::mem;
 use core::ptr;
 use std::alloc;
+use std::collections::BinaryHeap;
+use std::iter::FromIterator;
 
 pub struct LinkedList<T> {
     head: *mut Member<T>,
     tail: *mut Member<T>,
 }
 
+/// Reason why one of the `try_*` methods of [`LinkedList`] or
+/// [`ArenaList`](crate::datastruct::arena_struct::ArenaList) refused to touch the list, or why a
+/// cycle could not be linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListError {
+    /// the index does not name a position of a list holding `len` members
+    IndexOutOfBounds { index: usize, len: usize },
+    /// the operation needs at least one member
+    EmptyList,
+}
+
+impl std::fmt::Display for ListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListError::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "index {} is out of bounds for a list of length {}",
+                    index, len
+                )
+            }
+            ListError::EmptyList => write!(f, "the list is empty"),
+        }
+    }
+}
+
+impl std::error::Error for ListError {}
+
 impl<T> LinkedList<T>
 where
     T: PartialOrd + std::fmt::Debug,
@@ -166,6 +198,20 @@ where
     /// assert_eq!(list.find_node('d'), None);
     /// ```
     pub fn find_node(&mut self, element: T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.find(&element)
+    }
+
+    /// [`LinkedList::find_node`] for a borrowed element, it compares one member after the other
+    /// from the head and stops at the first equal one
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let list: LinkedList<String> = vec!["a".to_string(), "b".to_string()].into_iter().collect();
+    /// assert_eq!(list.find(&"b".to_string()), Some(1));
+    /// ```
+    pub fn find(&self, element: &T) -> Option<usize>
     where
         T: PartialEq,
     {
@@ -173,14 +219,14 @@ where
         let mut index: usize = 0;
         while !current_node.is_null() {
             unsafe {
-                if (*current_node).data == element {
+                if (*current_node).data == *element {
                     return Some(index);
                 }
                 current_node = (*current_node).next;
             }
             index += 1;
         }
-        return None;
+        None
     }
 
     /// recursive approach for [`LinkedList::find_node`]
@@ -226,6 +272,7 @@ where
                 }
             }
         }
+        self.sync_tail();
     }
 
     /// recursive approach for [`LinkedList::insert_node_at`]
@@ -234,6 +281,7 @@ where
         let node_ptr = Member::allocate_memory(node);
         let new_head = LinkedList::insert_node_at_rec_help(index, self.head, node_ptr);
         self.head = new_head;
+        self.sync_tail();
     }
 
     /// delete node at given position
@@ -276,12 +324,14 @@ where
                 }
             }
         }
+        self.sync_tail();
     }
 
     /// recursive approach for [`LinkedList::delete_node_at`]
     pub fn delete_node_at_rec(&mut self, index: usize) {
         let new_head = LinkedList::delete_node_at_rec_help(index, self.head);
         self.head = new_head;
+        self.sync_tail();
     }
 
     /// swap node of the linkedlist by its index value
@@ -342,6 +392,84 @@ where
             (*current_two).next = (*current_one).next;
             (*current_one).next = current_one_temp;
         }
+        self.sync_tail();
+    }
+
+    /// checked version of [`LinkedList::get_at`]
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::{LinkedList, ListError};
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.try_get_at(0), Err(ListError::EmptyList));
+    /// list.add_node('a');
+    /// list.add_node('b');
+    /// assert_eq!(list.try_get_at(1), Ok(&'b'));
+    /// assert_eq!(list.try_get_at(2), Err(ListError::IndexOutOfBounds { index: 2, len: 2 }));
+    /// ```
+    pub fn try_get_at(&self, index: usize) -> Result<&T, ListError> {
+        self.check_index(index)?;
+        Ok(self.iter().nth(index).unwrap())
+    }
+
+    /// checked version of [`LinkedList::insert_node_at`], `index == len` appends at the tail
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::{LinkedList, ListError};
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.try_insert_node_at(0, 1), Ok(()));
+    /// assert_eq!(list.try_insert_node_at(1, 3), Ok(()));
+    /// assert_eq!(list.try_insert_node_at(1, 2), Ok(()));
+    /// assert_eq!(
+    ///     list.try_insert_node_at(5, 4),
+    ///     Err(ListError::IndexOutOfBounds { index: 5, len: 3 })
+    /// );
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> x"));
+    /// ```
+    pub fn try_insert_node_at(&mut self, index: usize, element: T) -> Result<(), ListError> {
+        let len = self.len();
+        if index > len {
+            return Err(ListError::IndexOutOfBounds { index, len });
+        }
+        self.insert_node_at(index, element);
+        Ok(())
+    }
+
+    /// checked version of [`LinkedList::delete_node_at`] which also hands back the removed element
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::{LinkedList, ListError};
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.try_delete_node_at(0), Err(ListError::EmptyList));
+    /// list.add_node(1);
+    /// list.add_node(2);
+    /// list.add_node(3);
+    /// assert_eq!(list.try_delete_node_at(1), Ok(2));
+    /// assert_eq!(list.try_delete_node_at(2), Err(ListError::IndexOutOfBounds { index: 2, len: 2 }));
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 3 -> x"));
+    /// ```
+    pub fn try_delete_node_at(&mut self, index: usize) -> Result<T, ListError> {
+        self.check_index(index)?;
+        let mut cursor = self.cursor_front_mut();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        Ok(cursor.remove_current().unwrap())
+    }
+
+    /// checked version of [`LinkedList::swape_nodes`]
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::{LinkedList, ListError};
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.try_swape_nodes(0, 0), Err(ListError::EmptyList));
+    /// list.add_node(1);
+    /// list.add_node(2);
+    /// list.add_node(3);
+    /// assert_eq!(list.try_swape_nodes(0, 3), Err(ListError::IndexOutOfBounds { index: 3, len: 3 }));
+    /// assert_eq!(list.try_swape_nodes(2, 0), Ok(()));
+    /// assert_eq!(format!("{:?}",list), format!("3 -> 2 -> 1 -> x"));
+    /// ```
+    pub fn try_swape_nodes(&mut self, i: usize, j: usize) -> Result<(), ListError> {
+        self.check_index(i)?;
+        self.check_index(j)?;
+        self.swape_nodes(i, j);
+        Ok(())
     }
 
     /// remove duplicate from the linkedlistt
@@ -391,6 +519,7 @@ where
         unsafe {
             (*t1).next = t2;
         }
+        self.tail = t1;
     }
 
     ///
@@ -483,11 +612,13 @@ where
                 curr = _next;
             }
         }
+        self.tail = self.head;
         self.head = prev;
     }
 
     /// recursive approach for [`LinkedList::reverse_iterative`]
     pub fn reverse_recursive(&mut self) {
+        self.tail = self.head;
         self.head = LinkedList::reverse_recursive_helper(self.head);
     }
 
@@ -521,57 +652,344 @@ where
     where
         T: Copy + std::ops::Rem<Output = T> + PartialEq + From<i32>,
     {
-        if self.head.is_null() {
-            return;
-        }
         let two: T = 2i32.into();
         let zero: T = 0i32.into();
-        let mut odd_head = ptr::null_mut() as *mut Member<T>;
-        let mut odd_tail = ptr::null_mut() as *mut Member<T>;
-        let mut even_head = ptr::null_mut() as *mut Member<T>;
-        let mut even_tail = ptr::null_mut() as *mut Member<T>;
-        let mut c_node = self.head;
-        loop {
-            if c_node.is_null() {
-                break;
+        self.partition_by(|x| *x % two != zero);
+    }
+
+    /// stable partition, every member satisfying `pred` is relinked in front of the others while
+    /// both groups keep their original order. O(n) and no member is allocated or copied.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=8).collect();
+    /// list.partition_by(|x| x % 3 == 0);
+    /// assert_eq!(format!("{:?}",list), format!("3 -> 6 -> 1 -> 2 -> 4 -> 5 -> 7 -> 8 -> x"));
+    /// ```
+    pub fn partition_by<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let groups = self.take_groups(|x| if pred(x) { 0 } else { 1 });
+        self.join_groups(groups);
+    }
+
+    /// like [`LinkedList::partition_by`] but hands the two groups back as separate lists
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let list: LinkedList<i32> = (1..=6).collect();
+    /// let (even, odd) = list.split_by(|x| x % 2 == 0);
+    /// assert_eq!(format!("{:?}",even), format!("2 -> 4 -> 6 -> x"));
+    /// assert_eq!(format!("{:?}",odd), format!("1 -> 3 -> 5 -> x"));
+    /// ```
+    pub fn split_by<F>(mut self, mut pred: F) -> (LinkedList<T>, LinkedList<T>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let [(head_1, tail_1), (head_2, tail_2), _] =
+            self.take_groups(|x| if pred(x) { 0 } else { 1 });
+        let accepted = LinkedList {
+            head: head_1,
+            tail: tail_1,
+        };
+        let rejected = LinkedList {
+            head: head_2,
+            tail: tail_2,
+        };
+        (accepted, rejected)
+    }
+
+    /// three way (dutch national flag) stable partition around `pivot`: smaller elements, then
+    /// the ones equal to it, then the greater ones. Elements which cannot be compared with the
+    /// pivot are kept with the greater ones.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = vec![5, 1, 3, 9, 3, 0, 7].into_iter().collect();
+    /// list.partition3(&3);
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 0 -> 3 -> 3 -> 5 -> 9 -> 7 -> x"));
+    /// ```
+    pub fn partition3(&mut self, pivot: &T) {
+        let groups = self.take_groups(|x| {
+            if x < pivot {
+                0
+            } else if x == pivot {
+                1
+            } else {
+                2
             }
-            unsafe {
-                if (*c_node).data % two == zero {
-                    if even_head.is_null() {
-                        even_head = c_node;
-                        even_tail = c_node;
-                    } else {
-                        (*even_tail).next = c_node;
-                        even_tail = c_node;
-                    }
+        });
+        self.join_groups(groups);
+    }
+
+    /// reverse the list in groups of `k` members, a last group shorter than `k` keeps its order
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=8).collect();
+    /// list.reverse_k_group(3);
+    /// assert_eq!(format!("{:?}",list), format!("3 -> 2 -> 1 -> 6 -> 5 -> 4 -> 7 -> 8 -> x"));
+    /// ```
+    pub fn reverse_k_group(&mut self, k: usize) {
+        if k < 2 {
+            return;
+        }
+        let mut new_head = ptr::null_mut();
+        let mut done_tail: *mut Member<T> = ptr::null_mut();
+        let mut group_head = self.head;
+        unsafe {
+            loop {
+                let mut next_group = group_head;
+                let mut count = 0;
+                while count < k && !next_group.is_null() {
+                    next_group = (*next_group).next;
+                    count += 1;
+                }
+                if count < k {
+                    break;
+                }
+                let mut prev = next_group;
+                let mut curr = group_head;
+                for _ in 0..k {
+                    let next = (*curr).next;
+                    (*curr).next = prev;
+                    prev = curr;
+                    curr = next;
+                }
+                if done_tail.is_null() {
+                    new_head = prev;
                 } else {
-                    if odd_head.is_null() {
-                        odd_head = c_node;
-                        odd_tail = c_node;
-                    } else {
-                        (*odd_tail).next = c_node;
-                        odd_tail = c_node;
-                    }
+                    (*done_tail).next = prev;
                 }
-                c_node = (*c_node).next;
+                done_tail = group_head;
+                group_head = next_group;
+            }
+        }
+        if !new_head.is_null() {
+            self.head = new_head;
+            self.sync_tail();
+        }
+    }
+
+    /// recursive approach for [`LinkedList::reverse_k_group`]
+    pub fn reverse_k_group_rec(&mut self, k: usize) {
+        if k < 2 {
+            return;
+        }
+        self.head = LinkedList::reverse_k_group_help(self.head, k);
+        self.sync_tail();
+    }
+
+    /// reverse the members between the indices `from` and `to` (both included), nothing happens
+    /// if `to` is not a valid index or `from >= to`
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=6).collect();
+    /// list.reverse_range(1, 4);
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 5 -> 4 -> 3 -> 2 -> 6 -> x"));
+    /// ```
+    pub fn reverse_range(&mut self, from: usize, to: usize) {
+        if from >= to || to >= self.len() {
+            return;
+        }
+        unsafe {
+            let mut before: *mut Member<T> = ptr::null_mut();
+            let mut first = self.head;
+            for _ in 0..from {
+                before = first;
+                first = (*first).next;
+            }
+            let mut last = first;
+            for _ in from..to {
+                last = (*last).next;
+            }
+            let mut prev = (*last).next;
+            let mut curr = first;
+            for _ in from..=to {
+                let next = (*curr).next;
+                (*curr).next = prev;
+                prev = curr;
+                curr = next;
+            }
+            if before.is_null() {
+                self.head = last;
+            } else {
+                (*before).next = last;
             }
+            if self.tail == last {
+                self.tail = first;
+            }
+        }
+    }
+
+    /// recursive approach for [`LinkedList::reverse_range`]
+    pub fn reverse_range_rec(&mut self, from: usize, to: usize) {
+        if from >= to || to >= self.len() {
+            return;
+        }
+        self.head = LinkedList::reverse_range_help(self.head, from, to);
+        self.sync_tail();
+    }
+
+    /// unlink the `n`-th member counted from the tail (`n = 1` is the tail) in a single pass, a
+    /// leading pointer runs `n` members ahead of the one that is removed
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=5).collect();
+    /// assert_eq!(list.remove_nth_from_end(2), Some(4));
+    /// assert_eq!(list.remove_nth_from_end(6), None);
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> 5 -> x"));
+    /// ```
+    pub fn remove_nth_from_end(&mut self, n: usize) -> Option<T> {
+        if n == 0 {
+            return None;
         }
+        let mut lead = self.head;
         unsafe {
-            if odd_head.is_null() {
-                self.head = even_head;
-                self.tail = even_tail;
+            for _ in 0..n {
+                if lead.is_null() {
+                    return None;
+                }
+                lead = (*lead).next;
+            }
+            let mut previous: *mut Member<T> = ptr::null_mut();
+            let mut target = self.head;
+            while !lead.is_null() {
+                lead = (*lead).next;
+                previous = target;
+                target = (*target).next;
+            }
+            if previous.is_null() {
+                self.head = (*target).next;
             } else {
-                if even_head.is_null() {
-                    self.head = odd_head;
-                    self.tail = odd_tail;
+                (*previous).next = (*target).next;
+            }
+            if self.tail == target {
+                self.tail = previous;
+            }
+            let data = ptr::read(&(*target).data);
+            Member::deallocate_memory_single(target);
+            Some(data)
+        }
+    }
+
+    /// recursive approach for [`LinkedList::remove_nth_from_end`], the position from the end is
+    /// counted while the recursion unwinds
+    pub fn remove_nth_from_end_rec(&mut self, n: usize) -> Option<T> {
+        let mut removed = ptr::null_mut();
+        let (head, _) = LinkedList::remove_nth_help(self.head, n, &mut removed);
+        self.head = head;
+        if removed.is_null() {
+            return None;
+        }
+        self.sync_tail();
+        unsafe {
+            let data = ptr::read(&(*removed).data);
+            Member::deallocate_memory_single(removed);
+            Some(data)
+        }
+    }
+
+    /// swap every two adjacent members by relinking them
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=5).collect();
+    /// list.swap_pairs();
+    /// assert_eq!(format!("{:?}",list), format!("2 -> 1 -> 4 -> 3 -> 5 -> x"));
+    /// ```
+    pub fn swap_pairs(&mut self) {
+        let mut previous: *mut Member<T> = ptr::null_mut();
+        let mut first = self.head;
+        unsafe {
+            while !first.is_null() && !(*first).next.is_null() {
+                let second = (*first).next;
+                (*first).next = (*second).next;
+                (*second).next = first;
+                if previous.is_null() {
+                    self.head = second;
                 } else {
-                    self.head = odd_head;
-                    (*odd_tail).next = even_head;
-                    self.tail = even_tail;
-                    (*even_tail).next = ptr::null_mut();
+                    (*previous).next = second;
                 }
+                if self.tail == second {
+                    self.tail = first;
+                }
+                previous = first;
+                first = (*first).next;
+            }
+        }
+    }
+
+    /// recursive approach for [`LinkedList::swap_pairs`]
+    pub fn swap_pairs_rec(&mut self) {
+        self.head = LinkedList::swap_pairs_help(self.head);
+        self.sync_tail();
+    }
+
+    /// reorder `L0 -> L1 -> .. -> Ln` into `L0 -> Ln -> L1 -> Ln-1 -> ..`. The second half is
+    /// cut at the middle, reversed and woven into the first half.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=6).collect();
+    /// list.reorder_alternating();
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 6 -> 2 -> 5 -> 3 -> 4 -> x"));
+    /// ```
+    pub fn reorder_alternating(&mut self) {
+        let mid = LinkedList::get_mid(self.head);
+        if mid.is_null() {
+            return;
+        }
+        unsafe {
+            let mut second = LinkedList::reverse_list((*mid).next);
+            (*mid).next = ptr::null_mut();
+            let mut first = self.head;
+            while !second.is_null() {
+                let first_next = (*first).next;
+                let second_next = (*second).next;
+                (*first).next = second;
+                (*second).next = first_next;
+                first = first_next;
+                second = second_next;
+            }
+        }
+        self.sync_tail();
+    }
+
+    /// recursive approach for [`LinkedList::reorder_alternating`], the recursion reaches the tail
+    /// first and every unwinding step links the next member from the back behind the front
+    pub fn reorder_alternating_rec(&mut self) {
+        let mut front = self.head;
+        let mut done = false;
+        LinkedList::reorder_help(&mut front, self.head, &mut done);
+        self.sync_tail();
+    }
+
+    /// relink the members at odd positions (1st, 3rd, ..) in front of the ones at even positions,
+    /// both groups keep their order
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<char> = "abcdefg".chars().collect();
+    /// list.odd_even_by_position();
+    /// assert_eq!(list.iter().collect::<String>(), "acegbdf");
+    /// ```
+    pub fn odd_even_by_position(&mut self) {
+        let mut position = 0;
+        let groups = self.take_groups(|_| {
+            position += 1;
+            position % 2
+        });
+        self.join_groups([groups[1], groups[0], groups[2]]);
+    }
+
+    /// recursive approach for [`LinkedList::odd_even_by_position`]
+    pub fn odd_even_by_position_rec(&mut self) {
+        let (odd, even) = LinkedList::odd_even_help(self.head, true);
+        self.head = odd;
+        self.sync_tail();
+        if self.tail.is_null() {
+            self.head = even;
+        } else {
+            unsafe {
+                (*self.tail).next = even;
             }
         }
+        self.sync_tail();
     }
 
     /// take last k element and append it to the front of the list
@@ -628,6 +1046,7 @@ where
                 (*temp_head).next = head_1;
             }
             self.head = head_2;
+            self.tail = current_head;
         }
     }
 
@@ -669,6 +1088,7 @@ where
                 }
             }
         }
+        self.sync_tail();
     }
 
     /// O(n log n) sorting algorithm
@@ -688,71 +1108,481 @@ where
         T: PartialOrd,
     {
         self.head = LinkedList::merge_sort_helper(self.head);
+        self.sync_tail();
     }
 
-    fn delete_node_at_rec_help(index: usize, mut head: *mut Member<T>) -> *mut Member<T> {
-        if head.is_null() {
-            return head;
-        }
-        if index == 0 {
-            let align = mem::align_of::<Member<T>>();
-            let size = mem::size_of::<Member<T>>();
-            let old_head = head;
-            unsafe {
-                head = (*old_head).next;
-                let layout = alloc::Layout::from_size_align_unchecked(size, align);
-                alloc::dealloc(old_head as *mut u8, layout);
-            }
-        } else {
-            unsafe {
-                let node_ptr = LinkedList::delete_node_at_rec_help(index - 1, (*head).next);
-                (*head).next = node_ptr;
+    /// stable O(n log n) sort with a comparator. It is a bottom-up merge sort which merges runs of
+    /// width 1, 2, 4, .. in place, so unlike [`LinkedList::merge_sort`] it uses no recursion and
+    /// O(1) extra memory however long the list is.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = vec![3, 1, 4, 1, 5, 9, 2, 6].into_iter().collect();
+    /// list.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(format!("{:?}",list), format!("9 -> 6 -> 5 -> 4 -> 3 -> 2 -> 1 -> 1 -> x"));
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len();
+        let mut width = 1;
+        while width < len {
+            let mut rest = self.head;
+            let mut sorted_head: *mut Member<T> = ptr::null_mut();
+            let mut sorted_tail: *mut Member<T> = ptr::null_mut();
+            while !rest.is_null() {
+                let left = rest;
+                let right = Member::cut_after(left, width);
+                rest = Member::cut_after(right, width);
+                let (head, tail) = Member::merge_by(left, right, &mut compare);
+                if sorted_head.is_null() {
+                    sorted_head = head;
+                } else {
+                    unsafe {
+                        (*sorted_tail).next = head;
+                    }
+                }
+                sorted_tail = tail;
             }
+            self.head = sorted_head;
+            self.tail = sorted_tail;
+            width *= 2;
         }
-        return head;
     }
 
-    fn insert_node_at_rec_help(
-        index: usize,
-        mut head: *mut Member<T>,
-        node: *mut Member<T>,
-    ) -> *mut Member<T> {
-        if index == 0 {
-            unsafe {
-                (*node).next = head;
-                head = node;
-            }
-        } else {
-            unsafe {
-                let node_ptr = LinkedList::insert_node_at_rec_help(index - 1, (*head).next, node);
-                (*head).next = node_ptr;
+    /// stable sort by the key extracted from every element, see [`LinkedList::sort_by`]
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<&str> = vec!["ccc", "a", "bb", "d"].into_iter().collect();
+    /// list.sort_by_key(|s| s.len());
+    /// assert_eq!(format!("{:?}",list), format!("\"a\" -> \"d\" -> \"bb\" -> \"ccc\" -> x"));
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// stable O(n^2) insertion sort with a comparator, every member is relinked into a growing
+    /// sorted chain. Input that is already sorted only costs O(n) because the tail of the chain
+    /// is checked first.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = vec![4, 2, 5, 1, 3].into_iter().collect();
+    /// list.insertion_sort_by(|a, b| a.cmp(b));
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> 4 -> 5 -> x"));
+    /// ```
+    pub fn insertion_sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut c_node = self.head;
+        let mut sorted_head: *mut Member<T> = ptr::null_mut();
+        let mut sorted_tail: *mut Member<T> = ptr::null_mut();
+        unsafe {
+            while !c_node.is_null() {
+                let next = (*c_node).next;
+                (*c_node).next = ptr::null_mut();
+                if sorted_head.is_null() {
+                    sorted_head = c_node;
+                    sorted_tail = c_node;
+                } else if compare(&(*sorted_tail).data, &(*c_node).data) != Ordering::Greater {
+                    (*sorted_tail).next = c_node;
+                    sorted_tail = c_node;
+                } else if compare(&(*sorted_head).data, &(*c_node).data) == Ordering::Greater {
+                    (*c_node).next = sorted_head;
+                    sorted_head = c_node;
+                } else {
+                    let mut previous = sorted_head;
+                    while compare(&(*(*previous).next).data, &(*c_node).data) != Ordering::Greater {
+                        previous = (*previous).next;
+                    }
+                    (*c_node).next = (*previous).next;
+                    (*previous).next = c_node;
+                }
+                c_node = next;
             }
         }
-        return head;
+        self.head = sorted_head;
+        self.tail = sorted_tail;
     }
 
-    fn push_back_node(&mut self, new_node: *mut Member<T>) {
-        if self.head.is_null() {
-            self.head = new_node;
-            self.tail = new_node;
-        } else {
+    /// quicksort on a list: the first member of a chain is the pivot and the rest is relinked
+    /// into smaller, equal and greater chains. Relinking keeps the order inside each chain so,
+    /// unlike the array version, this quicksort is stable. Pending chains are kept on a heap
+    /// allocated stack instead of recursing, sorted input still costs O(n^2) comparisons.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = vec![3, 6, 1, 3, 8, 0].into_iter().collect();
+    /// list.quick_sort_by(|a, b| a.cmp(b));
+    /// assert_eq!(format!("{:?}",list), format!("0 -> 1 -> 3 -> 3 -> 6 -> 8 -> x"));
+    /// ```
+    pub fn quick_sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // (head, tail, sorted) chains, the top of the stack is the leftmost pending chain
+        let mut pending = vec![(self.head, self.tail, false)];
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        while let Some((head, tail, sorted)) = pending.pop() {
+            if head.is_null() {
+                continue;
+            }
             unsafe {
-                (*self.tail).next = new_node;
-                self.tail = (*self.tail).next;
-                //self.tail = new_node;
+                if sorted || (*head).next.is_null() {
+                    if self.head.is_null() {
+                        self.head = head;
+                    } else {
+                        (*self.tail).next = head;
+                    }
+                    self.tail = tail;
+                    continue;
+                }
+                let pivot = head;
+                let mut rest = LinkedList {
+                    head: (*pivot).next,
+                    tail,
+                };
+                (*pivot).next = ptr::null_mut();
+                let [less, equal, greater] =
+                    rest.take_groups(|x| match compare(x, &(*pivot).data) {
+                        Ordering::Less => 0,
+                        Ordering::Equal => 1,
+                        Ordering::Greater => 2,
+                    });
+                (*pivot).next = equal.0;
+                let equal_tail = if equal.1.is_null() { pivot } else { equal.1 };
+                pending.push((greater.0, greater.1, false));
+                pending.push((pivot, equal_tail, true));
+                pending.push((less.0, less.1, false));
             }
         }
     }
 
-    unsafe fn rec_len_util(node: *mut Member<T>) -> usize {
-        if node.is_null() {
-            return 0;
+    /// merge two sorted lists into one sorted list by relinking their members, on equal elements
+    /// the ones from `a` come first
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let a: LinkedList<i32> = vec![1, 4, 6].into_iter().collect();
+    /// let b: LinkedList<i32> = vec![2, 3, 5, 7].into_iter().collect();
+    /// let merged = LinkedList::merge_sorted(a, b);
+    /// assert_eq!(format!("{:?}",merged), format!("1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> x"));
+    /// ```
+    pub fn merge_sorted(a: LinkedList<T>, b: LinkedList<T>) -> LinkedList<T> {
+        let (head_a, _) = a.into_raw();
+        let (head_b, tail_b) = b.into_raw();
+        let mut merged = LinkedList::new();
+        if head_a.is_null() {
+            merged.head = head_b;
+            merged.tail = tail_b;
         } else {
-            return 1 + LinkedList::rec_len_util((*node).next);
+            merged.head = unsafe { (*head_a).merge_member(head_b) };
+            merged.sync_tail();
         }
+        merged
     }
 
-    fn merge_sort_helper(head: *mut Member<T>) -> *mut Member<T>
+    /// merge any number of sorted lists in O(n log k), a binary heap holds the current front
+    /// member of each of the k lists. Equal elements keep the order of the lists they came from.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let lists: Vec<LinkedList<i32>> = vec![vec![1, 4, 7], vec![2, 5, 8], vec![], vec![0, 3, 6, 9]]
+    ///     .into_iter()
+    ///     .map(|v| v.into_iter().collect())
+    ///     .collect();
+    /// let merged = LinkedList::merge_k_sorted(lists);
+    /// assert_eq!(merged.iter().copied().collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+    /// ```
+    pub fn merge_k_sorted(lists: Vec<LinkedList<T>>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut heap = BinaryHeap::with_capacity(lists.len());
+        for (source, list) in lists.into_iter().enumerate() {
+            let (node, _) = list.into_raw();
+            if !node.is_null() {
+                heap.push(HeapEntry { node, source });
+            }
+        }
+        let mut merged = LinkedList::new();
+        while let Some(HeapEntry { node, source }) = heap.pop() {
+            unsafe {
+                let next = (*node).next;
+                (*node).next = ptr::null_mut();
+                if !next.is_null() {
+                    heap.push(HeapEntry { node: next, source });
+                }
+            }
+            merged.push_back_node(node);
+        }
+        merged
+    }
+
+    /// find where two lists start sharing the same suffix of values, the "intersection node" of
+    /// two lists that merge into a Y shape. The longer list is skipped ahead by the difference of
+    /// lengths and both are walked in lockstep, returns the index of the first shared member in
+    /// `self` and in `other`.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let a: LinkedList<i32> = vec![4, 1, 8, 4, 5].into_iter().collect();
+    /// let b: LinkedList<i32> = vec![5, 6, 1, 8, 4, 5].into_iter().collect();
+    /// assert_eq!(a.intersection_node(&b), Some((1, 2)));
+    /// let c: LinkedList<i32> = vec![2, 6, 4].into_iter().collect();
+    /// assert_eq!(a.intersection_node(&c), None);
+    /// ```
+    pub fn intersection_node(&self, other: &LinkedList<T>) -> Option<(usize, usize)> {
+        let (len_a, len_b) = (self.len(), other.len());
+        let skip_a = len_a.saturating_sub(len_b);
+        let skip_b = len_b.saturating_sub(len_a);
+        let mut start = None;
+        for (offset, (x, y)) in self
+            .iter()
+            .skip(skip_a)
+            .zip(other.iter().skip(skip_b))
+            .enumerate()
+        {
+            if x != y {
+                start = None;
+            } else if start.is_none() {
+                start = Some(offset);
+            }
+        }
+        start.map(|offset| (skip_a + offset, skip_b + offset))
+    }
+
+    /// relink the members of two lists alternately, `a0 -> b0 -> a1 -> b1 -> ..`, whatever is
+    /// left of the longer list follows at the end
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let a: LinkedList<i32> = vec![1, 3, 5, 7, 9].into_iter().collect();
+    /// let b: LinkedList<i32> = vec![2, 4].into_iter().collect();
+    /// let zipped = LinkedList::interleave(a, b);
+    /// assert_eq!(format!("{:?}",zipped), format!("1 -> 2 -> 3 -> 4 -> 5 -> 7 -> 9 -> x"));
+    /// ```
+    pub fn interleave(a: LinkedList<T>, b: LinkedList<T>) -> LinkedList<T> {
+        let (mut node_a, tail_a) = a.into_raw();
+        let (mut node_b, tail_b) = b.into_raw();
+        let mut zipped = LinkedList::new();
+        unsafe {
+            while !node_a.is_null() && !node_b.is_null() {
+                let next_a = (*node_a).next;
+                let next_b = (*node_b).next;
+                (*node_a).next = ptr::null_mut();
+                (*node_b).next = ptr::null_mut();
+                zipped.push_back_node(node_a);
+                zipped.push_back_node(node_b);
+                node_a = next_a;
+                node_b = next_b;
+            }
+            let (rest, rest_tail) = if node_a.is_null() {
+                (node_b, tail_b)
+            } else {
+                (node_a, tail_a)
+            };
+            if !rest.is_null() {
+                zipped.push_back_node(rest);
+                zipped.tail = rest_tail;
+            }
+        }
+        zipped
+    }
+
+    /// move every member of `other` to the end of this list in O(1), `other` is left empty
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut a: LinkedList<i32> = (1..=2).collect();
+    /// let mut b: LinkedList<i32> = (3..=4).collect();
+    /// a.append(&mut b);
+    /// assert_eq!(format!("{:?}",a), format!("1 -> 2 -> 3 -> 4 -> x"));
+    /// assert_eq!(b.len(), 0);
+    /// ```
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        if other.head.is_null() {
+            return;
+        }
+        self.push_back_node(other.head);
+        self.tail = other.tail;
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+    }
+
+    /// split the list in two at the given index, the members from `at` onward are returned as a
+    /// new list in O(at)
+    ///
+    /// # Panics
+    /// Panics if `at > len`.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut a: LinkedList<i32> = (1..=5).collect();
+    /// let b = a.split_off(3);
+    /// assert_eq!(format!("{:?}",a), format!("1 -> 2 -> 3 -> x"));
+    /// assert_eq!(format!("{:?}",b), format!("4 -> 5 -> x"));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "cannot split off at {} a list of length {}",
+            at,
+            len
+        );
+        if at == 0 {
+            let rest = LinkedList {
+                head: self.head,
+                tail: self.tail,
+            };
+            self.head = ptr::null_mut();
+            self.tail = ptr::null_mut();
+            return rest;
+        }
+        let mut cursor = self.cursor_front_mut();
+        for _ in 1..at {
+            cursor.move_next();
+        }
+        cursor.split_after()
+    }
+
+    fn delete_node_at_rec_help(index: usize, mut head: *mut Member<T>) -> *mut Member<T> {
+        if head.is_null() {
+            return head;
+        }
+        if index == 0 {
+            let align = mem::align_of::<Member<T>>();
+            let size = mem::size_of::<Member<T>>();
+            let old_head = head;
+            unsafe {
+                head = (*old_head).next;
+                let layout = alloc::Layout::from_size_align_unchecked(size, align);
+                alloc::dealloc(old_head as *mut u8, layout);
+            }
+        } else {
+            unsafe {
+                let node_ptr = LinkedList::delete_node_at_rec_help(index - 1, (*head).next);
+                (*head).next = node_ptr;
+            }
+        }
+        return head;
+    }
+
+    fn insert_node_at_rec_help(
+        index: usize,
+        mut head: *mut Member<T>,
+        node: *mut Member<T>,
+    ) -> *mut Member<T> {
+        if index == 0 {
+            unsafe {
+                (*node).next = head;
+                head = node;
+            }
+        } else {
+            unsafe {
+                let node_ptr = LinkedList::insert_node_at_rec_help(index - 1, (*head).next, node);
+                (*head).next = node_ptr;
+            }
+        }
+        return head;
+    }
+
+    fn push_back_node(&mut self, new_node: *mut Member<T>) {
+        if self.head.is_null() {
+            self.head = new_node;
+            self.tail = new_node;
+        } else {
+            unsafe {
+                (*self.tail).next = new_node;
+                self.tail = (*self.tail).next;
+                //self.tail = new_node;
+            }
+        }
+    }
+
+    /// unlink every member into one of three chains picked by `classify`, leaving the list empty.
+    /// Members keep their relative order inside each chain.
+    fn take_groups<F>(&mut self, mut classify: F) -> [(*mut Member<T>, *mut Member<T>); 3]
+    where
+        F: FnMut(&T) -> usize,
+    {
+        let mut groups = [(ptr::null_mut(), ptr::null_mut()); 3];
+        let mut c_node = self.head;
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        while !c_node.is_null() {
+            unsafe {
+                let next = (*c_node).next;
+                (*c_node).next = ptr::null_mut();
+                let (head, tail): &mut (*mut Member<T>, *mut Member<T>) =
+                    &mut groups[classify(&(*c_node).data)];
+                if head.is_null() {
+                    *head = c_node;
+                } else {
+                    (**tail).next = c_node;
+                }
+                *tail = c_node;
+                c_node = next;
+            }
+        }
+        groups
+    }
+
+    /// append the chains produced by [`LinkedList::take_groups`] one after another
+    fn join_groups(&mut self, groups: [(*mut Member<T>, *mut Member<T>); 3]) {
+        for (head, tail) in groups.iter() {
+            if head.is_null() {
+                continue;
+            }
+            if self.head.is_null() {
+                self.head = *head;
+            } else {
+                unsafe {
+                    (*self.tail).next = *head;
+                }
+            }
+            self.tail = *tail;
+        }
+    }
+
+    /// hand the chain over to the caller, the emptied list has nothing left to free on drop
+    fn into_raw(mut self) -> (*mut Member<T>, *mut Member<T>) {
+        let raw = (self.head, self.tail);
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        raw
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), ListError> {
+        let len = self.len();
+        if len == 0 {
+            return Err(ListError::EmptyList);
+        }
+        if index >= len {
+            return Err(ListError::IndexOutOfBounds { index, len });
+        }
+        Ok(())
+    }
+
+    /// walk to the last member so that `tail` is valid again after the links were rearranged
+    fn sync_tail(&mut self) {
+        let mut last = self.head;
+        unsafe {
+            while !last.is_null() && !(*last).next.is_null() {
+                last = (*last).next;
+            }
+        }
+        self.tail = last;
+    }
+
+    unsafe fn rec_len_util(node: *mut Member<T>) -> usize {
+        if node.is_null() {
+            return 0;
+        } else {
+            return 1 + LinkedList::rec_len_util((*node).next);
+        }
+    }
+
+    fn merge_sort_helper(head: *mut Member<T>) -> *mut Member<T>
     where
         T: PartialOrd,
     {
@@ -825,6 +1655,121 @@ where
         return slow;
     }
 
+    fn reverse_k_group_help(head: *mut Member<T>, k: usize) -> *mut Member<T> {
+        let mut next_group = head;
+        for _ in 0..k {
+            if next_group.is_null() {
+                return head;
+            }
+            next_group = unsafe { (*next_group).next };
+        }
+        let mut prev = LinkedList::reverse_k_group_help(next_group, k);
+        let mut curr = head;
+        unsafe {
+            for _ in 0..k {
+                let next = (*curr).next;
+                (*curr).next = prev;
+                prev = curr;
+                curr = next;
+            }
+        }
+        prev
+    }
+
+    fn reverse_range_help(head: *mut Member<T>, from: usize, to: usize) -> *mut Member<T> {
+        if from == 0 {
+            return LinkedList::reverse_first_help(head, to + 1).0;
+        }
+        unsafe {
+            (*head).next = LinkedList::reverse_range_help((*head).next, from - 1, to - 1);
+        }
+        head
+    }
+
+    /// reverse the first `n` members, returns the new head and the member that followed them
+    fn reverse_first_help(head: *mut Member<T>, n: usize) -> (*mut Member<T>, *mut Member<T>) {
+        unsafe {
+            if n == 1 {
+                return (head, (*head).next);
+            }
+            let (new_head, successor) = LinkedList::reverse_first_help((*head).next, n - 1);
+            (*(*head).next).next = head;
+            (*head).next = successor;
+            (new_head, successor)
+        }
+    }
+
+    /// returns the new head of the chain and the position of `head` counted from the end
+    fn remove_nth_help(
+        head: *mut Member<T>,
+        n: usize,
+        removed: &mut *mut Member<T>,
+    ) -> (*mut Member<T>, usize) {
+        if head.is_null() {
+            return (head, 0);
+        }
+        unsafe {
+            let (next, position) = LinkedList::remove_nth_help((*head).next, n, removed);
+            (*head).next = next;
+            if position + 1 == n {
+                *removed = head;
+                return (next, position + 1);
+            }
+            (head, position + 1)
+        }
+    }
+
+    fn swap_pairs_help(head: *mut Member<T>) -> *mut Member<T> {
+        unsafe {
+            if head.is_null() || (*head).next.is_null() {
+                return head;
+            }
+            let second = (*head).next;
+            (*head).next = LinkedList::swap_pairs_help((*second).next);
+            (*second).next = head;
+            second
+        }
+    }
+
+    fn reorder_help(front: &mut *mut Member<T>, back: *mut Member<T>, done: &mut bool) {
+        if back.is_null() {
+            return;
+        }
+        unsafe {
+            LinkedList::reorder_help(front, (*back).next, done);
+            if *done {
+                return;
+            }
+            let left = *front;
+            if left == back || (*left).next == back {
+                (*back).next = ptr::null_mut();
+                *done = true;
+                return;
+            }
+            let next = (*left).next;
+            (*left).next = back;
+            (*back).next = next;
+            *front = next;
+        }
+    }
+
+    /// returns the chain of members at odd positions and the chain at even positions
+    fn odd_even_help(head: *mut Member<T>, odd: bool) -> (*mut Member<T>, *mut Member<T>) {
+        if head.is_null() {
+            return (head, head);
+        }
+        unsafe {
+            let (odd_chain, even_chain) = LinkedList::odd_even_help((*head).next, !odd);
+            if odd {
+                (*head).next = odd_chain;
+                (head, even_chain)
+            } else {
+                (*head).next = even_chain;
+                (odd_chain, head)
+            }
+        }
+    }
+
     fn reverse_list(head: *mut Member<T>) -> *mut Member<T> {
         let mut cur = head;
         let mut prv = ptr::null_mut();
@@ -841,11 +1786,142 @@ where
     }
 }
 
+impl LinkedList<u8> {
+    /// add two numbers stored as decimal digits with the least significant digit at the head,
+    /// `2 -> 4 -> 3` is 342. The members of `a` and `b` are reused for the sum, a new member is
+    /// only allocated for a final carry.
+    ///
+    /// # Panics
+    /// Panics if a member of `a` or `b` is not a decimal digit, before any member is relinked.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let a: LinkedList<u8> = vec![2, 4, 3].into_iter().collect();
+    /// let b: LinkedList<u8> = vec![5, 6, 4, 9].into_iter().collect();
+    /// // 342 + 9465 = 9807
+    /// assert_eq!(format!("{:?}",LinkedList::add_digits(a, b)), format!("7 -> 0 -> 8 -> 9 -> x"));
+    /// ```
+    pub fn add_digits(a: LinkedList<u8>, b: LinkedList<u8>) -> LinkedList<u8> {
+        LinkedList::assert_decimal(&a, &b);
+        let (mut node_a, _) = a.into_raw();
+        let (mut node_b, _) = b.into_raw();
+        let mut sum = LinkedList::new();
+        let mut carry = 0;
+        unsafe {
+            while !node_a.is_null() || !node_b.is_null() {
+                let mut digit = carry;
+                let mut reused = ptr::null_mut();
+                if !node_b.is_null() {
+                    digit += (*node_b).data;
+                    reused = node_b;
+                    node_b = (*node_b).next;
+                }
+                if !node_a.is_null() {
+                    digit += (*node_a).data;
+                    if !reused.is_null() {
+                        Member::deallocate_memory_single(reused);
+                    }
+                    reused = node_a;
+                    node_a = (*node_a).next;
+                }
+                (*reused).data = digit % 10;
+                (*reused).next = ptr::null_mut();
+                carry = digit / 10;
+                sum.push_back_node(reused);
+            }
+        }
+        if carry > 0 {
+            sum.add_node(carry);
+        }
+        sum
+    }
+
+    /// multiply two numbers stored like in [`LinkedList::add_digits`] with schoolbook long
+    /// multiplication. The product has at most `len(a) + len(b)` digits, so the members of both
+    /// factors are relinked to hold it and only the unused leading zeros are freed.
+    ///
+    /// # Panics
+    /// Panics if a member of `a` or `b` is not a decimal digit.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let a: LinkedList<u8> = vec![3, 2, 1].into_iter().collect();
+    /// let b: LinkedList<u8> = vec![6, 5, 4].into_iter().collect();
+    /// // 123 * 456 = 56088
+    /// assert_eq!(format!("{:?}",LinkedList::multiply_digits(a, b)), format!("8 -> 8 -> 0 -> 6 -> 5 -> x"));
+    /// ```
+    pub fn multiply_digits(mut a: LinkedList<u8>, mut b: LinkedList<u8>) -> LinkedList<u8> {
+        LinkedList::assert_decimal(&a, &b);
+        let mut product = vec![0u32; a.len() + b.len()];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                product[i + j] += u32::from(*x) * u32::from(*y);
+            }
+        }
+        let mut carry = 0;
+        for digit in product.iter_mut() {
+            *digit += carry;
+            carry = *digit / 10;
+            *digit %= 10;
+        }
+        let mut significant = product.len();
+        while significant > 1 && product[significant - 1] == 0 {
+            significant -= 1;
+        }
+        a.append(&mut b);
+        let mut cursor = a.cursor_front_mut();
+        for digit in product.iter().take(significant) {
+            if let Some(x) = cursor.current() {
+                *x = *digit as u8;
+            }
+            cursor.move_next();
+        }
+        while cursor.remove_current().is_some() {}
+        a
+    }
+
+    fn assert_decimal(a: &LinkedList<u8>, b: &LinkedList<u8>) {
+        if let Some(digit) = a.iter().chain(b.iter()).find(|digit| **digit > 9) {
+            panic!("{} is not a decimal digit", digit);
+        }
+    }
+}
+
 struct Member<T> {
     data: T,
     next: *mut Member<T>,
 }
 
+/// front member of one of the lists in [`LinkedList::merge_k_sorted`], ordered so that the
+/// max-heap pops the smallest element and, among equal ones, the earliest list
+struct HeapEntry<T> {
+    node: *mut Member<T>,
+    source: usize,
+}
+
+impl<T: Ord> Ord for HeapEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe {
+            (*other.node)
+                .data
+                .cmp(&(*self.node).data)
+                .then_with(|| other.source.cmp(&self.source))
+        }
+    }
+}
+
+impl<T: Ord> PartialOrd for HeapEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> PartialEq for HeapEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for HeapEntry<T> {}
+
 impl<T> std::fmt::Debug for LinkedList<T>
 where
     T: std::fmt::Debug,
@@ -876,28 +1952,745 @@ where
 
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
+        self.break_cycle();
         Member::deallocate_memory(self.head);
     }
 }
 
-impl<T> Member<T> {
-    fn new(element: T) -> Self {
-        return Self {
-            data: element,
-            next: ptr::null_mut(),
-        };
+impl<T> LinkedList<T> {
+    /// borrow every element from head to tail
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.add_node(1);
+    /// list.add_node(2);
+    /// list.add_node(3);
+    /// let mut sum = 0;
+    /// for x in &list {
+    ///     sum += x;
+    /// }
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            len: Member::count(self.head),
+            marker: PhantomData,
+        }
     }
 
-    fn merge_member(&mut self, mut node2: *mut Member<T>) -> *mut Member<T>
-    where
-        T: PartialOrd,
-    {
-        let mut node1 = self as *mut Member<T>;
-        if node1.is_null() {
-            return node2;
+    /// mutably borrow every element from head to tail
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=3).collect();
+    /// for x in list.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(format!("{:?}",list), format!("10 -> 20 -> 30 -> x"));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            len: Member::count(self.head),
+            marker: PhantomData,
         }
-        if node2.is_null() {
-            return node1;
+    }
+
+    /// bytes taken by the list header and all of its members
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let list: LinkedList<u64> = (0..10).collect();
+    /// assert_eq!(list.memory_footprint(), 2 * 8 + 10 * 16);
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        mem::size_of::<Self>() + Member::count(self.head) * mem::size_of::<Member<T>>()
+    }
+
+    /// cursor positioned at the head which can edit the list around itself in O(1), so a whole
+    /// batch of insertions and deletions costs a single O(n) pass instead of O(index) per edit
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=6).collect();
+    /// let mut cursor = list.cursor_front_mut();
+    /// while let Some(x) = cursor.current() {
+    ///     if *x % 2 == 0 {
+    ///         cursor.remove_current();
+    ///     } else {
+    ///         let copy = *x * 10;
+    ///         cursor.insert_after(copy);
+    ///         cursor.move_next();
+    ///         cursor.move_next();
+    ///     }
+    /// }
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 10 -> 3 -> 30 -> 5 -> 50 -> x"));
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let index = if self.head.is_null() { None } else { Some(0) };
+        CursorMut {
+            current: self.head,
+            previous: ptr::null_mut(),
+            index,
+            list: self,
+        }
+    }
+
+    /// Point the tail back to the member at `index` and hand the cyclic list to `inspect`, to
+    /// practice cycle detection on. Every other method expects the list to end in null, so
+    /// `inspect` only sees a [`Cycle`] with the detection methods, and the cycle is broken again
+    /// before this returns, even when `inspect` panics.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=6).collect();
+    /// let found = list.link_tail_to(2, |cycle| (cycle.cycle_entry(), cycle.cycle_len()));
+    /// assert_eq!(found, Ok((Some(2), Some(4))));
+    /// assert!(!list.has_cycle());
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> 4 -> 5 -> 6 -> x"));
+    /// ```
+    pub fn link_tail_to<R, F>(&mut self, index: usize, inspect: F) -> Result<R, ListError>
+    where
+        F: FnOnce(&Cycle<'_, T>) -> R,
+    {
+        self.break_cycle();
+        let len = Member::count(self.head);
+        if len == 0 {
+            return Err(ListError::EmptyList);
+        }
+        if index >= len {
+            return Err(ListError::IndexOutOfBounds { index, len });
+        }
+        let mut target = self.head;
+        unsafe {
+            for _ in 0..index {
+                target = (*target).next;
+            }
+            (*self.tail).next = target;
+        }
+        let guard = CycleGuard { list: self };
+        Ok(inspect(&Cycle { list: guard.list }))
+    }
+
+    /// Floyd's tortoise and hare, the hare moves two members for every member of the tortoise
+    /// and they can only meet again inside a cycle. O(n) time, O(1) memory.
+    pub fn has_cycle(&self) -> bool {
+        !self.floyd_meeting().is_null()
+    }
+
+    /// Brent's cycle detection, the tortoise teleports to the hare whenever the hare has walked a
+    /// power of two steps. Same complexity as [`LinkedList::has_cycle`] with fewer pointer moves.
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=3).collect();
+    /// assert!(!list.has_cycle_brent());
+    /// assert_eq!(list.link_tail_to(0, |cycle| cycle.has_cycle_brent()), Ok(true));
+    /// ```
+    pub fn has_cycle_brent(&self) -> bool {
+        self.brent().is_some()
+    }
+
+    /// index of the member where the cycle starts. After Floyd's meeting point is found a pointer
+    /// from the head and one from the meeting point reach the entry after the same number of steps.
+    pub fn cycle_entry(&self) -> Option<usize> {
+        let mut meeting = self.floyd_meeting();
+        if meeting.is_null() {
+            return None;
+        }
+        let mut from_head = self.head;
+        let mut index = 0;
+        unsafe {
+            while from_head != meeting {
+                from_head = (*from_head).next;
+                meeting = (*meeting).next;
+                index += 1;
+            }
+        }
+        Some(index)
+    }
+
+    /// number of members on the cycle, found with Brent's algorithm
+    pub fn cycle_len(&self) -> Option<usize> {
+        self.brent().map(|(length, _)| length)
+    }
+
+    /// cut the link which closes the cycle so the list ends at a tail again, does nothing on an
+    /// acyclic list
+    pub fn break_cycle(&mut self) {
+        let entry = match self.brent() {
+            Some((_, entry)) => entry,
+            None => return,
+        };
+        let mut entry_node = self.head;
+        unsafe {
+            for _ in 0..entry {
+                entry_node = (*entry_node).next;
+            }
+            let mut last = entry_node;
+            while (*last).next != entry_node {
+                last = (*last).next;
+            }
+            (*last).next = ptr::null_mut();
+            self.tail = last;
+        }
+    }
+
+    fn floyd_meeting(&self) -> *mut Member<T> {
+        let mut slow = self.head;
+        let mut fast = self.head;
+        unsafe {
+            while !fast.is_null() && !(*fast).next.is_null() {
+                slow = (*slow).next;
+                fast = (*(*fast).next).next;
+                if slow == fast {
+                    return slow;
+                }
+            }
+        }
+        ptr::null_mut()
+    }
+
+    /// returns the cycle length and the index of the cycle entry
+    fn brent(&self) -> Option<(usize, usize)> {
+        if self.head.is_null() {
+            return None;
+        }
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = self.head;
+        let mut hare = unsafe { (*self.head).next };
+        unsafe {
+            while tortoise != hare {
+                if hare.is_null() {
+                    return None;
+                }
+                if power == length {
+                    tortoise = hare;
+                    power *= 2;
+                    length = 0;
+                }
+                hare = (*hare).next;
+                length += 1;
+            }
+            tortoise = self.head;
+            hare = self.head;
+            for _ in 0..length {
+                hare = (*hare).next;
+            }
+            let mut entry = 0;
+            while tortoise != hare {
+                tortoise = (*tortoise).next;
+                hare = (*hare).next;
+                entry += 1;
+            }
+            Some((length, entry))
+        }
+    }
+
+    fn pop_front_data(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+        let align = mem::align_of::<Member<T>>();
+        let size = mem::size_of::<Member<T>>();
+        unsafe {
+            let old_head = self.head;
+            self.head = (*old_head).next;
+            if self.head.is_null() {
+                self.tail = ptr::null_mut();
+            }
+            let data = ptr::read(&(*old_head).data);
+            let layout = alloc::Layout::from_size_align_unchecked(size, align);
+            alloc::dealloc(old_head as *mut u8, layout);
+            Some(data)
+        }
+    }
+
+    fn pop_back_data(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe {
+            if (*self.head).next.is_null() {
+                return self.pop_front_data();
+            }
+            let mut previous = self.head;
+            while !(*(*previous).next).next.is_null() {
+                previous = (*previous).next;
+            }
+            let old_tail = (*previous).next;
+            (*previous).next = ptr::null_mut();
+            self.tail = previous;
+            let data = ptr::read(&(*old_tail).data);
+            let layout = alloc::Layout::from_size_align_unchecked(
+                mem::size_of::<Member<T>>(),
+                mem::align_of::<Member<T>>(),
+            );
+            alloc::dealloc(old_tail as *mut u8, layout);
+            Some(data)
+        }
+    }
+}
+
+/// A list whose tail links back into it, lent to the closure of [`LinkedList::link_tail_to`].
+/// Only the cycle detection methods can be called on it.
+pub struct Cycle<'a, T> {
+    list: &'a LinkedList<T>,
+}
+
+impl<'a, T> Cycle<'a, T> {
+    /// [`LinkedList::has_cycle`]
+    pub fn has_cycle(&self) -> bool {
+        self.list.has_cycle()
+    }
+
+    /// [`LinkedList::has_cycle_brent`]
+    pub fn has_cycle_brent(&self) -> bool {
+        self.list.has_cycle_brent()
+    }
+
+    /// [`LinkedList::cycle_entry`]
+    pub fn cycle_entry(&self) -> Option<usize> {
+        self.list.cycle_entry()
+    }
+
+    /// [`LinkedList::cycle_len`]
+    pub fn cycle_len(&self) -> Option<usize> {
+        self.list.cycle_len()
+    }
+}
+
+/// breaks the cycle closed by [`LinkedList::link_tail_to`] when the inspection ends or unwinds
+struct CycleGuard<'a, T> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> Drop for CycleGuard<'a, T> {
+    fn drop(&mut self) {
+        self.list.break_cycle();
+    }
+}
+
+/// Cursor returned by [`LinkedList::cursor_front_mut`].
+///
+/// Besides the members the cursor can rest on a "ghost" position which sits between the tail
+/// and the head, moving past the tail lands on the ghost and moving once more wraps to the head.
+/// The cursor remembers the member before the current one, that is what makes
+/// [`CursorMut::remove_current`] O(1) on a singly linked list.
+pub struct CursorMut<'a, T> {
+    current: *mut Member<T>,
+    previous: *mut Member<T>,
+    index: Option<usize>,
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// index of the current member, `None` on the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// move to the next member, from the tail it moves to the ghost and from the ghost to the head
+    pub fn move_next(&mut self) {
+        if self.current.is_null() {
+            self.current = self.list.head;
+            self.previous = ptr::null_mut();
+            self.index = if self.current.is_null() {
+                None
+            } else {
+                Some(0)
+            };
+        } else {
+            self.previous = self.current;
+            self.current = unsafe { (*self.current).next };
+            if self.current.is_null() {
+                self.previous = ptr::null_mut();
+                self.index = None;
+            } else {
+                self.index = self.index.map(|i| i + 1);
+            }
+        }
+    }
+
+    /// element under the cursor, `None` on the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        if self.current.is_null() {
+            None
+        } else {
+            unsafe { Some(&mut (*self.current).data) }
+        }
+    }
+
+    /// element right after the cursor, the head when the cursor is on the ghost
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = if self.current.is_null() {
+            self.list.head
+        } else {
+            unsafe { (*self.current).next }
+        };
+        if next.is_null() {
+            None
+        } else {
+            unsafe { Some(&mut (*next).data) }
+        }
+    }
+
+    /// link a new member right after the cursor (at the head when on the ghost) without moving
+    pub fn insert_after(&mut self, element: T) {
+        let node_ptr = Member::allocate_memory(Member::new(element));
+        unsafe {
+            if self.current.is_null() {
+                (*node_ptr).next = self.list.head;
+                self.list.head = node_ptr;
+                if self.list.tail.is_null() {
+                    self.list.tail = node_ptr;
+                }
+            } else {
+                (*node_ptr).next = (*self.current).next;
+                (*self.current).next = node_ptr;
+                if self.list.tail == self.current {
+                    self.list.tail = node_ptr;
+                }
+            }
+        }
+    }
+
+    /// unlink the current member and return its element, the cursor moves to the next member
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=3).collect();
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// assert_eq!(cursor.current(), Some(&mut 3));
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 3 -> x"));
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current.is_null() {
+            return None;
+        }
+        let node = self.current;
+        unsafe {
+            let next = (*node).next;
+            if self.previous.is_null() {
+                self.list.head = next;
+            } else {
+                (*self.previous).next = next;
+            }
+            if self.list.tail == node {
+                self.list.tail = self.previous;
+            }
+            self.current = next;
+            if next.is_null() {
+                self.previous = ptr::null_mut();
+                self.index = None;
+            }
+            let data = ptr::read(&(*node).data);
+            let layout = alloc::Layout::from_size_align_unchecked(
+                mem::size_of::<Member<T>>(),
+                mem::align_of::<Member<T>>(),
+            );
+            alloc::dealloc(node as *mut u8, layout);
+            Some(data)
+        }
+    }
+
+    /// cut the list after the cursor and return everything that followed as a new list, on the
+    /// ghost position the whole list is taken
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = (1..=5).collect();
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// let rest = cursor.split_after();
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> x"));
+    /// assert_eq!(format!("{:?}",rest), format!("3 -> 4 -> 5 -> x"));
+    /// ```
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        if self.current.is_null() {
+            let rest = LinkedList {
+                head: self.list.head,
+                tail: self.list.tail,
+            };
+            self.list.head = ptr::null_mut();
+            self.list.tail = ptr::null_mut();
+            return rest;
+        }
+        unsafe {
+            let next = (*self.current).next;
+            if next.is_null() {
+                return LinkedList {
+                    head: ptr::null_mut(),
+                    tail: ptr::null_mut(),
+                };
+            }
+            let rest = LinkedList {
+                head: next,
+                tail: self.list.tail,
+            };
+            (*self.current).next = ptr::null_mut();
+            self.list.tail = self.current;
+            rest
+        }
+    }
+
+    /// link every member of `other` right after the cursor in O(1), the cursor does not move
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list: LinkedList<i32> = vec![1, 5].into_iter().collect();
+    /// let middle: LinkedList<i32> = (2..=4).collect();
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.splice_after(middle);
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> 4 -> 5 -> x"));
+    /// ```
+    pub fn splice_after(&mut self, mut other: LinkedList<T>) {
+        if other.head.is_null() {
+            return;
+        }
+        let (other_head, other_tail) = (other.head, other.tail);
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        unsafe {
+            if self.current.is_null() {
+                (*other_tail).next = self.list.head;
+                self.list.head = other_head;
+                if self.list.tail.is_null() {
+                    self.list.tail = other_tail;
+                }
+            } else {
+                (*other_tail).next = (*self.current).next;
+                (*self.current).next = other_head;
+                if self.list.tail == self.current {
+                    self.list.tail = other_tail;
+                }
+            }
+        }
+    }
+}
+
+/// Borrowing iterator returned by [`LinkedList::iter`].
+///
+/// The list is counted once when the iterator is created, after that `len` and `size_hint` are
+/// O(1). `next_back` has to walk from the front because members only know their successor, it
+/// costs O(remaining) and reversing a singly linked list this way costs O(n^2) in total.
+pub struct Iter<'a, T> {
+    head: *mut Member<T>,
+    len: usize,
+    marker: PhantomData<&'a Member<T>>,
+}
+
+/// Mutably borrowing iterator returned by [`LinkedList::iter_mut`], with the same costs as
+/// [`Iter`].
+pub struct IterMut<'a, T> {
+    head: *mut Member<T>,
+    len: usize,
+    marker: PhantomData<&'a mut Member<T>>,
+}
+
+/// Owning iterator returned by `LinkedList::into_iter`, every member is freed as soon as its
+/// element is handed out.
+///
+/// Like [`Iter`] it counts the list once up front and `size_hint` is O(1), `next_back` unlinks
+/// the tail by walking to the member before it and costs O(remaining).
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            self.len -= 1;
+            Some(&(*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let mut node = self.head;
+        unsafe {
+            for _ in 0..self.len {
+                node = (*node).next;
+            }
+            Some(&(*node).data)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            self.len -= 1;
+            Some(&mut (*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let mut node = self.head;
+        unsafe {
+            for _ in 0..self.len {
+                node = (*node).next;
+            }
+            Some(&mut (*node).data)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let data = self.list.pop_front_data()?;
+        self.len -= 1;
+        Some(data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        let data = self.list.pop_back_data()?;
+        self.len -= 1;
+        Some(data)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        while self.list.pop_front_data().is_some() {}
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// consume the list, yielding its elements from head to tail
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let list: LinkedList<String> = vec!["a", "b", "c"].into_iter().map(String::from).collect();
+    /// let v: Vec<String> = list.into_iter().rev().collect();
+    /// assert_eq!(v, vec!["c", "b", "a"]);
+    /// ```
+    fn into_iter(self) -> IntoIter<T> {
+        let len = Member::count(self.head);
+        IntoIter { list: self, len }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    /// build a list by appending every element at the tail
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let list: LinkedList<i32> = (1..=4).collect();
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> 4 -> x"));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    /// append every element at the tail in O(1) each
+    /// ```
+    /// # use dsa_sport::datastruct::list_struct::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.add_node(1);
+    /// list.extend(vec![2, 3]);
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 2 -> 3 -> x"));
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.add_node(element);
+        }
+    }
+}
+
+impl<T> Member<T> {
+    fn new(element: T) -> Self {
+        return Self {
+            data: element,
+            next: ptr::null_mut(),
+        };
+    }
+
+    fn merge_member(&mut self, mut node2: *mut Member<T>) -> *mut Member<T>
+    where
+        T: PartialOrd,
+    {
+        let mut node1 = self as *mut Member<T>;
+        if node1.is_null() {
+            return node2;
+        }
+        if node2.is_null() {
+            return node1;
         }
         let mut _head = ptr::null_mut();
         let mut _tail = ptr::null_mut();
@@ -952,6 +2745,89 @@ impl<T> Member<T> {
         return node_ptr;
     }
 
+    /// terminate the chain starting at `head` after `n` members and return what followed
+    fn cut_after(mut head: *mut Member<T>, n: usize) -> *mut Member<T> {
+        for _ in 1..n {
+            if head.is_null() {
+                break;
+            }
+            unsafe {
+                head = (*head).next;
+            }
+        }
+        if head.is_null() {
+            return head;
+        }
+        unsafe {
+            let rest = (*head).next;
+            (*head).next = ptr::null_mut();
+            rest
+        }
+    }
+
+    /// stable merge of two sorted chains, returns the head and the tail of the merged chain
+    fn merge_by<F>(
+        mut node1: *mut Member<T>,
+        mut node2: *mut Member<T>,
+        compare: &mut F,
+    ) -> (*mut Member<T>, *mut Member<T>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut head: *mut Member<T> = ptr::null_mut();
+        let mut tail: *mut Member<T> = ptr::null_mut();
+        unsafe {
+            while !node1.is_null() && !node2.is_null() {
+                let next;
+                if compare(&(*node1).data, &(*node2).data) != Ordering::Greater {
+                    next = node1;
+                    node1 = (*node1).next;
+                } else {
+                    next = node2;
+                    node2 = (*node2).next;
+                }
+                if head.is_null() {
+                    head = next;
+                } else {
+                    (*tail).next = next;
+                }
+                tail = next;
+            }
+            let mut rest = if node1.is_null() { node2 } else { node1 };
+            if head.is_null() {
+                head = rest;
+            } else {
+                (*tail).next = rest;
+            }
+            while !rest.is_null() {
+                tail = rest;
+                rest = (*rest).next;
+            }
+        }
+        (head, tail)
+    }
+
+    fn deallocate_memory_single(node: *mut Member<T>) {
+        unsafe {
+            let layout = alloc::Layout::from_size_align_unchecked(
+                mem::size_of::<Member<T>>(),
+                mem::align_of::<Member<T>>(),
+            );
+            alloc::dealloc(node as *mut u8, layout);
+        }
+    }
+
+    fn count(mut head: *mut Member<T>) -> usize {
+        let mut count = 0;
+        while !head.is_null() {
+            unsafe {
+                head = (*head).next;
+            }
+            count += 1;
+        }
+        count
+    }
+
     fn deallocate_memory(mut head: *mut Member<T>) {
         let mut count = 0;
         let align = mem::align_of::<Member<T>>();
@@ -968,3 +2844,301 @@ impl<T> Member<T> {
         println!("delocate : {}", count);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extend_after_relinking_keeps_tail() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        list.reverse_iterative();
+        list.extend(vec![0]);
+        assert_eq!(
+            format!("{:?}", list),
+            format!("5 -> 4 -> 3 -> 2 -> 1 -> 0 -> x")
+        );
+        list.rotate_from_k(2);
+        list.add_node(9);
+        assert_eq!(
+            format!("{:?}", list),
+            format!("1 -> 0 -> 5 -> 4 -> 3 -> 2 -> 9 -> x")
+        );
+        list.merge_sort();
+        list.add_node(10);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5, 9, 10]
+        );
+    }
+
+    #[test]
+    fn cursor_keeps_head_and_tail_consistent() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.remove_current(), Some(1));
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.index(), None);
+        cursor.insert_after(0);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 0));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.peek_next(), Some(&mut 2));
+        list.add_node(7);
+        assert_eq!(format!("{:?}", list), format!("0 -> 2 -> 7 -> x"));
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.splice_after((8..=9).collect());
+        list.add_node(10);
+        assert_eq!(
+            format!("{:?}", list),
+            format!("0 -> 2 -> 7 -> 8 -> 9 -> 10 -> x")
+        );
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.splice_after(list);
+        assert_eq!(cursor.split_after().len(), 6);
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn sort_by_is_stable_on_a_million_members() {
+        let mut list: LinkedList<(u32, u32)> = (0..1_000_000u32).map(|i| (i % 1000, i)).collect();
+        list.sort_by(|a, b| b.0.cmp(&a.0));
+        let mut previous = (u32::MAX, 0);
+        for &(key, index) in list.iter() {
+            assert!(key < previous.0 || (key == previous.0 && index > previous.1));
+            previous = (key, index);
+        }
+        list.add_node((0, 0));
+        assert_eq!(list.len(), 1_000_001);
+    }
+
+    #[test]
+    fn list_sorts_agree_with_slice_sort() {
+        let input: Vec<(i32, usize)> = (0..200).map(|i| ((i * 37 % 23) as i32, i)).collect();
+        let mut expected = input.clone();
+        expected.sort_by_key(|x| x.0);
+        type Tagged = LinkedList<(i32, usize)>;
+        let sorts: [fn(&mut Tagged); 4] = [
+            |l| l.sort_by(|a, b| a.0.cmp(&b.0)),
+            |l| l.sort_by_key(|a| a.0),
+            |l| l.insertion_sort_by(|a, b| a.0.cmp(&b.0)),
+            |l| l.quick_sort_by(|a, b| a.0.cmp(&b.0)),
+        ];
+        for sort in sorts.iter() {
+            let mut list: Tagged = input.iter().copied().collect();
+            sort(&mut list);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            list.add_node((99, 99));
+            assert_eq!(list.iter().last(), Some(&(99, 99)));
+
+            let mut empty: Tagged = LinkedList::new();
+            sort(&mut empty);
+            assert_eq!(empty.len(), 0);
+        }
+    }
+
+    #[test]
+    fn floyd_and_brent_agree_on_every_cycle() {
+        for len in 1..20 {
+            let mut list: LinkedList<usize> = (0..len).collect();
+            assert!(!list.has_cycle());
+            assert_eq!(list.cycle_len(), None);
+            assert_eq!(list.cycle_entry(), None);
+            for entry in 0..len {
+                list.link_tail_to(entry, |cycle| {
+                    assert!(cycle.has_cycle() && cycle.has_cycle_brent());
+                    assert_eq!(cycle.cycle_entry(), Some(entry));
+                    assert_eq!(cycle.cycle_len(), Some(len - entry));
+                })
+                .unwrap();
+                assert!(!list.has_cycle_brent());
+            }
+            assert_eq!(
+                list.link_tail_to(len, |_| ()),
+                Err(ListError::IndexOutOfBounds { index: len, len })
+            );
+            assert_eq!(list.len(), len);
+            assert!(list.iter().copied().eq(0..len));
+        }
+        assert_eq!(
+            LinkedList::<u8>::new().link_tail_to(0, |_| ()),
+            Err(ListError::EmptyList)
+        );
+    }
+
+    #[test]
+    fn a_panicking_inspection_leaves_an_acyclic_list() {
+        let mut list: LinkedList<String> = (0..5).map(|i| i.to_string()).collect();
+        let inspection = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.link_tail_to(1, |_| panic!("inspection failed"))
+                .unwrap();
+        }));
+        assert!(inspection.is_err());
+        assert!(!list.has_cycle());
+        let mut cursor = list.cursor_front_mut();
+        for _ in 0..4 {
+            cursor.move_next();
+        }
+        assert_eq!(cursor.remove_current().as_deref(), Some("4"));
+        list.add_node("5".to_string());
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn digit_arithmetic_matches_integers() {
+        fn digits(mut n: u64) -> LinkedList<u8> {
+            let mut list = LinkedList::new();
+            loop {
+                list.add_node((n % 10) as u8);
+                n /= 10;
+                if n == 0 {
+                    return list;
+                }
+            }
+        }
+        fn value(list: &LinkedList<u8>) -> u64 {
+            list.iter().rev().fold(0, |acc, d| acc * 10 + u64::from(*d))
+        }
+        for &(x, y) in [
+            (0, 0),
+            (0, 7),
+            (9, 1),
+            (999, 1),
+            (12, 99_999),
+            (98_765, 4_321),
+        ]
+        .iter()
+        {
+            let mut sum = LinkedList::add_digits(digits(x), digits(y));
+            assert_eq!(value(&sum), x + y);
+            sum.add_node(0);
+            let product = LinkedList::multiply_digits(digits(x), digits(y));
+            assert_eq!(value(&product), x * y);
+            assert!(product.len() == 1 || product.iter().last() != Some(&0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "255 is not a decimal digit")]
+    fn add_digits_rejects_non_decimal_digits() {
+        let a: LinkedList<u8> = vec![1, 255].into_iter().collect();
+        let b: LinkedList<u8> = vec![9, 255].into_iter().collect();
+        LinkedList::add_digits(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "10 is not a decimal digit")]
+    fn multiply_digits_rejects_non_decimal_digits() {
+        let a: LinkedList<u8> = vec![3].into_iter().collect();
+        let b: LinkedList<u8> = vec![10].into_iter().collect();
+        LinkedList::multiply_digits(a, b);
+    }
+
+    #[test]
+    fn relinking_merges_keep_tail() {
+        let mut merged = LinkedList::merge_sorted((5..8).collect(), (1..3).collect());
+        merged.add_node(10);
+        assert_eq!(
+            format!("{:?}", merged),
+            format!("1 -> 2 -> 5 -> 6 -> 7 -> 10 -> x")
+        );
+        let mut zipped = LinkedList::interleave((1..3).collect(), (5..9).collect());
+        zipped.add_node(0);
+        assert_eq!(
+            format!("{:?}", zipped),
+            format!("1 -> 5 -> 2 -> 6 -> 7 -> 8 -> 0 -> x")
+        );
+        let mut rest = zipped.split_off(0);
+        assert_eq!(zipped.len(), 0);
+        assert_eq!(rest.split_off(7).len(), 0);
+        zipped.append(&mut rest);
+        zipped.add_node(3);
+        assert_eq!(zipped.len(), 8);
+        let mut empty = LinkedList::merge_k_sorted(Vec::<LinkedList<i32>>::new());
+        empty.append(&mut LinkedList::new());
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn group_transforms_agree_with_their_recursive_versions() {
+        type Transform = fn(&mut LinkedList<usize>);
+        let pairs: [(Transform, Transform); 6] = [
+            (|l| l.reverse_k_group(3), |l| l.reverse_k_group_rec(3)),
+            (|l| l.reverse_range(1, 4), |l| l.reverse_range_rec(1, 4)),
+            (
+                |l| {
+                    l.remove_nth_from_end(2);
+                },
+                |l| {
+                    l.remove_nth_from_end_rec(2);
+                },
+            ),
+            (|l| l.swap_pairs(), |l| l.swap_pairs_rec()),
+            (|l| l.reorder_alternating(), |l| l.reorder_alternating_rec()),
+            (
+                |l| l.odd_even_by_position(),
+                |l| l.odd_even_by_position_rec(),
+            ),
+        ];
+        for len in 0..12 {
+            for (iterative, recursive) in pairs.iter() {
+                let mut a: LinkedList<usize> = (0..len).collect();
+                let mut b: LinkedList<usize> = (0..len).collect();
+                iterative(&mut a);
+                recursive(&mut b);
+                assert_eq!(format!("{:?}", a), format!("{:?}", b));
+                assert_eq!(a.len(), b.len());
+                a.add_node(100);
+                b.add_node(100);
+                assert_eq!(a.iter().last(), Some(&100));
+                assert_eq!(b.iter().last(), Some(&100));
+            }
+        }
+    }
+
+    #[test]
+    fn iterators_meet_in_the_middle() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        let mut it = list.iter();
+        assert_eq!(it.len(), 5);
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&5));
+        assert_eq!(it.next_back(), Some(&4));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let mut it = list.iter_mut();
+        *it.next_back().unwrap() = 50;
+        *it.next().unwrap() = 10;
+        assert_eq!(
+            format!("{:?}", list),
+            format!("10 -> 2 -> 3 -> 4 -> 50 -> x")
+        );
+
+        let mut it = list.into_iter();
+        assert_eq!(it.len(), 5);
+        assert_eq!(it.next_back(), Some(50));
+        assert_eq!(it.next(), Some(10));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        let mut it = LinkedList::<i32>::new().into_iter();
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next_back(), None);
+        assert_eq!(it.len(), 0);
+    }
+}
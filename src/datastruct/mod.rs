@@ -1,3 +1,11 @@
+pub mod arena_struct;
+pub mod clist_struct;
+pub mod dlist_struct;
 pub mod list_struct;
+pub(crate) mod node;
+pub mod poly_struct;
+pub mod selforg_struct;
+pub mod skip_struct;
 pub mod stack_struct;
+pub mod unrolled_struct;
 pub mod vec_struct;
@@ -0,0 +1,28 @@
+//! heap allocation of single list members, shared by the pointer based lists
+
+use core::mem;
+use core::ptr;
+use std::alloc;
+
+/// move `node` into a fresh allocation and return the pointer to it
+pub(crate) fn allocate<N>(node: N) -> *mut N {
+    unsafe {
+        let layout =
+            alloc::Layout::from_size_align_unchecked(mem::size_of::<N>(), mem::align_of::<N>());
+        let ptr = alloc::alloc(layout) as *mut N;
+        ptr.write(node);
+        ptr
+    }
+}
+
+/// move a detached member out of its allocation and free the allocation
+///
+/// # Safety
+/// `node` comes from [`allocate`] and nothing reads it afterwards
+pub(crate) unsafe fn free<N>(node: *mut N) -> N {
+    let member = ptr::read(node);
+    let layout =
+        alloc::Layout::from_size_align_unchecked(mem::size_of::<N>(), mem::align_of::<N>());
+    alloc::dealloc(node as *mut u8, layout);
+    member
+}
@@ -0,0 +1,519 @@
+//! polynomials with integer coefficients `Polynomial` stored in a [`LinkedList`]
+//!
+//!A polynomial is kept as a [`LinkedList`] of [`Term`]s sorted by strictly decreasing exponent,
+//!without zero coefficients. Only the terms that are present take memory, so `x^1000 + 1` is two
+//!members. Adding or subtracting walks both lists once like the merge step of merge sort,
+//!multiplying adds one shifted copy of the right operand per term of the left one.
+//!
+//!The text format is the usual one, `3x^2 + 2x - 1`. [`Display`] writes it and
+//![`str::parse`] reads it back, whitespace in the input is ignored.
+//!
+//!Coefficients are `i64` and all arithmetic on them wraps around like [`i64::wrapping_mul`], so
+//!the coefficients of sums, products and derivatives and the values of [`Polynomial::evaluate`]
+//!are computed modulo 2^64. Each of them is exact whenever the exact result fits in an `i64`.
+//!Exponents are `u32` and cannot wrap: multiplying terms whose exponents add up to more than
+//!`u32::MAX` panics, [`Polynomial::checked_mul`] returns `None` instead.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::datastruct::poly_struct::Polynomial;
+//! let p: Polynomial = "3x^2 + 2x - 1".parse().unwrap();
+//! let q: Polynomial = "x - 1".parse().unwrap();
+//! assert_eq!((&p + &q).to_string(), "3x^2 + 3x - 2");
+//! assert_eq!((&p * &q).to_string(), "3x^3 - x^2 - 3x + 1");
+//! assert_eq!(p.evaluate(2), 15);
+//! assert_eq!(p.derivative().to_string(), "6x + 2");
+//! ```
+//!
+//! [`LinkedList`]: crate::datastruct::list_struct::LinkedList
+//! [`Display`]: std::fmt::Display
+
+use crate::datastruct::list_struct::LinkedList;
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// `coeff * x^exp`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Term {
+    pub coeff: i64,
+    pub exp: u32,
+}
+
+pub struct Polynomial {
+    terms: LinkedList<Term>,
+}
+
+/// Reason why a string is not a polynomial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePolynomialError {
+    /// nothing but whitespace
+    Empty,
+    /// a term which is neither a number, `x`, `x^n` nor a number followed by one of them
+    InvalidTerm(String),
+}
+
+impl std::fmt::Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePolynomialError::Empty => write!(f, "cannot parse a polynomial from nothing"),
+            ParsePolynomialError::InvalidTerm(term) => write!(f, "invalid term `{}`", term),
+        }
+    }
+}
+
+impl std::error::Error for ParsePolynomialError {}
+
+impl Polynomial {
+    /// the zero polynomial, it has no terms
+    pub fn new() -> Self {
+        Self {
+            terms: LinkedList::new(),
+        }
+    }
+
+    /// sum of the `(coeff, exp)` terms given in any order
+    /// ```
+    /// # use dsa_sport::datastruct::poly_struct::Polynomial;
+    /// let p = Polynomial::from_terms(vec![(1, 0), (4, 3), (-1, 0), (2, 1)]);
+    /// assert_eq!(p.to_string(), "4x^3 + 2x");
+    /// ```
+    pub fn from_terms<I: IntoIterator<Item = (i64, u32)>>(terms: I) -> Self {
+        let mut polynomial = Self::new();
+        for (coeff, exp) in terms {
+            polynomial.add_term(coeff, exp);
+        }
+        polynomial
+    }
+
+    /// add `coeff * x^exp` in place, in O(n) for the walk to its sorted position, the coefficient
+    /// of `x^exp` wraps around on overflow
+    pub fn add_term(&mut self, coeff: i64, exp: u32) {
+        if coeff == 0 {
+            return;
+        }
+        let term = Term { coeff, exp };
+        let mut cursor = self.terms.cursor_front_mut();
+        match cursor.current() {
+            None => return self.terms.add_node(term),
+            Some(head) if head.exp < exp => return self.terms.insert_node_at(0, term),
+            Some(_) => {}
+        }
+        while matches!(cursor.peek_next(), Some(next) if next.exp > exp) {
+            cursor.move_next();
+        }
+        if matches!(cursor.current(), Some(current) if current.exp != exp) {
+            match cursor.peek_next() {
+                Some(next) if next.exp == exp => cursor.move_next(),
+                _ => return cursor.insert_after(term),
+            }
+        }
+        if let Some(current) = cursor.current() {
+            current.coeff = current.coeff.wrapping_add(coeff);
+            if current.coeff == 0 {
+                cursor.remove_current();
+            }
+        }
+    }
+
+    /// terms by decreasing exponent
+    pub fn terms(&self) -> impl Iterator<Item = &Term> {
+        self.terms.iter()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.iter().next().is_none()
+    }
+
+    /// highest exponent, `None` for the zero polynomial
+    pub fn degree(&self) -> Option<u32> {
+        self.terms.iter().next().map(|term| term.exp)
+    }
+
+    /// coefficient of `x^exp`, zero when the term is missing
+    pub fn coefficient(&self, exp: u32) -> i64 {
+        self.terms
+            .iter()
+            .find(|term| term.exp == exp)
+            .map_or(0, |term| term.coeff)
+    }
+
+    /// Value at `x` by Horner's method: `3x^2 + 2x - 1` is `(3x + 2)x - 1`. Between two present
+    /// terms the value is multiplied by `x` to the power of the gap in their exponents, computed
+    /// by squaring, so a sparse polynomial costs O(log degree) per term. The value wraps around
+    /// on overflow, it is exact whenever the exact value fits in an `i64`.
+    /// ```
+    /// # use dsa_sport::datastruct::poly_struct::Polynomial;
+    /// let p: Polynomial = "2x^3 - x + 5".parse().unwrap();
+    /// assert_eq!(p.evaluate(-2), -9);
+    /// assert_eq!(Polynomial::new().evaluate(7), 0);
+    /// let sparse: Polynomial = "x^4000000000 + 1".parse().unwrap();
+    /// assert_eq!(sparse.evaluate(-1), 2);
+    /// ```
+    pub fn evaluate(&self, x: i64) -> i64 {
+        let mut value: i64 = 0;
+        let mut previous_exp = None;
+        for term in self.terms.iter() {
+            if let Some(previous) = previous_exp {
+                value = value.wrapping_mul(x.wrapping_pow(previous - term.exp));
+            }
+            value = value.wrapping_add(term.coeff);
+            previous_exp = Some(term.exp);
+        }
+        value.wrapping_mul(x.wrapping_pow(previous_exp.unwrap_or(0)))
+    }
+
+    /// `d/dx`, the constant term drops out
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial {
+            terms: self
+                .terms
+                .iter()
+                .filter(|term| term.exp > 0)
+                .map(|term| Term {
+                    coeff: term.coeff.wrapping_mul(i64::from(term.exp)),
+                    exp: term.exp - 1,
+                })
+                .filter(|term| term.coeff != 0)
+                .collect(),
+        }
+    }
+
+    /// product like `&self * other`, `None` when a term of it would have an exponent above
+    /// `u32::MAX`
+    /// ```
+    /// # use dsa_sport::datastruct::poly_struct::Polynomial;
+    /// let p: Polynomial = "x^3000000000 + 1".parse().unwrap();
+    /// assert!(p.checked_mul(&p).is_none());
+    /// let q: Polynomial = "x - 1".parse().unwrap();
+    /// assert_eq!(q.checked_mul(&q).unwrap().to_string(), "x^2 - 2x + 1");
+    /// ```
+    pub fn checked_mul(&self, other: &Polynomial) -> Option<Polynomial> {
+        match (self.degree(), other.degree()) {
+            (Some(left), Some(right)) if left.checked_add(right).is_none() => None,
+            _ => Some(self.multiply(other)),
+        }
+    }
+
+    /// Merge two sorted term lists in O(n + m), the right side is scaled by `sign` first.
+    fn combine(&self, other: &Polynomial, sign: i64) -> Polynomial {
+        let mut sum = LinkedList::new();
+        let mut left = self.terms.iter().peekable();
+        let mut right = other.terms.iter().map(|term| Term {
+            coeff: sign.wrapping_mul(term.coeff),
+            exp: term.exp,
+        });
+        let mut pending = right.next();
+        loop {
+            let term = match (left.peek(), pending) {
+                (None, None) => break,
+                (Some(&&l), Some(r)) if l.exp == r.exp => {
+                    left.next();
+                    pending = right.next();
+                    Term {
+                        coeff: l.coeff.wrapping_add(r.coeff),
+                        exp: l.exp,
+                    }
+                }
+                (Some(&&l), Some(r)) if l.exp < r.exp => {
+                    pending = right.next();
+                    r
+                }
+                (None, Some(r)) => {
+                    pending = right.next();
+                    r
+                }
+                (Some(&&l), _) => {
+                    left.next();
+                    l
+                }
+            };
+            if term.coeff != 0 {
+                sum.add_node(term);
+            }
+        }
+        Polynomial { terms: sum }
+    }
+
+    /// One shifted and scaled copy of `other` per term of `self`, added up one after another.
+    ///
+    /// # Panics
+    /// Panics if the degrees add up to more than `u32::MAX`.
+    fn multiply(&self, other: &Polynomial) -> Polynomial {
+        let mut product = Polynomial::new();
+        for term in self.terms.iter() {
+            // scaling and shifting keeps the terms sorted, so they are linked as they come
+            let partial = Polynomial {
+                terms: other
+                    .terms
+                    .iter()
+                    .map(|t| Term {
+                        coeff: t.coeff.wrapping_mul(term.coeff),
+                        exp: t
+                            .exp
+                            .checked_add(term.exp)
+                            .expect("exponent of the product does not fit in a u32"),
+                    })
+                    .filter(|t| t.coeff != 0)
+                    .collect(),
+            };
+            product = product.combine(&partial, 1);
+        }
+        product
+    }
+}
+
+impl<'a> Add<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &Polynomial) -> Polynomial {
+        self.combine(other, 1)
+    }
+}
+
+impl<'a> Sub<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: &Polynomial) -> Polynomial {
+        self.combine(other, -1)
+    }
+}
+
+impl<'a> Mul<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    /// # Panics
+    /// Panics if the degrees add up to more than `u32::MAX`, see [`Polynomial::checked_mul`].
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        self.multiply(other)
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        &Polynomial::new() - self
+    }
+}
+
+impl FromIterator<Term> for Polynomial {
+    fn from_iter<I: IntoIterator<Item = Term>>(iter: I) -> Self {
+        Polynomial::from_terms(iter.into_iter().map(|term| (term.coeff, term.exp)))
+    }
+}
+
+impl Clone for Polynomial {
+    fn clone(&self) -> Self {
+        Polynomial {
+            terms: self.terms.iter().copied().collect(),
+        }
+    }
+}
+
+impl PartialEq for Polynomial {
+    fn eq(&self, other: &Polynomial) -> bool {
+        self.terms.iter().eq(other.terms.iter())
+    }
+}
+
+impl Default for Polynomial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.terms)
+    }
+}
+
+impl std::fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        for (i, term) in self.terms.iter().enumerate() {
+            let magnitude = term.coeff.unsigned_abs();
+            match (i, term.coeff < 0) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => {}
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+            if magnitude != 1 || term.exp == 0 {
+                write!(f, "{}", magnitude)?;
+            }
+            match term.exp {
+                0 => {}
+                1 => write!(f, "x")?,
+                exp => write!(f, "x^{}", exp)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Polynomial {
+    type Err = ParsePolynomialError;
+
+    /// ```
+    /// # use dsa_sport::datastruct::poly_struct::{ParsePolynomialError, Polynomial};
+    /// let p: Polynomial = " -x^3+ 4x -2x + 7 ".parse().unwrap();
+    /// assert_eq!(p.to_string(), "-x^3 + 2x + 7");
+    /// assert_eq!("".parse::<Polynomial>(), Err(ParsePolynomialError::Empty));
+    /// assert_eq!(
+    ///     "3y + 1".parse::<Polynomial>(),
+    ///     Err(ParsePolynomialError::InvalidTerm("3y".to_string()))
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if text.is_empty() {
+            return Err(ParsePolynomialError::Empty);
+        }
+        let mut polynomial = Polynomial::new();
+        let mut start = 0;
+        for (i, c) in text.char_indices().skip(1) {
+            if (c == '+' || c == '-') && !text[..i].ends_with('^') {
+                polynomial.add_term_str(&text[start..i])?;
+                start = i;
+            }
+        }
+        polynomial.add_term_str(&text[start..])?;
+        Ok(polynomial)
+    }
+}
+
+impl Polynomial {
+    /// parse one signed term like `-3x^2`, `+x`, `7`, and add it
+    fn add_term_str(&mut self, term: &str) -> Result<(), ParsePolynomialError> {
+        let invalid = || ParsePolynomialError::InvalidTerm(term.to_string());
+        let (negative, body) = match term.as_bytes().first() {
+            Some(b'-') => (true, &term[1..]),
+            Some(b'+') => (false, &term[1..]),
+            _ => (false, term),
+        };
+        // the magnitude is read unsigned so that `-9223372036854775808` fits
+        let signed = |digits: &str| {
+            if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+                return None;
+            }
+            let magnitude = i128::from(digits.parse::<u64>().ok()?);
+            i64::try_from(if negative { -magnitude } else { magnitude }).ok()
+        };
+        let (coeff, exp) = match body.find('x') {
+            None => (signed(body).ok_or_else(invalid)?, 0),
+            Some(at) => {
+                let coeff = match &body[..at] {
+                    "" if negative => -1,
+                    "" => 1,
+                    digits => signed(digits).ok_or_else(invalid)?,
+                };
+                let exp = match &body[at + 1..] {
+                    "" => 1,
+                    power => match power.strip_prefix('^') {
+                        Some(digits) => digits.parse::<u32>().map_err(|_| invalid())?,
+                        None => return Err(invalid()),
+                    },
+                };
+                (coeff, exp)
+            }
+        };
+        self.add_term(coeff, exp);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prng::XorShift64;
+
+    fn random_polynomial(rng: &mut XorShift64) -> Polynomial {
+        let terms = rng.below(6);
+        Polynomial::from_terms((0..terms).map(|_| {
+            let coeff = rng.below(11) as i64 - 5;
+            (coeff, rng.below(6) as u32)
+        }))
+    }
+
+    #[test]
+    fn arithmetic_agrees_with_evaluation() {
+        let mut rng = XorShift64::new(41);
+        for _ in 0..300 {
+            let p = random_polynomial(&mut rng);
+            let q = random_polynomial(&mut rng);
+            for x in -3..=3 {
+                assert_eq!((&p + &q).evaluate(x), p.evaluate(x) + q.evaluate(x));
+                assert_eq!((&p - &q).evaluate(x), p.evaluate(x) - q.evaluate(x));
+                assert_eq!((&p * &q).evaluate(x), p.evaluate(x) * q.evaluate(x));
+            }
+            assert!((&p - &p).is_zero());
+            assert_eq!(-&(-&p), p);
+            assert_eq!(p.to_string().parse::<Polynomial>(), Ok(p.clone()));
+            let exps: Vec<u32> = (&p * &q).terms().map(|t| t.exp).collect();
+            assert!(exps.windows(2).all(|w| w[0] > w[1]));
+        }
+    }
+
+    #[test]
+    fn derivative_and_degree() {
+        let p: Polynomial = "x^4 - 3x^2 + x - 8".parse().unwrap();
+        assert_eq!(p.degree(), Some(4));
+        assert_eq!(p.coefficient(2), -3);
+        assert_eq!(p.coefficient(3), 0);
+        assert_eq!(p.derivative().to_string(), "4x^3 - 6x + 1");
+        assert_eq!(p.derivative().derivative().derivative().to_string(), "24x");
+        assert_eq!(
+            Polynomial::from_terms(vec![(5, 0)])
+                .derivative()
+                .to_string(),
+            "0"
+        );
+        assert_eq!(Polynomial::new().degree(), None);
+        for bad in ["x^", "2x3", "1 + + 2", "x^-1", "--x"].iter() {
+            assert!(bad.parse::<Polynomial>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn coefficients_wrap_and_extreme_values_round_trip() {
+        let mut rng = XorShift64::new(41);
+        let mut wide = || {
+            Polynomial::from_terms(
+                (0..4).map(|_| (rng.next_u64() as i64, rng.below(1 << 20) as u32)),
+            )
+        };
+        for _ in 0..100 {
+            let (p, q) = (wide(), wide());
+            for &x in [-3, 2, i64::MAX, i64::MIN].iter() {
+                let (a, b) = (p.evaluate(x), q.evaluate(x));
+                assert_eq!((&p + &q).evaluate(x), a.wrapping_add(b));
+                assert_eq!((&p * &q).evaluate(x), a.wrapping_mul(b));
+            }
+            assert_eq!(p.to_string().parse::<Polynomial>(), Ok(p.clone()));
+        }
+        let min = Polynomial::from_terms(vec![(i64::MIN, 1), (-1, 0)]);
+        assert_eq!(min.to_string(), "-9223372036854775808x - 1");
+        assert_eq!(min.to_string().parse::<Polynomial>(), Ok(min.clone()));
+        assert_eq!((-&min).to_string(), "-9223372036854775808x + 1");
+        assert_eq!(min.derivative().to_string(), "-9223372036854775808");
+        assert!("9223372036854775808x".parse::<Polynomial>().is_err());
+        let doubled = &min + &min;
+        assert_eq!(doubled.to_string(), "-2");
+        let sparse: Polynomial = "x^4294967295 - x^3".parse().unwrap();
+        assert_eq!(sparse.evaluate(1), 0);
+        assert_eq!(sparse.evaluate(2), -8);
+        assert_eq!(sparse.evaluate(-1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "exponent of the product does not fit in a u32")]
+    fn exponent_overflow_panics() {
+        let p: Polynomial = "x^3000000000".parse().unwrap();
+        let _ = &p * &p;
+    }
+}
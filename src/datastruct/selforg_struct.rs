@@ -0,0 +1,279 @@
+//! self organizing list `SelfOrganizingList<T>` with move-to-front, transpose and count
+//!
+//!A linear search in a [`LinkedList`] costs one comparison per member it passes, so a list that
+//!keeps its popular elements near the head is searched faster. A self organizing list reorders
+//!itself on every [`SelfOrganizingList::access`] following a [`Heuristic`], without knowing the
+//!requests in advance.
+//!
+//!Accessing the element at 0-based position `i` costs `i + 1` comparisons, a search for a missing
+//!element costs one comparison per member. The list adds up the cost of every access so
+//!heuristics can be compared on the same trace, and [`static_optimum_cost`] gives the cost of the
+//!best fixed order for that trace. The fixed order is free to start sorted, while the list has
+//!to get there from the order it was built in. Sleator and Tarjan (1985) showed that
+//!move-to-front never pays more than twice the optimum plus the number of pairs it has to swap to
+//!get there, which is at most `n (n - 1) / 2` for `n` elements. On a long trace the additive term
+//!fades and move-to-front is 2-competitive, on a short one it can cost far more than twice the
+//!optimum.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::datastruct::selforg_struct::{Heuristic, SelfOrganizingList};
+//! let mut list = SelfOrganizingList::with_items(Heuristic::MoveToFront, vec!['a', 'b', 'c']);
+//! assert_eq!(list.access(&'c'), Some(2));
+//! assert_eq!(format!("{:?}",list), format!("'c' -> 'a' -> 'b' -> x"));
+//! assert_eq!(list.access(&'c'), Some(0));
+//! assert_eq!(list.total_cost(), 3 + 1);
+//! ```
+
+use crate::datastruct::list_struct::{Iter, LinkedList};
+use core::cmp::Reverse;
+
+/// How a [`SelfOrganizingList`] reorders itself after finding an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heuristic {
+    /// the accessed member becomes the head
+    MoveToFront,
+    /// the accessed member swaps places with the member before it
+    Transpose,
+    /// every member counts its accesses and the list stays sorted by decreasing count, an
+    /// accessed member moves in front of every member with a smaller count
+    FrequencyCount,
+}
+
+/// A [`LinkedList`] searched with [`LinkedList::find`] and reordered with its cursor.
+pub struct SelfOrganizingList<T> {
+    list: LinkedList<T>,
+    /// access count of the member at every position, its length is the length of the list
+    counts: Vec<usize>,
+    heuristic: Heuristic,
+    cost: usize,
+}
+
+impl<T> SelfOrganizingList<T>
+where
+    T: PartialOrd + std::fmt::Debug,
+{
+    pub fn new(heuristic: Heuristic) -> Self {
+        Self {
+            list: LinkedList::new(),
+            counts: Vec::new(),
+            heuristic,
+            cost: 0,
+        }
+    }
+
+    /// list holding `items` in the given order
+    pub fn with_items<I: IntoIterator<Item = T>>(heuristic: Heuristic, items: I) -> Self {
+        let mut list = Self::new(heuristic);
+        for element in items {
+            list.add_node(element);
+        }
+        list
+    }
+
+    /// add node from the tail position, its access count starts at zero
+    pub fn add_node(&mut self, element: T) {
+        self.list.add_node(element);
+        self.counts.push(0);
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    pub fn heuristic(&self) -> Heuristic {
+        self.heuristic
+    }
+
+    /// comparisons spent by every access since the list was built or the cost was reset
+    pub fn total_cost(&self) -> usize {
+        self.cost
+    }
+
+    pub fn reset_cost(&mut self) {
+        self.cost = 0;
+    }
+
+    /// Search `element` with [`LinkedList::find`] and return the index it was found at, then
+    /// reorder the list by the heuristic. The search cost is added to
+    /// [`SelfOrganizingList::total_cost`].
+    /// ```
+    /// # use dsa_sport::datastruct::selforg_struct::{Heuristic, SelfOrganizingList};
+    /// let mut list = SelfOrganizingList::with_items(Heuristic::Transpose, 1..=4);
+    /// assert_eq!(list.access(&4), Some(3));
+    /// assert_eq!(list.access(&4), Some(2));
+    /// assert_eq!(format!("{:?}",list), format!("1 -> 4 -> 2 -> 3 -> x"));
+    /// assert_eq!(list.access(&9), None);
+    /// assert_eq!(list.total_cost(), 4 + 3 + 4);
+    /// ```
+    pub fn access(&mut self, element: &T) -> Option<usize> {
+        let index = match self.list.find(element) {
+            Some(index) => index,
+            None => {
+                self.cost += self.len();
+                return None;
+            }
+        };
+        self.cost += index + 1;
+        self.counts[index] += 1;
+        let target = match self.heuristic {
+            Heuristic::MoveToFront => 0,
+            Heuristic::Transpose => index.saturating_sub(1),
+            Heuristic::FrequencyCount => self.counts[..index]
+                .iter()
+                .position(|&count| count < self.counts[index])
+                .unwrap_or(index),
+        };
+        self.move_member(index, target);
+        Some(index)
+    }
+
+    /// access every element of `trace` in turn and return what the whole trace cost
+    /// ```
+    /// # use dsa_sport::datastruct::selforg_struct::{Heuristic, SelfOrganizingList};
+    /// let trace = [3, 3, 3, 1, 3, 3];
+    /// let mut mtf = SelfOrganizingList::with_items(Heuristic::MoveToFront, 1..=3);
+    /// let mut count = SelfOrganizingList::with_items(Heuristic::FrequencyCount, 1..=3);
+    /// assert_eq!(mtf.run_trace(&trace), 3 + 1 + 1 + 2 + 2 + 1);
+    /// assert_eq!(count.run_trace(&trace), 3 + 1 + 1 + 2 + 1 + 1);
+    /// ```
+    pub fn run_trace(&mut self, trace: &[T]) -> usize {
+        let before = self.cost;
+        for element in trace {
+            self.access(element);
+        }
+        self.cost - before
+    }
+
+    /// borrow every element from head to tail
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// unlink the member at `from` and link it back in at `to`, which is not after it
+    fn move_member(&mut self, from: usize, to: usize) {
+        if from == to {
+            return;
+        }
+        let mut cursor = self.list.cursor_front_mut();
+        for _ in 0..from {
+            cursor.move_next();
+        }
+        let element = cursor.remove_current().expect("the member was just found");
+        self.list.insert_node_at(to, element);
+        let count = self.counts.remove(from);
+        self.counts.insert(to, count);
+    }
+}
+
+/// Cost of `trace` on the best list that never moves: the elements sorted by decreasing number
+/// of requests. Only the requested elements are placed, every other element would sit behind
+/// them and is never paid for.
+/// ```
+/// # use dsa_sport::datastruct::selforg_struct::static_optimum_cost;
+/// // 'b' requested three times goes first, 'a' twice second
+/// assert_eq!(static_optimum_cost(&['a', 'b', 'b', 'a', 'b']), 3 * 1 + 2 * 2);
+/// ```
+pub fn static_optimum_cost<T: PartialEq>(trace: &[T]) -> usize {
+    let mut frequencies: Vec<(&T, usize)> = Vec::new();
+    for element in trace {
+        match frequencies.iter_mut().find(|(x, _)| *x == element) {
+            Some((_, count)) => *count += 1,
+            None => frequencies.push((element, 1)),
+        }
+    }
+    frequencies.sort_by_key(|&(_, count)| Reverse(count));
+    frequencies
+        .iter()
+        .enumerate()
+        .map(|(index, (_, count))| (index + 1) * count)
+        .sum()
+}
+
+impl<T> std::fmt::Debug for SelfOrganizingList<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prng::XorShift64;
+
+    fn skewed_trace(seed: u64, n: usize, len: usize) -> Vec<usize> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| {
+                // roughly Zipf: element i is requested about twice as often as element i + 1
+                let coins = rng.next_u64().trailing_ones() as usize;
+                coins.min(n - 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn move_to_front_is_two_competitive_up_to_the_initial_order() {
+        let initial_order = 16 * 15 / 2;
+        for seed in 1..20 {
+            let trace = skewed_trace(seed, 16, 500);
+            let mut mtf = SelfOrganizingList::with_items(Heuristic::MoveToFront, (0..16).rev());
+            let optimum = static_optimum_cost(&trace);
+            assert!(mtf.run_trace(&trace) <= 2 * optimum + initial_order);
+        }
+    }
+
+    #[test]
+    fn move_to_front_pays_for_the_initial_order() {
+        // the only requested element starts at the back, the static optimum puts it first
+        let mut mtf =
+            SelfOrganizingList::with_items(Heuristic::MoveToFront, (1..=100).chain(Some(0)));
+        let cost = mtf.run_trace(&[0]);
+        let optimum = static_optimum_cost(&[0]);
+        assert_eq!((cost, optimum), (101, 1));
+        assert!(cost > 2 * optimum);
+        assert!(cost <= 2 * optimum + 101 * 100 / 2);
+    }
+
+    #[test]
+    fn heuristics_keep_every_element() {
+        let trace = skewed_trace(7, 10, 300);
+        for &heuristic in [
+            Heuristic::MoveToFront,
+            Heuristic::Transpose,
+            Heuristic::FrequencyCount,
+        ]
+        .iter()
+        {
+            let mut list = SelfOrganizingList::with_items(heuristic, (0..10).rev());
+            let cost = list.run_trace(&trace);
+            assert_eq!(list.total_cost(), cost);
+            let mut items: Vec<usize> = list.iter().copied().collect();
+            items.sort_unstable();
+            assert_eq!(items, (0..10).collect::<Vec<_>>());
+            assert_eq!(list.len(), 10);
+            assert!(list.access(&0).is_some(), "{:?}", heuristic);
+            list.add_node(10);
+            assert_eq!(format!("{:?}", list).matches("->").count(), 11);
+        }
+    }
+
+    #[test]
+    fn frequency_count_stays_sorted() {
+        let mut list = SelfOrganizingList::with_items(Heuristic::FrequencyCount, "abcd".chars());
+        for c in "dcdbdc".chars() {
+            list.access(&c);
+        }
+        assert_eq!(list.iter().collect::<String>(), "dcba");
+        list.reset_cost();
+        assert_eq!(list.access(&'a'), Some(3));
+        assert_eq!(list.total_cost(), 4);
+    }
+}
@@ -0,0 +1,488 @@
+//! probabilistic ordered map `SkipList<K, V>`
+//!
+//!A skip list is a sorted [`LinkedList`] with express lanes. Every member is on level 0 and, by
+//!flipping coins, on each further level with probability 1/2, so level `i` skips about `2^i`
+//!members at a time. A search runs along the highest level until it would overshoot and drops
+//!down one level, which takes O(log n) expected steps. Insertion and removal only relink the
+//!members found on the way down.
+//!
+//!Every link also stores its span, the number of level 0 steps it jumps over. Summing spans on
+//!the way down gives the rank of a key and following them backwards selects the member at an
+//!index, both in O(log n).
+//!
+//!Coins are flipped by a seeded [`XorShift64`], two lists built with the same seed and the same
+//!operations have exactly the same shape.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::datastruct::skip_struct::SkipList;
+//! let mut map = SkipList::new();
+//! map.insert(30, "c");
+//! map.insert(10, "a");
+//! map.insert(20, "b");
+//! assert_eq!(map.get(&20), Some(&"b"));
+//! assert_eq!(map.rank(&30), Some(2));
+//! assert_eq!(map.select(0), Some((&10, &"a")));
+//! assert_eq!(map.range(15..).map(|(k, _)| *k).collect::<Vec<_>>(), vec![20, 30]);
+//! ```
+//!
+//! [`LinkedList`]: crate::datastruct::list_struct::LinkedList
+
+use crate::prng::XorShift64;
+use core::ptr;
+use std::ops::{Bound, RangeBounds};
+
+/// no member is ever taller than this, enough for far more members than fit in memory
+const MAX_LEVEL: usize = 32;
+
+pub struct SkipList<K, V> {
+    head: *mut Member<K, V>,
+    level: usize,
+    length: usize,
+    rng: XorShift64,
+}
+
+struct Member<K, V> {
+    /// `None` only for the head sentinel
+    entry: Option<(K, V)>,
+    forward: Vec<*mut Member<K, V>>,
+    span: Vec<usize>,
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    /// empty list flipping its coins with a fixed default seed
+    pub fn new() -> Self {
+        Self::with_seed(0x5EED)
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            head: Member::allocate_memory(None, MAX_LEVEL),
+            level: 1,
+            length: 0,
+            rng: XorShift64::new(seed),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// insert a key in O(log n) expected, the old value is returned when the key was present
+    /// ```
+    /// # use dsa_sport::datastruct::skip_struct::SkipList;
+    /// let mut map = SkipList::new();
+    /// assert_eq!(map.insert("k", 1), None);
+    /// assert_eq!(map.insert("k", 2), Some(1));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut update = [self.head; MAX_LEVEL];
+        let mut rank = [0usize; MAX_LEVEL];
+        let mut x = self.head;
+        unsafe {
+            for i in (0..self.level).rev() {
+                rank[i] = if i + 1 == self.level { 0 } else { rank[i + 1] };
+                while Member::key_before(x, i, &key) {
+                    rank[i] += (&*x).span[i];
+                    x = (&*x).forward[i];
+                }
+                update[i] = x;
+            }
+            let candidate = (&*x).forward[0];
+            if let Some((k, v)) = Member::entry_mut(candidate) {
+                if *k == key {
+                    return Some(std::mem::replace(v, value));
+                }
+            }
+
+            let height = self.random_level();
+            if height > self.level {
+                for i in self.level..height {
+                    rank[i] = 0;
+                    update[i] = self.head;
+                    (&mut *self.head).span[i] = self.length;
+                }
+                self.level = height;
+            }
+            let node = Member::allocate_memory(Some((key, value)), height);
+            for i in 0..height {
+                (&mut *node).forward[i] = (&*update[i]).forward[i];
+                (&mut *update[i]).forward[i] = node;
+                (&mut *node).span[i] = (&*update[i]).span[i] - (rank[0] - rank[i]);
+                (&mut *update[i]).span[i] = rank[0] - rank[i] + 1;
+            }
+            for (i, &prev) in update.iter().enumerate().take(self.level).skip(height) {
+                (&mut *prev).span[i] += 1;
+            }
+        }
+        self.length += 1;
+        None
+    }
+
+    /// remove a key in O(log n) expected and return its value
+    /// ```
+    /// # use dsa_sport::datastruct::skip_struct::SkipList;
+    /// let mut map: SkipList<i32, char> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+    /// assert_eq!(map.remove(&1), Some('a'));
+    /// assert_eq!(map.remove(&1), None);
+    /// assert_eq!(map.rank(&2), Some(0));
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let mut update = [self.head; MAX_LEVEL];
+        let mut x = self.head;
+        unsafe {
+            for i in (0..self.level).rev() {
+                while Member::key_before(x, i, key) {
+                    x = (&*x).forward[i];
+                }
+                update[i] = x;
+            }
+            let target = (&*x).forward[0];
+            match Member::entry_mut(target) {
+                Some((k, _)) if *k == *key => {}
+                _ => return None,
+            }
+            for (i, &prev) in update.iter().enumerate().take(self.level) {
+                if (&*prev).forward[i] == target {
+                    (&mut *prev).span[i] += (&*target).span[i];
+                    (&mut *prev).span[i] -= 1;
+                    (&mut *prev).forward[i] = (&*target).forward[i];
+                } else {
+                    (&mut *prev).span[i] -= 1;
+                }
+            }
+            while self.level > 1 && (&*self.head).forward[self.level - 1].is_null() {
+                self.level -= 1;
+            }
+            self.length -= 1;
+            Member::deallocate_memory(target).map(|(_, v)| v)
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let node = self.find(key);
+        unsafe { Member::entry_ref(node).map(|(_, v)| v) }
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let node = self.find(key);
+        unsafe { Member::entry_mut(node).map(|(_, v)| v) }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        !self.find(key).is_null()
+    }
+
+    /// number of keys smaller than `key` if it is present, that is its index in sorted order
+    pub fn rank(&self, key: &K) -> Option<usize> {
+        let mut x = self.head;
+        let mut traversed = 0;
+        unsafe {
+            for i in (0..self.level).rev() {
+                while Member::key_before(x, i, key) {
+                    traversed += (&*x).span[i];
+                    x = (&*x).forward[i];
+                }
+            }
+            match Member::entry_ref((&*x).forward[0]) {
+                Some((k, _)) if *k == *key => Some(traversed),
+                _ => None,
+            }
+        }
+    }
+
+    /// the entry at `index` in sorted order, found by following spans in O(log n) expected
+    pub fn select(&self, index: usize) -> Option<(&K, &V)> {
+        if index >= self.length {
+            return None;
+        }
+        let target = index + 1;
+        let mut x = self.head;
+        let mut traversed = 0;
+        unsafe {
+            for i in (0..self.level).rev() {
+                while !(&*x).forward[i].is_null() && traversed + (&*x).span[i] <= target {
+                    traversed += (&*x).span[i];
+                    x = (&*x).forward[i];
+                }
+                if traversed == target {
+                    break;
+                }
+            }
+            Member::entry_ref(x).map(|(k, v)| (k, v))
+        }
+    }
+
+    /// entries whose key falls in `range`, in sorted order, none when the range ends before it
+    /// starts
+    /// ```
+    /// # use dsa_sport::datastruct::skip_struct::SkipList;
+    /// let map: SkipList<i32, i32> = (0..10).map(|i| (i, i * i)).collect();
+    /// let squares: Vec<i32> = map.range(3..=5).map(|(_, v)| *v).collect();
+    /// assert_eq!(squares, vec![9, 16, 25]);
+    /// assert_eq!(map.range(5..3).count(), 0);
+    /// ```
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Iter<'_, K, V> {
+        let start = match range.start_bound() {
+            Bound::Unbounded => unsafe { (&*self.head).forward[0] },
+            Bound::Included(key) => self.first_where(|k| k >= key),
+            Bound::Excluded(key) => self.first_where(|k| k > key),
+        };
+        let stop = match range.end_bound() {
+            Bound::Unbounded => ptr::null_mut(),
+            Bound::Included(key) => self.first_where(|k| k > key),
+            Bound::Excluded(key) => self.first_where(|k| k >= key),
+        };
+        // an inverted range finds its stop before its start and would otherwise run to the end
+        let inverted = unsafe {
+            match (Member::entry_ref(start), Member::entry_ref(stop)) {
+                (Some((first, _)), Some((last, _))) => last < first,
+                _ => false,
+            }
+        };
+        let start = if inverted { stop } else { start };
+        Iter {
+            node: start,
+            stop,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    /// every entry in sorted order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.range(..)
+    }
+
+    /// `histogram[h - 1]` is the number of members which are exactly `h` levels tall, about half
+    /// as many for every extra level
+    /// ```
+    /// # use dsa_sport::datastruct::skip_struct::SkipList;
+    /// let map: SkipList<u32, ()> = (0..1024).map(|i| (i, ())).collect();
+    /// let histogram = map.level_histogram();
+    /// assert_eq!(histogram.iter().sum::<usize>(), 1024);
+    /// assert!(histogram[0] > histogram[1] && histogram[1] > histogram[2]);
+    /// ```
+    pub fn level_histogram(&self) -> Vec<usize> {
+        let mut histogram = vec![0; self.level];
+        let mut x = unsafe { (&*self.head).forward[0] };
+        while !x.is_null() {
+            unsafe {
+                histogram[(&*x).forward.len() - 1] += 1;
+                x = (&*x).forward[0];
+            }
+        }
+        histogram
+    }
+
+    /// node holding `key`, null if it is absent
+    fn find(&self, key: &K) -> *mut Member<K, V> {
+        let mut x = self.head;
+        unsafe {
+            for i in (0..self.level).rev() {
+                while Member::key_before(x, i, key) {
+                    x = (&*x).forward[i];
+                }
+            }
+            let candidate = (&*x).forward[0];
+            match Member::entry_ref(candidate) {
+                Some((k, _)) if *k == *key => candidate,
+                _ => ptr::null_mut(),
+            }
+        }
+    }
+
+    /// first member whose key satisfies a predicate that is monotone in the key order
+    fn first_where<F: Fn(&K) -> bool>(&self, pred: F) -> *mut Member<K, V> {
+        let mut x = self.head;
+        unsafe {
+            for i in (0..self.level).rev() {
+                while let Some((k, _)) = Member::entry_ref((&*x).forward[i]) {
+                    if pred(k) {
+                        break;
+                    }
+                    x = (&*x).forward[i];
+                }
+            }
+            (&*x).forward[0]
+        }
+    }
+
+    fn random_level(&mut self) -> usize {
+        let coins = self.rng.next_u64().trailing_ones() as usize;
+        (coins + 1).min(MAX_LEVEL)
+    }
+}
+
+impl<K, V> Member<K, V> {
+    fn allocate_memory(entry: Option<(K, V)>, height: usize) -> *mut Member<K, V> {
+        Box::into_raw(Box::new(Member {
+            entry,
+            forward: vec![ptr::null_mut(); height],
+            span: vec![0; height],
+        }))
+    }
+
+    unsafe fn deallocate_memory(node: *mut Member<K, V>) -> Option<(K, V)> {
+        Box::from_raw(node).entry
+    }
+
+    /// whether the member after `x` on level `i` exists and has a smaller key
+    unsafe fn key_before(x: *mut Member<K, V>, i: usize, key: &K) -> bool
+    where
+        K: Ord,
+    {
+        match Member::entry_ref((&*x).forward[i]) {
+            Some((k, _)) => k < key,
+            None => false,
+        }
+    }
+
+    unsafe fn entry_ref<'a>(node: *mut Member<K, V>) -> Option<&'a (K, V)> {
+        if node.is_null() {
+            return None;
+        }
+        (*node).entry.as_ref()
+    }
+
+    unsafe fn entry_mut<'a>(node: *mut Member<K, V>) -> Option<(&'a K, &'a mut V)> {
+        if node.is_null() {
+            return None;
+        }
+        (*node).entry.as_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+/// Iterator over the entries of a [`SkipList`] returned by [`SkipList::range`] and
+/// [`SkipList::iter`], it walks level 0.
+pub struct Iter<'a, K, V> {
+    node: *mut Member<K, V>,
+    stop: *mut Member<K, V>,
+    marker: std::marker::PhantomData<&'a Member<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        if self.node.is_null() || self.node == self.stop {
+            return None;
+        }
+        unsafe {
+            let node = self.node;
+            self.node = (&*node).forward[0];
+            Member::entry_ref(node).map(|(k, v)| (k, v))
+        }
+    }
+}
+
+impl<K: Ord, V> std::iter::FromIterator<(K, V)> for SkipList<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = SkipList::new();
+        for (k, v) in iter {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> std::fmt::Debug for SkipList<K, V>
+where
+    K: Ord + std::fmt::Debug,
+    V: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> Drop for SkipList<K, V> {
+    fn drop(&mut self) {
+        let mut x = self.head;
+        while !x.is_null() {
+            unsafe {
+                let next = (&*x).forward[0];
+                Member::deallocate_memory(x);
+                x = next;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn behaves_like_btree_map() {
+        let mut rng = XorShift64::new(11);
+        let mut skip = SkipList::with_seed(3);
+        let mut model = BTreeMap::new();
+        for _ in 0..5000 {
+            let key = rng.below(500) as i32;
+            if rng.below(3) == 0 {
+                assert_eq!(skip.remove(&key), model.remove(&key));
+            } else {
+                assert_eq!(skip.insert(key, key * 2), model.insert(key, key * 2));
+            }
+        }
+        assert_eq!(skip.len(), model.len());
+        let expected: Vec<(&i32, &i32)> = model.iter().collect();
+        assert_eq!(skip.iter().collect::<Vec<_>>(), expected);
+        for (index, (key, value)) in model.iter().enumerate() {
+            assert_eq!(skip.rank(key), Some(index));
+            assert_eq!(skip.select(index), Some((key, value)));
+            assert_eq!(skip.get(key), Some(value));
+        }
+        assert_eq!(skip.select(model.len()), None);
+        let expected: Vec<&i32> = model.range(100..=200).map(|(k, _)| k).collect();
+        assert_eq!(
+            skip.range(100..=200).map(|(k, _)| k).collect::<Vec<_>>(),
+            expected
+        );
+        let expected: Vec<&i32> = model.range(..42).map(|(k, _)| k).collect();
+        assert_eq!(
+            skip.range(..42).map(|(k, _)| k).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn inverted_ranges_are_empty() {
+        let map: SkipList<i32, ()> = (0..10).map(|i| (i, ())).collect();
+        let (low, high) = (3, 5);
+        let (below, above) = ((-10, -5), (15, 20));
+        assert_eq!(map.range(high..low).count(), 0);
+        assert_eq!(map.range(high..=low).count(), 0);
+        assert_eq!(map.range(high..=high - 1).count(), 0);
+        assert_eq!(map.range(high..high).count(), 0);
+        assert_eq!(map.range(below.1..below.0).count(), 0);
+        assert_eq!(map.range(above.1..above.0).count(), 0);
+        let excluded = (Bound::Excluded(high), Bound::Excluded(high));
+        assert_eq!(map.range(excluded).count(), 0);
+        assert_eq!(map.range(high..=high).count(), 1);
+    }
+
+    #[test]
+    fn same_seed_same_shape() {
+        let a: SkipList<u32, ()> = (0..300).map(|i| (i, ())).collect();
+        let b: SkipList<u32, ()> = (0..300).map(|i| (i, ())).collect();
+        assert_eq!(a.level_histogram(), b.level_histogram());
+        let mut c = SkipList::with_seed(99);
+        for i in 0..300 {
+            c.insert(i, ());
+        }
+        assert_ne!(a.level_histogram(), c.level_histogram());
+    }
+}
@@ -0,0 +1,531 @@
+//! unrolled linked list `UnrolledList<T, B>`
+//!
+//!Each member of an unrolled list holds up to `B` elements in a small inline array, so it sits
+//!between [`Vector`] and [`LinkedList`]: walking it follows one pointer per `B` elements and reads
+//!the elements of a member from a contiguous block, which keeps the cache busy with useful data.
+//!Indexing skips whole members and costs O(n/B), insertion in the middle shifts at most `B`
+//!elements.
+//!
+//!A member which overflows is split into two half full members. A member which drops below half
+//!full takes elements from its successor, or swallows it when both fit in one member.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::datastruct::unrolled_struct::UnrolledList;
+//! let mut list: UnrolledList<i32, 4> = (1..=6).collect();
+//! assert_eq!(format!("{:?}",list), format!("[1, 2, 3, 4] -> [5, 6] -> x"));
+//! list.insert(1, 10);
+//! assert_eq!(format!("{:?}",list), format!("[1, 10, 2] -> [3, 4] -> [5, 6] -> x"));
+//! assert_eq!(list.get(3), Some(&3));
+//! ```
+//!
+//! [`Vector`]: crate::datastruct::vec_struct::Vector
+//! [`LinkedList`]: crate::datastruct::list_struct::LinkedList
+
+use core::marker::PhantomData;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+use std::iter::FromIterator;
+
+pub struct UnrolledList<T, const B: usize> {
+    head: *mut Member<T, B>,
+    tail: *mut Member<T, B>,
+    length: usize,
+}
+
+struct Member<T, const B: usize> {
+    /// `items[..count]` are initialised
+    items: [MaybeUninit<T>; B],
+    count: usize,
+    next: *mut Member<T, B>,
+}
+
+impl<T, const B: usize> UnrolledList<T, B> {
+    /// # Panics
+    /// Panics if `B` is smaller than two, a member has to be splittable.
+    pub fn new() -> Self {
+        assert!(B >= 2, "a member must hold at least two elements");
+        Self {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            length: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// number of allocated members, between `len / B` and about `2 * len / B`
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut node = self.head;
+        while !node.is_null() {
+            count += 1;
+            node = unsafe { (*node).next };
+        }
+        count
+    }
+
+    /// append an element, a fresh member is started only when the tail is full
+    pub fn push_back(&mut self, element: T) {
+        unsafe {
+            if self.tail.is_null() || (*self.tail).count == B {
+                let node = Member::allocate_memory();
+                if self.tail.is_null() {
+                    self.head = node;
+                } else {
+                    (*self.tail).next = node;
+                }
+                self.tail = node;
+            }
+            let tail = &mut *self.tail;
+            tail.insert_at(tail.count, element);
+        }
+        self.length += 1;
+    }
+
+    /// prepend an element, a fresh member is started only when the head is full
+    pub fn push_front(&mut self, element: T) {
+        unsafe {
+            if self.head.is_null() || (*self.head).count == B {
+                let node = Member::allocate_memory();
+                (*node).next = self.head;
+                if self.head.is_null() {
+                    self.tail = node;
+                }
+                self.head = node;
+            }
+            (*self.head).insert_at(0, element);
+        }
+        self.length += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    /// O(n/B), the member before the tail has to be found to unlink an emptied tail
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.length.checked_sub(1)?)
+    }
+
+    /// element at `index` in O(n/B)
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
+        }
+        let (_, node, offset) = self.locate(index);
+        unsafe { Some((*node).get(offset)) }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.length {
+            return None;
+        }
+        let (_, node, offset) = self.locate(index);
+        unsafe { Some((*node).get_mut(offset)) }
+    }
+
+    /// insert an element so that it ends up at `index`, a full member is split in half first
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    /// ```
+    /// # use dsa_sport::datastruct::unrolled_struct::UnrolledList;
+    /// let mut list: UnrolledList<char, 2> = "ac".chars().collect();
+    /// list.insert(1, 'b');
+    /// list.insert(3, 'd');
+    /// assert_eq!(list.iter().collect::<String>(), "abcd");
+    /// assert_eq!(list.node_count(), 2);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.length,
+            "insertion index {} is out of bounds for length {}",
+            index,
+            self.length
+        );
+        if index == self.length {
+            self.push_back(element);
+            return;
+        }
+        let (_, mut node, mut offset) = self.locate(index);
+        unsafe {
+            if (*node).count == B {
+                let upper = (*node).split();
+                if self.tail == node {
+                    self.tail = upper;
+                }
+                if offset > (*node).count {
+                    offset -= (*node).count;
+                    node = upper;
+                }
+            }
+            (*node).insert_at(offset, element);
+        }
+        self.length += 1;
+    }
+
+    /// remove the element at `index` in O(n/B), its member is refilled from the next one when it
+    /// falls below half full
+    /// ```
+    /// # use dsa_sport::datastruct::unrolled_struct::UnrolledList;
+    /// let mut list: UnrolledList<i32, 4> = (1..=6).collect();
+    /// assert_eq!(list.remove(0), Some(1));
+    /// assert_eq!(list.remove(0), Some(2));
+    /// assert_eq!(format!("{:?}",list), format!("[3, 4] -> [5, 6] -> x"));
+    /// assert_eq!(list.remove(0), Some(3));
+    /// assert_eq!(format!("{:?}",list), format!("[4, 5, 6] -> x"));
+    /// assert_eq!(list.remove(9), None);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+        let (previous, node, offset) = self.locate(index);
+        self.length -= 1;
+        unsafe {
+            let element = (*node).remove_at(offset);
+            let next = (*node).next;
+            if (*node).count < B / 2 && !next.is_null() {
+                if (*node).count + (*next).count <= B {
+                    (*node).absorb(next);
+                    if self.tail == next {
+                        self.tail = node;
+                    }
+                } else {
+                    while (*node).count < B / 2 {
+                        let moved = (*next).remove_at(0);
+                        let node = &mut *node;
+                        node.insert_at(node.count, moved);
+                    }
+                }
+            }
+            if (*node).count == 0 {
+                if previous.is_null() {
+                    self.head = (*node).next;
+                } else {
+                    (*previous).next = (*node).next;
+                }
+                if self.tail == node {
+                    self.tail = previous;
+                }
+                Member::deallocate_memory(node);
+            }
+            Some(element)
+        }
+    }
+
+    /// borrow every element from front to back
+    pub fn iter(&self) -> Iter<'_, T, B> {
+        Iter {
+            node: self.head,
+            offset: 0,
+            len: self.length,
+            marker: PhantomData,
+        }
+    }
+
+    /// bytes used by the list header and its members, unused slots of a member included
+    /// ```
+    /// # use dsa_sport::datastruct::unrolled_struct::UnrolledList;
+    /// let list: UnrolledList<u64, 8> = (0..10).collect();
+    /// assert_eq!(list.memory_footprint(), 3 * 8 + 2 * (8 * 8 + 8 + 8));
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        mem::size_of::<Self>() + self.node_count() * mem::size_of::<Member<T, B>>()
+    }
+
+    /// member holding `index` together with the member before it and the offset inside it
+    fn locate(&self, mut index: usize) -> (*mut Member<T, B>, *mut Member<T, B>, usize) {
+        let mut previous = ptr::null_mut();
+        let mut node = self.head;
+        unsafe {
+            while index >= (*node).count {
+                index -= (*node).count;
+                previous = node;
+                node = (*node).next;
+            }
+        }
+        (previous, node, index)
+    }
+}
+
+impl<T, const B: usize> Member<T, B> {
+    fn allocate_memory() -> *mut Member<T, B> {
+        Box::into_raw(Box::new(Member {
+            items: [(); B].map(|_| MaybeUninit::uninit()),
+            count: 0,
+            next: ptr::null_mut(),
+        }))
+    }
+
+    unsafe fn deallocate_memory(node: *mut Member<T, B>) {
+        drop(Box::from_raw(node));
+    }
+
+    fn get(&self, offset: usize) -> &T {
+        debug_assert!(offset < self.count);
+        unsafe { &*self.items[offset].as_ptr() }
+    }
+
+    fn get_mut(&mut self, offset: usize) -> &mut T {
+        debug_assert!(offset < self.count);
+        unsafe { &mut *self.items[offset].as_mut_ptr() }
+    }
+
+    /// shift `items[offset..count]` one slot right and write the element into the gap
+    fn insert_at(&mut self, offset: usize, element: T) {
+        debug_assert!(self.count < B && offset <= self.count);
+        unsafe {
+            let base = self.items.as_mut_ptr();
+            ptr::copy(base.add(offset), base.add(offset + 1), self.count - offset);
+        }
+        self.items[offset] = MaybeUninit::new(element);
+        self.count += 1;
+    }
+
+    /// read the element out and shift `items[offset + 1..count]` one slot left
+    fn remove_at(&mut self, offset: usize) -> T {
+        debug_assert!(offset < self.count);
+        self.count -= 1;
+        unsafe {
+            let base = self.items.as_mut_ptr();
+            let element = base.add(offset).read().assume_init();
+            ptr::copy(base.add(offset + 1), base.add(offset), self.count - offset);
+            element
+        }
+    }
+
+    /// move the upper half into a new member linked right after this one and return it
+    fn split(&mut self) -> *mut Member<T, B> {
+        let keep = self.count / 2;
+        let moved = self.count - keep;
+        let upper = Member::allocate_memory();
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.items.as_ptr().add(keep),
+                (*upper).items.as_mut_ptr(),
+                moved,
+            );
+            (*upper).count = moved;
+            (*upper).next = self.next;
+        }
+        self.count = keep;
+        self.next = upper;
+        upper
+    }
+
+    /// append every element of the next member, which has to fit, then unlink and free it
+    unsafe fn absorb(&mut self, next: *mut Member<T, B>) {
+        let other = &mut *next;
+        debug_assert!(self.count + other.count <= B);
+        ptr::copy_nonoverlapping(
+            other.items.as_ptr(),
+            self.items.as_mut_ptr().add(self.count),
+            other.count,
+        );
+        self.count += other.count;
+        other.count = 0;
+        self.next = other.next;
+        Member::deallocate_memory(next);
+    }
+}
+
+impl<T, const B: usize> Drop for Member<T, B> {
+    fn drop(&mut self) {
+        for item in &mut self.items[..self.count] {
+            unsafe { ptr::drop_in_place(item.as_mut_ptr()) };
+        }
+    }
+}
+
+/// Borrowing iterator returned by [`UnrolledList::iter`].
+pub struct Iter<'a, T, const B: usize> {
+    node: *mut Member<T, B>,
+    offset: usize,
+    len: usize,
+    marker: PhantomData<&'a Member<T, B>>,
+}
+
+impl<'a, T, const B: usize> Iterator for Iter<'a, T, B> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = &*self.node;
+            let element = node.get(self.offset);
+            self.offset += 1;
+            if self.offset == node.count {
+                self.node = node.next;
+                self.offset = 0;
+            }
+            Some(element)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T, const B: usize> ExactSizeIterator for Iter<'a, T, B> {}
+
+impl<'a, T, const B: usize> IntoIterator for &'a UnrolledList<T, B> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, B>;
+
+    fn into_iter(self) -> Iter<'a, T, B> {
+        self.iter()
+    }
+}
+
+impl<T, const B: usize> FromIterator<T> for UnrolledList<T, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = UnrolledList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const B: usize> Extend<T> for UnrolledList<T, B> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<T, const B: usize> Default for UnrolledList<T, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const B: usize> std::fmt::Debug for UnrolledList<T, B>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        let mut node = self.head;
+        while !node.is_null() {
+            unsafe {
+                let member = &*node;
+                let items: Vec<&T> = (0..member.count).map(|i| member.get(i)).collect();
+                out += &format!("{:?} -> ", items);
+                node = member.next;
+            }
+        }
+        out += "x";
+        write!(f, "{}", out)
+    }
+}
+
+impl<T, const B: usize> Drop for UnrolledList<T, B> {
+    fn drop(&mut self) {
+        let mut node = self.head;
+        while !node.is_null() {
+            unsafe {
+                let next = (*node).next;
+                Member::deallocate_memory(node);
+                node = next;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prng::XorShift64;
+
+    /// every member is non empty and not over full, and the tail really is the last member
+    fn check_shape<T, const B: usize>(list: &UnrolledList<T, B>) {
+        let mut total = 0;
+        let mut last = ptr::null_mut();
+        let mut node = list.head;
+        while !node.is_null() {
+            unsafe {
+                assert!((*node).count > 0 && (*node).count <= B);
+                total += (*node).count;
+                last = node;
+                node = (*node).next;
+            }
+        }
+        assert_eq!(total, list.len());
+        assert_eq!(last, list.tail);
+    }
+
+    fn random_edits<const B: usize>(seed: u64) {
+        let mut rng = XorShift64::new(seed);
+        let mut list: UnrolledList<String, B> = UnrolledList::new();
+        let mut model: Vec<String> = Vec::new();
+        for step in 0..3000 {
+            let len = model.len();
+            match rng.below(5) {
+                0 | 1 => {
+                    let index = rng.below(len + 1);
+                    list.insert(index, step.to_string());
+                    model.insert(index, step.to_string());
+                }
+                2 => {
+                    let index = rng.below(len + 1);
+                    let expected = if index < model.len() {
+                        Some(model.remove(index))
+                    } else {
+                        None
+                    };
+                    assert_eq!(list.remove(index), expected);
+                }
+                3 => {
+                    list.push_front(step.to_string());
+                    model.insert(0, step.to_string());
+                }
+                _ => {
+                    assert_eq!(list.pop_back(), model.pop());
+                }
+            }
+            check_shape(&list);
+        }
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            model.iter().collect::<Vec<_>>()
+        );
+        for (i, x) in model.iter().enumerate() {
+            assert_eq!(list.get(i), Some(x));
+        }
+    }
+
+    #[test]
+    fn matches_vec_model() {
+        random_edits::<2>(1);
+        random_edits::<3>(2);
+        random_edits::<8>(3);
+    }
+
+    #[test]
+    fn members_stay_half_full() {
+        let mut list: UnrolledList<usize, 16> = UnrolledList::new();
+        for i in 0..1000 {
+            list.insert(i / 2, i);
+        }
+        assert!(list.node_count() <= 2 * 1000 / 16 + 1);
+        while list.len() > 100 {
+            list.remove(list.len() / 3);
+        }
+        check_shape(&list);
+        assert!(list.node_count() <= 2 * 100 / 16 + 1);
+        *list.get_mut(0).unwrap() = 7;
+        assert_eq!(list.pop_front(), Some(7));
+    }
+}
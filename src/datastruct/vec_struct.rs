@@ -71,6 +71,50 @@ impl<T> Vector<T> {
         return unsafe { self.pointer.add(index).as_ref() };
     }
 
+    /// Passing the logical index would return an `Option` of the mutable borrow of the element
+    /// # Examples
+    /// ```
+    ///# use dsa_sport::datastruct::vec_struct::Vector;
+    /// let mut v = Vector::new();
+    /// v.push('a');
+    /// v.push('b');
+    /// *v.get_mut(1).unwrap() = 'c';
+    /// assert_eq!(v.get(1), Some(&'c'));
+    /// assert_eq!(v.get_mut(2), None);
+    /// ```
+    ///
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.length {
+            return None;
+        }
+        unsafe { self.pointer.add(index).as_mut() }
+    }
+
+    /// Borrow the elements as a slice
+    /// ```
+    ///# use dsa_sport::datastruct::vec_struct::Vector;
+    /// let mut v = Vector::new();
+    /// assert!(v.as_slice().is_empty());
+    /// v.push(1);
+    /// v.push(2);
+    /// v.as_mut_slice()[0] = 3;
+    /// assert_eq!(v.as_slice(), &[3, 2]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        if self.length == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.pointer, self.length) }
+    }
+
+    /// Mutably borrow the elements as a slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        if self.length == 0 {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.pointer, self.length) }
+    }
+
     /// Clears the vector from the memorry
     /// ```
     ///# use dsa_sport::datastruct::vec_struct::Vector;
@@ -149,6 +193,13 @@ impl<T> Vector<T> {
 
     fn deallocate(&mut self) {
         unsafe {
+            for i in 0..self.length {
+                ptr::drop_in_place(self.pointer.add(i));
+            }
+            // an empty vector never allocated, there is nothing to give back
+            if self.capacity == 0 {
+                return;
+            }
             let layout = alloc::Layout::from_size_align_unchecked(
                 mem::size_of::<T>() * self.capacity,
                 mem::align_of::<T>(),
@@ -169,7 +220,7 @@ where
         } else {
             for i in 0..self.length {
                 unsafe {
-                    out += &format!("[{:?}]", self.pointer.add(i).read());
+                    out += &format!("[{:?}]", *self.pointer.add(i));
                 }
             }
             for _ in 0..self.capacity - self.length {
@@ -198,4 +249,15 @@ mod tedts {
         let mut v: Vector<Dummy> = Vector::new();
         v.push(Dummy);
     }
+
+    #[test]
+    fn empty_vector_clears_and_drops() {
+        let mut v: Vector<String> = Vector::new();
+        v.clear();
+        assert_eq!(v.capacity(), 0);
+        v.push("a".to_string());
+        v.clear();
+        v.clear();
+        drop(v);
+    }
 }
@@ -3,6 +3,8 @@
 //! Crate [`crate`]  is not intended to be used as a library for any of
 //! the application. this crate only serves some basic concept related to data structure.
 
+pub mod cache;
 pub mod datastruct;
+pub mod prng;
 pub mod sort_analysis;
 pub use self::datastruct::list_struct::LinkedList;
@@ -0,0 +1,63 @@
+//! # prng
+//!
+//! Small seedable pseudo random number generator `XorShift64`.
+//!
+//! The crate has no dependencies, so randomised structures and input generators use this
+//! xorshift64* generator instead of the `rand` crate. It is fast and good enough for levels of a
+//! skip list or shuffled test input, it is **not** suitable for anything cryptographic. The same
+//! seed always produces the same sequence which keeps tests and benchmarks reproducible.
+//!
+//! # Examples
+//! ```
+//! # use dsa_sport::prng::XorShift64;
+//! let mut a = XorShift64::new(7);
+//! let mut b = XorShift64::new(7);
+//! assert_eq!(a.next_u64(), b.next_u64());
+//! assert!(a.below(10) < 10);
+//! ```
+
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// a zero state would only ever produce zeros, so a zero seed is replaced by a constant
+    pub fn new(seed: u64) -> Self {
+        let state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// uniform value in `0..bound`
+    ///
+    /// # Panics
+    /// Panics if `bound` is zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "empty range");
+        ((u128::from(self.next_u64()) * bound as u128) >> 64) as usize
+    }
+
+    /// uniform value in `[0, 1)`
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, v: &mut [T]) {
+        for i in (1..v.len()).rev() {
+            let j = self.below(i + 1);
+            v.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,777 @@
+//! # Sort Benchmarks
+//!
+//! An offline harness which estimates how the cost of a sort grows. An [`Experiment`] generates
+//! inputs of growing size for every [`Distribution`] from a seeded [`XorShift64`], times every
+//! [`Sorter`] on them and counts its operations on a [`Tracked`](super::metrics::Tracked)
+//! slice. [`Report::fit`] fits each series against 1, log n, n, n log n and n^2 and keeps the
+//! [`Growth`] class with the smallest relative error. A report prints as CSV with one row per
+//! run or as a markdown table of the fitted classes.
+//!
+//! Timings are wall clock, the best of a few rounds, and only comparable within one run on one
+//! machine. The operation counts are exact and the same for the same seed.
+//!
+//! # Example
+//!
+//! ```
+//! use dsa_sport::sort_analysis::bench::{Distribution, Experiment, Growth, Metric, Sorter};
+//! use dsa_sport::sort_analysis::ins_sort::{insertion_sort, insertion_sort_tracked};
+//! use dsa_sport::sort_analysis::metrics::measure;
+//!
+//! let insertion = Sorter::new("insertion", insertion_sort, |v| {
+//!     measure(v, insertion_sort_tracked)
+//! });
+//! let report = Experiment::new(&[100, 200, 400, 800])
+//!     .with_distributions(&[Distribution::Sorted, Distribution::Reversed])
+//!     .run(&[insertion]);
+//! let sorted = report.fit("insertion", Distribution::Sorted, Metric::Comparisons);
+//! assert_eq!(sorted.unwrap().growth, Growth::Linear);
+//! let reversed = report.fit("insertion", Distribution::Reversed, Metric::Comparisons);
+//! assert_eq!(reversed.unwrap().growth, Growth::Quadratic);
+//! ```
+
+use crate::prng::XorShift64;
+use crate::sort_analysis::bub_sort::{
+    bubble_sort, bubble_sort_improved, bubble_sort_improved_tracked, bubble_sort_tracked,
+};
+use crate::sort_analysis::cocktail_sort::{cocktail_shaker_sort, cocktail_shaker_sort_tracked};
+use crate::sort_analysis::comb_sort::{comb_sort, comb_sort_tracked};
+use crate::sort_analysis::counting_sort::{counting_sort, counting_sort_tracked};
+use crate::sort_analysis::cycle_sort::{cycle_sort, cycle_sort_tracked};
+use crate::sort_analysis::divide_conquer::DivideAndConquer;
+use crate::sort_analysis::gnome_sort::{gnome_sort, gnome_sort_tracked};
+use crate::sort_analysis::ins_sort::{
+    binary_insertion_sort, binary_insertion_sort_tracked, insertion_sort, insertion_sort_tracked,
+};
+use crate::sort_analysis::metrics::{measure, SortMetrics};
+use crate::sort_analysis::odd_even_sort::{odd_even_sort, odd_even_sort_tracked};
+use crate::sort_analysis::pancake_sort::{pancake_sort, pancake_sort_tracked};
+use crate::sort_analysis::pigeonhole_sort::{pigeonhole_sort, pigeonhole_sort_tracked};
+use crate::sort_analysis::radix_sort::{
+    lsd_radix_sort, lsd_radix_sort_tracked, msd_radix_sort, msd_radix_sort_tracked,
+};
+use crate::sort_analysis::sel_sort::{selection_sort, selection_sort_tracked};
+use crate::sort_analysis::shell_sort::{shell_sort_with, shell_sort_with_tracked, GapSequence};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Shapes of generated input, every generated value is below `n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    /// a shuffled permutation of `0..n`
+    Random,
+    Sorted,
+    Reversed,
+    /// sorted, then this many swaps of two random elements
+    NearlySorted(usize),
+    /// random values out of this many distinct ones
+    FewUnique(usize),
+    /// ascending to the middle and descending back, 0, 1, 2, ..., 2, 1, 0
+    OrganPipe,
+    /// this many ascending runs one after the other
+    Sawtooth(usize),
+}
+
+impl Distribution {
+    pub const ALL: [Distribution; 7] = [
+        Distribution::Random,
+        Distribution::Sorted,
+        Distribution::Reversed,
+        Distribution::NearlySorted(8),
+        Distribution::FewUnique(8),
+        Distribution::OrganPipe,
+        Distribution::Sawtooth(8),
+    ];
+
+    /// `n` values of this shape, random choices are drawn from `rng`
+    /// ```
+    /// # use dsa_sport::prng::XorShift64;
+    /// # use dsa_sport::sort_analysis::bench::Distribution;
+    /// let mut rng = XorShift64::new(1);
+    /// assert_eq!(Distribution::OrganPipe.generate(7, &mut rng), vec![0, 1, 2, 3, 2, 1, 0]);
+    /// assert_eq!(Distribution::Sawtooth(3).generate(7, &mut rng), vec![0, 1, 2, 0, 1, 2, 0]);
+    /// ```
+    pub fn generate(self, n: usize, rng: &mut XorShift64) -> Vec<u64> {
+        let mut v: Vec<u64> = (0..n as u64).collect();
+        match self {
+            Distribution::Random => rng.shuffle(&mut v),
+            Distribution::Sorted => {}
+            Distribution::Reversed => v.reverse(),
+            Distribution::NearlySorted(swaps) => {
+                if n > 0 {
+                    for _ in 0..swaps {
+                        let (i, j) = (rng.below(n), rng.below(n));
+                        v.swap(i, j);
+                    }
+                }
+            }
+            Distribution::FewUnique(values) => {
+                for x in v.iter_mut() {
+                    *x = rng.below(values.max(1)) as u64;
+                }
+            }
+            Distribution::OrganPipe => {
+                for (i, x) in v.iter_mut().enumerate() {
+                    *x = i.min(n - 1 - i) as u64;
+                }
+            }
+            Distribution::Sawtooth(teeth) => {
+                let tooth = n.div_ceil(teeth.max(1)).max(1);
+                for (i, x) in v.iter_mut().enumerate() {
+                    *x = (i % tooth) as u64;
+                }
+            }
+        }
+        v
+    }
+}
+
+impl std::fmt::Display for Distribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Distribution::Random => write!(f, "random"),
+            Distribution::Sorted => write!(f, "sorted"),
+            Distribution::Reversed => write!(f, "reversed"),
+            Distribution::NearlySorted(swaps) => write!(f, "nearly sorted ({} swaps)", swaps),
+            Distribution::FewUnique(values) => write!(f, "few unique ({})", values),
+            Distribution::OrganPipe => write!(f, "organ pipe"),
+            Distribution::Sawtooth(teeth) => write!(f, "sawtooth ({} teeth)", teeth),
+        }
+    }
+}
+
+type SortFn = Box<dyn Fn(&mut [u64])>;
+type MeasureFn = Box<dyn Fn(&mut [u64]) -> SortMetrics>;
+
+/// A named sort under test, the plain form is timed and the tracked form is counted.
+pub struct Sorter {
+    name: String,
+    sort: SortFn,
+    measure: MeasureFn,
+}
+
+impl Sorter {
+    /// `measure` is usually [`measure`] of the `_tracked` form of `sort`
+    pub fn new<S, M>(name: impl Into<String>, sort: S, measure: M) -> Self
+    where
+        S: Fn(&mut [u64]) + 'static,
+        M: Fn(&mut [u64]) -> SortMetrics + 'static,
+    {
+        Sorter {
+            name: name.into(),
+            sort: Box::new(sort),
+            measure: Box::new(measure),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn sort(&self, v: &mut [u64]) {
+        (self.sort)(v)
+    }
+
+    pub fn measure(&self, v: &mut [u64]) -> SortMetrics {
+        (self.measure)(v)
+    }
+}
+
+impl std::fmt::Debug for Sorter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Sorter").field(&self.name).finish()
+    }
+}
+
+/// Every sort of [`sort_analysis`](super) which sorts integers, bucket sort needs floats in
+/// `[0, 1)` and is left out.
+pub fn sorters() -> Vec<Sorter> {
+    let mut all = vec![
+        Sorter::new("bubble", bubble_sort, |v| measure(v, bubble_sort_tracked)),
+        Sorter::new("bubble improved", bubble_sort_improved, |v| {
+            measure(v, bubble_sort_improved_tracked)
+        }),
+        Sorter::new("selection", selection_sort, |v| {
+            measure(v, selection_sort_tracked)
+        }),
+        Sorter::new("insertion", insertion_sort, |v| {
+            measure(v, insertion_sort_tracked)
+        }),
+        Sorter::new("binary insertion", binary_insertion_sort, |v| {
+            measure(v, binary_insertion_sort_tracked)
+        }),
+        Sorter::new("cocktail shaker", cocktail_shaker_sort, |v| {
+            measure(v, cocktail_shaker_sort_tracked)
+        }),
+        Sorter::new("comb", comb_sort, |v| measure(v, comb_sort_tracked)),
+        Sorter::new("gnome", gnome_sort, |v| measure(v, gnome_sort_tracked)),
+        Sorter::new("odd-even", odd_even_sort, |v| {
+            measure(v, odd_even_sort_tracked)
+        }),
+        Sorter::new("cycle", cycle_sort, |v| measure(v, cycle_sort_tracked)),
+        Sorter::new("pancake", pancake_sort, |v| {
+            measure(v, pancake_sort_tracked)
+        }),
+    ];
+    for &sequence in GapSequence::ALL.iter() {
+        all.push(Sorter::new(
+            format!("shell {}", sequence),
+            move |v| shell_sort_with(v, sequence),
+            move |v| measure(v, |v| shell_sort_with_tracked(v, sequence)),
+        ));
+    }
+    for algorithm in DivideAndConquer::all() {
+        all.push(Sorter::new(
+            algorithm.to_string(),
+            move |v| algorithm.sort(v),
+            move |v| measure(v, |v| algorithm.sort_tracked(v)),
+        ));
+    }
+    all.push(Sorter::new("counting", counting_sort, |v| {
+        measure(v, counting_sort_tracked)
+    }));
+    all.push(Sorter::new("pigeonhole", pigeonhole_sort, |v| {
+        measure(v, pigeonhole_sort_tracked)
+    }));
+    all.push(Sorter::new("lsd radix", lsd_radix_sort, |v| {
+        measure(v, lsd_radix_sort_tracked)
+    }));
+    all.push(Sorter::new("msd radix", msd_radix_sort, |v| {
+        measure(v, msd_radix_sort_tracked)
+    }));
+    all
+}
+
+/// Sizes, input shapes and seed of a benchmark run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Experiment {
+    sizes: Vec<usize>,
+    distributions: Vec<Distribution>,
+    seed: u64,
+    rounds: usize,
+}
+
+impl Experiment {
+    /// every [`Distribution`] at each of `sizes`, timed as the best of 3 rounds
+    pub fn new(sizes: &[usize]) -> Self {
+        Experiment {
+            sizes: sizes.to_vec(),
+            distributions: Distribution::ALL.to_vec(),
+            seed: 0x5EED,
+            rounds: 3,
+        }
+    }
+
+    pub fn with_distributions(mut self, distributions: &[Distribution]) -> Self {
+        self.distributions = distributions.to_vec();
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// number of timed runs per input, the fastest one is kept
+    pub fn with_rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds.max(1);
+        self
+    }
+
+    /// Run every sorter on the same inputs.
+    ///
+    /// # Panics
+    /// Panics if a sorter leaves its input unsorted.
+    pub fn run(&self, sorters: &[Sorter]) -> Report {
+        let mut rng = XorShift64::new(self.seed);
+        let mut samples = Vec::new();
+        for &distribution in self.distributions.iter() {
+            for &n in self.sizes.iter() {
+                let input = distribution.generate(n, &mut rng);
+                for sorter in sorters {
+                    let mut v = input.clone();
+                    let metrics = sorter.measure(&mut v);
+                    assert!(
+                        v.windows(2).all(|w| w[0] <= w[1]),
+                        "{} left {} input of {} elements unsorted",
+                        sorter.name(),
+                        distribution,
+                        n
+                    );
+                    let mut time = Duration::MAX;
+                    for _ in 0..self.rounds {
+                        let mut v = input.clone();
+                        let start = Instant::now();
+                        sorter.sort(black_box(&mut v));
+                        time = time.min(start.elapsed());
+                    }
+                    samples.push(Sample {
+                        algorithm: sorter.name().to_string(),
+                        distribution,
+                        n,
+                        time,
+                        metrics,
+                    });
+                }
+            }
+        }
+        Report { samples }
+    }
+}
+
+/// One sorter on one input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub algorithm: String,
+    pub distribution: Distribution,
+    pub n: usize,
+    /// fastest of the timed rounds
+    pub time: Duration,
+    pub metrics: SortMetrics,
+}
+
+/// The quantities of a [`Sample`] a growth class can be fitted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// nanoseconds
+    Time,
+    Comparisons,
+    Swaps,
+    Reads,
+    Writes,
+    Passes,
+    /// peak auxiliary memory in bytes
+    AuxMemory,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 7] = [
+        Metric::Time,
+        Metric::Comparisons,
+        Metric::Swaps,
+        Metric::Reads,
+        Metric::Writes,
+        Metric::Passes,
+        Metric::AuxMemory,
+    ];
+
+    pub fn of(self, sample: &Sample) -> f64 {
+        let metrics = &sample.metrics;
+        match self {
+            Metric::Time => sample.time.as_nanos() as f64,
+            Metric::Comparisons => metrics.comparisons as f64,
+            Metric::Swaps => metrics.swaps as f64,
+            Metric::Reads => metrics.reads as f64,
+            Metric::Writes => metrics.writes as f64,
+            Metric::Passes => metrics.passes as f64,
+            Metric::AuxMemory => metrics.aux_memory as f64,
+        }
+    }
+}
+
+impl std::fmt::Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Metric::Time => "time (ns)",
+            Metric::Comparisons => "comparisons",
+            Metric::Swaps => "swaps",
+            Metric::Reads => "reads",
+            Metric::Writes => "writes",
+            Metric::Passes => "passes",
+            Metric::AuxMemory => "aux memory (bytes)",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Growth classes, from the slowest growing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Growth {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl Growth {
+    pub const ALL: [Growth; 5] = [
+        Growth::Constant,
+        Growth::Logarithmic,
+        Growth::Linear,
+        Growth::Linearithmic,
+        Growth::Quadratic,
+    ];
+
+    /// the class function at `n`, logarithms are base 2 and taken of at least 2
+    pub fn at(self, n: usize) -> f64 {
+        let x = n as f64;
+        let log = x.max(2.0).log2();
+        match self {
+            Growth::Constant => 1.0,
+            Growth::Logarithmic => log,
+            Growth::Linear => x,
+            Growth::Linearithmic => x * log,
+            Growth::Quadratic => x * x,
+        }
+    }
+}
+
+impl std::fmt::Display for Growth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Growth::Constant => "1",
+            Growth::Logarithmic => "log n",
+            Growth::Linear => "n",
+            Growth::Linearithmic => "n log n",
+            Growth::Quadratic => "n^2",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The best growth class for a series, `y ≈ coefficient * growth(n)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub growth: Growth,
+    pub coefficient: f64,
+    /// root mean square of the relative residuals
+    pub error: f64,
+}
+
+impl std::fmt::Display for Fit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.growth {
+            Growth::Constant => write!(f, "{:.3}", self.coefficient),
+            growth => write!(f, "{:.3} {}", self.coefficient, growth),
+        }
+    }
+}
+
+/// Fit `(n, y)` points against every [`Growth`] class and return the best one.
+///
+/// Each class is fitted by least squares on the relative residuals `(y - c * g(n)) / y`, so small
+/// and large `n` weigh the same. Ties go to the slower growing class. `None` if there are fewer
+/// than two different `n` or every `y` is zero.
+/// ```
+/// # use dsa_sport::sort_analysis::bench::{fit, Growth};
+/// let points: Vec<(usize, f64)> = [10, 20, 40, 80].iter().map(|&n| (n, 3.0 * (n * n) as f64)).collect();
+/// let best = fit(&points).unwrap();
+/// assert_eq!(best.growth, Growth::Quadratic);
+/// assert!((best.coefficient - 3.0).abs() < 1e-9);
+/// ```
+pub fn fit(points: &[(usize, f64)]) -> Option<Fit> {
+    let distinct = points.windows(2).any(|w| w[0].0 != w[1].0);
+    if !distinct || points.iter().all(|&(_, y)| y == 0.0) {
+        return None;
+    }
+    let mut best: Option<Fit> = None;
+    for &growth in Growth::ALL.iter() {
+        // r = g(n) / y, minimising the sum of (1 - c * r)^2 gives c = sum(r) / sum(r^2)
+        let ratios: Vec<f64> = points
+            .iter()
+            .map(|&(n, y)| growth.at(n) / y.max(1.0))
+            .collect();
+        let coefficient = ratios.iter().sum::<f64>() / ratios.iter().map(|r| r * r).sum::<f64>();
+        let squares: f64 = ratios.iter().map(|r| (1.0 - coefficient * r).powi(2)).sum();
+        let error = (squares / ratios.len() as f64).sqrt();
+        if best.is_none_or(|best| error < best.error) {
+            best = Some(Fit {
+                growth,
+                coefficient,
+                error,
+            });
+        }
+    }
+    best
+}
+
+/// Every [`Sample`] of an [`Experiment`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub samples: Vec<Sample>,
+}
+
+impl Report {
+    /// `(n, metric)` of one sorter on one distribution, in order of `n`
+    pub fn series(
+        &self,
+        algorithm: &str,
+        distribution: Distribution,
+        metric: Metric,
+    ) -> Vec<(usize, f64)> {
+        let mut series: Vec<(usize, f64)> = self
+            .samples
+            .iter()
+            .filter(|s| s.algorithm == algorithm && s.distribution == distribution)
+            .map(|s| (s.n, metric.of(s)))
+            .collect();
+        series.sort_by_key(|&(n, _)| n);
+        series
+    }
+
+    /// the best growth class of a [`series`](Report::series), see [`fit`]
+    pub fn fit(&self, algorithm: &str, distribution: Distribution, metric: Metric) -> Option<Fit> {
+        fit(&self.series(algorithm, distribution, metric))
+    }
+
+    /// one line per sample under a header line
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "algorithm,distribution,n,nanos,comparisons,swaps,reads,writes,passes,aux_memory\n",
+        );
+        for s in self.samples.iter() {
+            let m = &s.metrics;
+            writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{}",
+                s.algorithm,
+                s.distribution,
+                s.n,
+                s.time.as_nanos(),
+                m.comparisons,
+                m.swaps,
+                m.reads,
+                m.writes,
+                m.passes,
+                m.aux_memory
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    /// A markdown table with the fitted growth of time, comparisons, swaps, writes and memory
+    /// for every sorter and distribution. Series which stay zero show as `0`, series with less
+    /// than two sizes as `-`.
+    pub fn to_markdown(&self) -> String {
+        const COLUMNS: [Metric; 5] = [
+            Metric::Time,
+            Metric::Comparisons,
+            Metric::Swaps,
+            Metric::Writes,
+            Metric::AuxMemory,
+        ];
+        let mut table = String::from("| algorithm | distribution |");
+        for metric in COLUMNS.iter() {
+            write!(table, " {} |", metric).unwrap();
+        }
+        table.push_str("\n| --- | --- |");
+        table.push_str(&" ---: |".repeat(COLUMNS.len()));
+        table.push('\n');
+        for algorithm in self.first_seen(|s| s.algorithm.clone()) {
+            for distribution in self.first_seen(|s| s.distribution) {
+                write!(table, "| {} | {} |", algorithm, distribution).unwrap();
+                for &metric in COLUMNS.iter() {
+                    let series = self.series(&algorithm, distribution, metric);
+                    match fit(&series) {
+                        Some(fit) => write!(table, " {} |", fit).unwrap(),
+                        None if series.len() > 1 => table.push_str(" 0 |"),
+                        None => table.push_str(" - |"),
+                    }
+                }
+                table.push('\n');
+            }
+        }
+        table
+    }
+
+    /// distinct values of `key` over the samples in order of first appearance
+    fn first_seen<K: PartialEq>(&self, key: impl Fn(&Sample) -> K) -> Vec<K> {
+        let mut seen = Vec::new();
+        for sample in self.samples.iter() {
+            let k = key(sample);
+            if !seen.contains(&k) {
+                seen.push(k);
+            }
+        }
+        seen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::bench::{
+        fit, sorters, Distribution, Experiment, Growth, Metric, Sorter,
+    };
+    use crate::sort_analysis::bub_sort::{bubble_sort_improved, bubble_sort_improved_tracked};
+    use crate::sort_analysis::heap_sort::{heap_sort, heap_sort_tracked};
+    use crate::sort_analysis::merge_sort::{top_down_merge_sort, top_down_merge_sort_tracked};
+    use crate::sort_analysis::metrics::measure;
+
+    #[test]
+    fn test_distributions() {
+        let n = 100;
+        let generate =
+            |distribution: Distribution| distribution.generate(n, &mut XorShift64::new(47));
+        let sorted: Vec<u64> = (0..n as u64).collect();
+        let mut random = generate(Distribution::Random);
+        assert_ne!(random, sorted);
+        random.sort();
+        assert_eq!(random, sorted);
+        assert_eq!(
+            generate(Distribution::Random),
+            generate(Distribution::Random)
+        );
+        assert_eq!(generate(Distribution::Sorted), sorted);
+        let reversed: Vec<u64> = sorted.iter().rev().cloned().collect();
+        assert_eq!(generate(Distribution::Reversed), reversed);
+        let nearly = generate(Distribution::NearlySorted(3));
+        let moved = nearly
+            .iter()
+            .zip(sorted.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert!(moved > 0 && moved <= 6);
+        let mut few = generate(Distribution::FewUnique(5));
+        few.sort();
+        few.dedup();
+        assert!(few.len() <= 5 && few.iter().all(|&x| x < 5));
+        let pipe = generate(Distribution::OrganPipe);
+        assert_eq!((pipe[0], pipe[49], pipe[50], pipe[99]), (0, 49, 49, 0));
+        let saw = generate(Distribution::Sawtooth(4));
+        let descents = saw.windows(2).filter(|w| w[0] > w[1]).count();
+        assert_eq!(descents, 3);
+        for &distribution in Distribution::ALL.iter() {
+            assert!(generate(distribution).iter().all(|&x| x < n as u64));
+            assert!(distribution.generate(0, &mut XorShift64::new(1)).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_fit_recovers_every_class() {
+        let sizes = [64, 128, 256, 512, 1024, 2048];
+        for &growth in Growth::ALL.iter() {
+            let points: Vec<(usize, f64)> =
+                sizes.iter().map(|&n| (n, 5.0 * growth.at(n))).collect();
+            let best = fit(&points).unwrap();
+            assert_eq!(best.growth, growth);
+            assert!((best.coefficient - 5.0).abs() < 1e-6);
+            assert!(best.error < 1e-9);
+        }
+        assert_eq!(fit(&[(10, 4.0), (10, 5.0)]), None);
+        assert_eq!(fit(&[(10, 0.0), (20, 0.0)]), None);
+    }
+
+    #[test]
+    fn test_experiment_finds_known_growth() {
+        let sorters = [
+            Sorter::new("bubble improved", bubble_sort_improved, |v| {
+                measure(v, bubble_sort_improved_tracked)
+            }),
+            Sorter::new("merge", top_down_merge_sort, |v| {
+                measure(v, top_down_merge_sort_tracked)
+            }),
+            Sorter::new("heap", heap_sort, |v| measure(v, heap_sort_tracked)),
+        ];
+        let report = Experiment::new(&[128, 256, 512, 1024])
+            .with_distributions(&[Distribution::Sorted, Distribution::Random])
+            .with_rounds(1)
+            .run(&sorters);
+        assert_eq!(report.samples.len(), 3 * 4 * 2);
+        let growth = |algorithm, distribution, metric| {
+            report.fit(algorithm, distribution, metric).unwrap().growth
+        };
+        let bubble = "bubble improved";
+        assert_eq!(
+            growth(bubble, Distribution::Sorted, Metric::Comparisons),
+            Growth::Linear
+        );
+        assert_eq!(
+            growth(bubble, Distribution::Sorted, Metric::Passes),
+            Growth::Constant
+        );
+        assert_eq!(
+            growth(bubble, Distribution::Random, Metric::Comparisons),
+            Growth::Quadratic
+        );
+        assert_eq!(
+            growth(bubble, Distribution::Random, Metric::Swaps),
+            Growth::Quadratic
+        );
+        assert_eq!(
+            growth("merge", Distribution::Random, Metric::Comparisons),
+            Growth::Linearithmic
+        );
+        assert_eq!(
+            growth("merge", Distribution::Random, Metric::AuxMemory),
+            Growth::Linear
+        );
+        assert_eq!(
+            growth("heap", Distribution::Random, Metric::Swaps),
+            Growth::Linearithmic
+        );
+        assert_eq!(
+            report.fit("heap", Distribution::Random, Metric::AuxMemory),
+            None
+        );
+
+        let csv = report.to_csv();
+        assert_eq!(csv.lines().count(), 1 + report.samples.len());
+        assert!(csv.starts_with("algorithm,distribution,n,nanos,"));
+        let markdown = report.to_markdown();
+        assert_eq!(markdown.lines().count(), 2 + 3 * 2);
+        assert!(markdown.contains("| bubble improved | sorted |"));
+        assert!(markdown
+            .lines()
+            .any(|l| l.starts_with("| heap | random |") && l.ends_with(" 0 |")));
+    }
+
+    #[test]
+    fn test_run_adaptive_sorts_are_linear_on_sorted_input() {
+        let names = ["bubble improved", "merge natural", "tim", "pdq"];
+        let all: Vec<Sorter> = sorters()
+            .into_iter()
+            .filter(|sorter| names.contains(&sorter.name()))
+            .collect();
+        assert_eq!(all.len(), names.len());
+        let report = Experiment::new(&[256, 512, 1024, 2048])
+            .with_distributions(&[
+                Distribution::Sorted,
+                Distribution::Reversed,
+                Distribution::Random,
+            ])
+            .with_rounds(1)
+            .run(&all);
+        let growth = |algorithm, distribution| {
+            report
+                .fit(algorithm, distribution, Metric::Comparisons)
+                .unwrap()
+                .growth
+        };
+        for &name in names.iter() {
+            assert_eq!(
+                growth(name, Distribution::Sorted),
+                Growth::Linear,
+                "{}",
+                name
+            );
+        }
+        for &name in names[1..].iter() {
+            assert_eq!(
+                growth(name, Distribution::Random),
+                Growth::Linearithmic,
+                "{}",
+                name
+            );
+        }
+        // tim and pdq sort reverse a descending slice, natural merge sort merges n runs of one
+        assert_eq!(growth("tim", Distribution::Reversed), Growth::Linear);
+        assert_eq!(growth("pdq", Distribution::Reversed), Growth::Linear);
+        assert_eq!(
+            growth("merge natural", Distribution::Reversed),
+            Growth::Linearithmic
+        );
+    }
+
+    #[test]
+    fn test_every_sorter_sorts_every_distribution() {
+        let all = sorters();
+        let report = Experiment::new(&[1, 33, 100]).with_rounds(1).run(&all);
+        assert_eq!(
+            report.samples.len(),
+            all.len() * 3 * Distribution::ALL.len()
+        );
+    }
+}
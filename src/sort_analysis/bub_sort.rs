@@ -3,6 +3,9 @@
 //! `sort_analysis` crate really not usefull in online Judge platform but this crate will ensure
 //! that you have all the materials that you need to learn about sorting algorithm complexities
 
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
 /// Bubble Sort algorithm in its most basic form
 ///
 /// # Example
@@ -14,12 +17,44 @@
 ///```
 pub fn bubble_sort<T: PartialOrd>(v: &mut [T]) {
     /*! O(n^2) !*/
+    bubble_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`bubble_sort`] with a comparator, like [`slice::sort_by`]
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::bub_sort::bubble_sort_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, vec![13,11,8,6,4,3,1])
+///```
+pub fn bubble_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    bubble_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`bubble_sort`] by a key, like [`slice::sort_by_key`]
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec!["pear", "fig", "apple", "kiwi"];
+/// dsa_sport::sort_analysis::bub_sort::bubble_sort_by_key(&mut v, |s| s.len());
+/// assert_eq!(v, vec!["fig", "pear", "kiwi", "apple"])
+///```
+pub fn bubble_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    bubble_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`bubble_sort`] on a [`Tracked`] slice
+pub fn bubble_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
     for _pass in 0..v.len() {
-        for i in 0..v.len() - 1 {
-            if v[i] > v[i + 1] {
+        for i in 0..v.len().saturating_sub(1) {
+            if v.less(i + 1, i) {
                 v.swap(i, i + 1);
             }
         }
+        v.pass();
     }
 }
 
@@ -34,14 +69,30 @@ pub fn bubble_sort<T: PartialOrd>(v: &mut [T]) {
 ///```
 pub fn bubble_sort_improved<T: PartialOrd>(v: &mut [T]) {
     /*! O(n^2) !*/
+    bubble_sort_improved_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`bubble_sort_improved`] with a comparator, like [`slice::sort_by`]
+pub fn bubble_sort_improved_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    bubble_sort_improved_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`bubble_sort_improved`] by a key, like [`slice::sort_by_key`]
+pub fn bubble_sort_improved_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    bubble_sort_improved_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`bubble_sort_improved`] on a [`Tracked`] slice
+pub fn bubble_sort_improved_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
     for pass in 0..v.len() {
         let mut sorted = true;
-        for i in 0..(v.len() - 1) - pass {
-            if v[i] > v[i + 1] {
+        for i in 0..v.len().saturating_sub(1 + pass) {
+            if v.less(i + 1, i) {
                 v.swap(i, i + 1);
                 sorted = false;
             }
         }
+        v.pass();
         if sorted {
             return;
         }
@@ -50,7 +101,9 @@ pub fn bubble_sort_improved<T: PartialOrd>(v: &mut [T]) {
 
 #[cfg(test)]
 mod tests {
-    use crate::sort_analysis::bub_sort::{bubble_sort, bubble_sort_improved};
+    use crate::sort_analysis::bub_sort::{
+        bubble_sort, bubble_sort_by, bubble_sort_improved, bubble_sort_improved_by_key,
+    };
 
     #[test]
     fn test_bubble_sort() {
@@ -65,4 +118,26 @@ mod tests {
         bubble_sort_improved(&mut v);
         assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13])
     }
+
+    #[test]
+    fn test_empty_and_single_element() {
+        let mut empty: Vec<i32> = vec![];
+        bubble_sort(&mut empty);
+        bubble_sort_improved(&mut empty);
+        assert!(empty.is_empty());
+        let mut single = vec![1.5];
+        bubble_sort(&mut single);
+        bubble_sort_improved(&mut single);
+        assert_eq!(single, vec![1.5]);
+    }
+
+    #[test]
+    fn test_descending_and_by_key() {
+        let mut v = vec![4.5, -1.0, 8.25, 0.0, 3.0];
+        bubble_sort_by(&mut v, |a, b| b.partial_cmp(a).unwrap());
+        assert_eq!(v, vec![8.25, 4.5, 3.0, 0.0, -1.0]);
+        let mut words = vec!["ccc", "a", "bb", "d"];
+        bubble_sort_improved_by_key(&mut words, |w| w.len());
+        assert_eq!(words, vec!["a", "d", "bb", "ccc"]);
+    }
 }
@@ -0,0 +1,107 @@
+//! # Bucket Sort Algorithm
+//!
+//! Bucket sort spreads n numbers from `[0, 1)` over n equally wide buckets, the value `x` goes to
+//! bucket `floor(x · n)`, and sorts every bucket by insertion sort. Uniformly spread input puts
+//! about one element into each bucket, which makes it O(n) on average. Clustered input fills a
+//! few buckets and degrades to the O(n^2) of insertion sort.
+
+use crate::sort_analysis::ins_sort::insertion_sort_tracked;
+use crate::sort_analysis::metrics::{Natural, Observer, Order, Tracked};
+use core::mem::size_of;
+
+/// Bucket Sort algorithm for floats in `[0, 1)`, stable
+///
+/// Time complexity: O(n) on average for uniform input, O(n^2) worst case. Extra space: O(n).
+///
+/// # Panics
+///
+/// Panics if a value is NaN or outside `[0, 1)`.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+/// dsa_sport::sort_analysis::bucket_sort::bucket_sort(&mut v);
+/// assert_eq!(v, vec![0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52])
+///```
+pub fn bucket_sort<T: Copy + PartialOrd + Into<f64>>(v: &mut [T]) {
+    bucket_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`bucket_sort`] on a [`Tracked`] slice, the order sorts within the buckets
+pub fn bucket_sort_tracked<T, C, O>(v: &mut Tracked<'_, T, C, O>)
+where
+    T: Copy + Into<f64>,
+    C: Order<T>,
+    O: Observer<T>,
+{
+    let n = v.len();
+    if n < 2 {
+        return;
+    }
+    let bucket = |x: T| {
+        let x: f64 = x.into();
+        assert!(
+            (0.0..1.0).contains(&x),
+            "bucket sort needs values in [0, 1), not {}",
+            x
+        );
+        ((x * n as f64) as usize).min(n - 1)
+    };
+    let buckets: Vec<usize> = (0..n).map(|i| bucket(*v.key(i))).collect();
+    let tables = (3 * n + 1) * size_of::<usize>();
+    v.allocate(tables);
+    let mut count = vec![0usize; n + 1];
+    for &b in buckets.iter() {
+        count[b + 1] += 1;
+    }
+    for i in 1..=n {
+        count[i] += count[i - 1];
+    }
+    let dest: Vec<usize> = buckets
+        .iter()
+        .map(|&b| {
+            let slot = &mut count[b];
+            *slot += 1;
+            *slot - 1
+        })
+        .collect();
+    v.permute(&dest);
+    // after placing, count[b] is where bucket b + 1 starts
+    let mut start = 0;
+    for &end in count[..n].iter() {
+        insertion_sort_tracked(&mut v.slice(start..end));
+        start = end;
+    }
+    v.release(tables);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::bucket_sort::bucket_sort;
+
+    #[test]
+    fn test_bucket_sort() {
+        let mut rng = XorShift64::new(49);
+        for n in [0, 1, 2, 10, 1000].iter() {
+            let mut v: Vec<f64> = (0..*n).map(|_| rng.next_f64()).collect();
+            let mut expected = v.clone();
+            expected.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            bucket_sort(&mut v);
+            assert_eq!(v, expected);
+        }
+        // everything in a few buckets still sorts, only slower
+        let mut clustered: Vec<f32> = (0..500).map(|i| ((i * 37) % 5) as f32 / 1000.0).collect();
+        let mut expected = clustered.clone();
+        expected.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        bucket_sort(&mut clustered);
+        assert_eq!(clustered, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bucket_sort_rejects_one() {
+        bucket_sort(&mut [0.5, 1.0]);
+    }
+}
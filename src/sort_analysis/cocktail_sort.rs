@@ -0,0 +1,77 @@
+//! # Cocktail Shaker Sort Algorithm
+//!
+//! Cocktail shaker sort is bubble sort passing alternately left to right and right to left. A
+//! small element near the end ("turtle") needs n passes to reach the front in bubble sort but
+//! only one backward pass here. Still O(n^2), stable, and O(n) on sorted input.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Cocktail Shaker Sort algorithm, stable
+///
+/// Time complexity: O(n^2), O(n) when sorted.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::cocktail_sort::cocktail_shaker_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn cocktail_shaker_sort<T: PartialOrd>(v: &mut [T]) {
+    cocktail_shaker_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`cocktail_shaker_sort`] with a comparator, like [`slice::sort_by`]
+pub fn cocktail_shaker_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    cocktail_shaker_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`cocktail_shaker_sort`] by a key, like [`slice::sort_by_key`]
+pub fn cocktail_shaker_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    cocktail_shaker_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`cocktail_shaker_sort`] on a [`Tracked`] slice
+pub fn cocktail_shaker_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    if v.len() < 2 {
+        return;
+    }
+    let (mut start, mut end) = (0, v.len() - 1);
+    while start < end {
+        // everything after the last swap of a pass is already in place
+        let mut last_swap = start;
+        for i in start..end {
+            if v.less(i + 1, i) {
+                v.swap(i, i + 1);
+                last_swap = i;
+            }
+        }
+        end = last_swap;
+        v.pass();
+        let mut first_swap = end;
+        for i in (start..end).rev() {
+            if v.less(i + 1, i) {
+                v.swap(i, i + 1);
+                first_swap = i + 1;
+            }
+        }
+        start = first_swap;
+        v.pass();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::cocktail_sort::cocktail_shaker_sort;
+
+    #[test]
+    fn test_cocktail_shaker_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        cocktail_shaker_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut turtles = vec![2, 3, 4, 5, 1, 1];
+        cocktail_shaker_sort(&mut turtles);
+        assert_eq!(turtles, vec![1, 1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,71 @@
+//! # Comb Sort Algorithm
+//!
+//! Comb sort is bubble sort comparing elements `gap` apart, with the gap shrinking by a factor of
+//! about 1.3 after every pass until it is 1. Like in shell sort the long jumps clear out the
+//! turtles early, on random input it is close to O(n log n) but the worst case is O(n^2).
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Comb Sort algorithm, not stable
+///
+/// Time complexity: O(n^2) worst, about O(n log n) on average.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::comb_sort::comb_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn comb_sort<T: PartialOrd>(v: &mut [T]) {
+    comb_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`comb_sort`] with a comparator, like [`slice::sort_by`]
+pub fn comb_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    comb_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`comb_sort`] by a key, like [`slice::sort_by_key`]
+pub fn comb_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    comb_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`comb_sort`] on a [`Tracked`] slice
+pub fn comb_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let mut gap = v.len();
+    let mut sorted = false;
+    while !sorted {
+        gap = (gap * 10 / 13).max(1);
+        // gaps of 9 and 10 leave more turtles behind than 11 ("combsort11")
+        if gap == 9 || gap == 10 {
+            gap = 11;
+        }
+        sorted = gap == 1;
+        for i in 0..v.len().saturating_sub(gap) {
+            if v.less(i + gap, i) {
+                v.swap(i, i + gap);
+                sorted = false;
+            }
+        }
+        v.pass();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::comb_sort::comb_sort;
+
+    #[test]
+    fn test_comb_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        comb_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut long: Vec<u32> = (0..500).map(|i| (i * 37) % 101).collect();
+        let mut expected = long.clone();
+        expected.sort_unstable();
+        comb_sort(&mut long);
+        assert_eq!(long, expected);
+    }
+}
@@ -0,0 +1,116 @@
+//! # Counting Sort Algorithm
+//!
+//! Counting sort never compares two elements. It counts how often every key occurs in the range
+//! `min..=max`, turns the counts into the first position of every key and sends each element
+//! there. That is O(n + k) for a range of k keys: faster than any comparison sort while k is
+//! about n, and hopeless once k grows far beyond n.
+//!
+//! Keys are read through [`RadixKey`] as one number, so they must be at most 8 bytes long and
+//! all of the same length, which holds for every integer type up to 64 bits.
+
+use crate::sort_analysis::metrics::{Natural, Observer, Order, Tracked};
+use crate::sort_analysis::radix_key::{small_key, RadixKey};
+use core::mem::size_of;
+use std::convert::TryFrom;
+
+/// Counting Sort algorithm, stable
+///
+/// Time complexity: O(n + k) for k = max - min + 1. Extra space: O(n + k).
+///
+/// # Panics
+///
+/// Panics if the keys are longer than 8 bytes, of different lengths, or span more keys than fit
+/// in memory.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, -6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::counting_sort::counting_sort(&mut v);
+/// assert_eq!(v, vec![-6,1,3,4,8,11,13])
+///```
+pub fn counting_sort<K: RadixKey + PartialOrd>(v: &mut [K]) {
+    counting_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`counting_sort`] on a [`Tracked`] slice, the order is not used
+pub fn counting_sort_tracked<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+) {
+    if v.len() < 2 {
+        return;
+    }
+    let (keys, min, range) = small_keys(v);
+    let tables = (range + 2 * keys.len()) * size_of::<usize>();
+    v.allocate(tables);
+    let mut count = vec![0usize; range];
+    for &key in keys.iter() {
+        count[(key - min) as usize] += 1;
+    }
+    let mut start = 0;
+    for slot in count.iter_mut() {
+        let n = *slot;
+        *slot = start;
+        start += n;
+    }
+    let dest: Vec<usize> = keys
+        .iter()
+        .map(|&key| {
+            let slot = &mut count[(key - min) as usize];
+            *slot += 1;
+            *slot - 1
+        })
+        .collect();
+    v.permute(&dest);
+    v.release(tables);
+}
+
+/// every key as one number, the smallest of them and the number of keys from min to max
+pub(crate) fn small_keys<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+) -> (Vec<u64>, u64, usize) {
+    let len = v.as_slice()[0].key_len();
+    assert!(
+        v.as_slice().iter().all(|key| key.key_len() == len),
+        "counting needs keys of the same length"
+    );
+    let keys: Vec<u64> = (0..v.len()).map(|i| small_key(v.key(i))).collect();
+    let min = keys.iter().copied().min().unwrap_or(0);
+    let max = keys.iter().copied().max().unwrap_or(0);
+    let range = usize::try_from(max - min)
+        .ok()
+        .and_then(|range| range.checked_add(1))
+        .expect("the key range is too large to count");
+    (keys, min, range)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::counting_sort::counting_sort;
+
+    #[test]
+    fn test_counting_sort() {
+        let mut rng = XorShift64::new(44);
+        let mut v: Vec<i16> = (0..2000).map(|_| rng.below(1000) as i16 - 500).collect();
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        counting_sort(&mut v);
+        assert_eq!(v, expected);
+
+        let mut words = vec!["dog", "cat", "ant", "cow", "bee"];
+        counting_sort(&mut words);
+        assert_eq!(words, vec!["ant", "bee", "cat", "cow", "dog"]);
+        let mut empty: Vec<u64> = vec![];
+        counting_sort(&mut empty);
+        let mut extremes = vec![u8::MAX, 0, 7, u8::MAX];
+        counting_sort(&mut extremes);
+        assert_eq!(extremes, vec![0, 7, u8::MAX, u8::MAX]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_counting_sort_needs_same_length() {
+        counting_sort(&mut ["b", "ab"]);
+    }
+}
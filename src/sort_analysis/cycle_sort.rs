@@ -0,0 +1,135 @@
+//! # Cycle Sort Algorithm
+//!
+//! Cycle sort counts how many elements are smaller than an element to know its final position,
+//! and sends it straight there. The element it displaces is carried on and placed the same way,
+//! until the cycle of the permutation closes and the last one drops into the place the first one
+//! left. Every element that is not in place yet is written exactly once, the theoretical minimum,
+//! at the price of O(n^2) comparisons.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+
+/// Cycle Sort algorithm, not stable
+///
+/// Time complexity: O(n^2) comparisons, at most n writes and no swaps.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::cycle_sort::cycle_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn cycle_sort<T: PartialOrd>(v: &mut [T]) {
+    cycle_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`cycle_sort`] with a comparator, like [`slice::sort_by`]
+pub fn cycle_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    cycle_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`cycle_sort`] by a key, like [`slice::sort_by_key`]
+pub fn cycle_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    cycle_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`cycle_sort`] on a [`Tracked`] slice
+///
+/// The carried element is reported at the index of the place it will finally fill, `start`.
+pub fn cycle_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let (v, offset, order, observer) = v.parts();
+    // the carried element is compared as if it still were at `start`
+    let mut less = |observer: &mut O, a: &T, b: &T, i: usize, j: usize| {
+        observer.compare(offset + i, offset + j);
+        order.less(a, b)
+    };
+    for start in 0..v.len().saturating_sub(1) {
+        let mut pos = position(v, start, &v[start], &mut |a, b, i, j| {
+            less(observer, a, b, i, j)
+        });
+        if pos == start {
+            continue;
+        }
+        observer.read(offset + start);
+        unsafe {
+            // if a comparison panics the hole puts the carried element back at `start`
+            let mut hole = Hole {
+                item: ManuallyDrop::new(ptr::read(&v[start])),
+                dest: v.as_mut_ptr().add(start),
+            };
+            while pos != start {
+                // equal elements already sit in the first places of their run
+                while !less(observer, &v[pos], &hole.item, pos, start)
+                    && !less(observer, &hole.item, &v[pos], start, pos)
+                {
+                    pos += 1;
+                }
+                mem::swap(&mut *hole.item, &mut v[pos]);
+                observer.write(offset + pos, &v[pos]);
+                pos = position(v, start, &hole.item, &mut |a, b, i, j| {
+                    less(observer, a, b, i, j)
+                });
+            }
+        }
+        observer.write(offset + start, &v[start]);
+    }
+}
+
+/// final index of `item`, which came from `v[start]`: `start` plus the number of smaller
+/// elements after it
+fn position<T, F>(v: &[T], start: usize, item: &T, less: &mut F) -> usize
+where
+    F: FnMut(&T, &T, usize, usize) -> bool,
+{
+    start
+        + (start + 1..v.len())
+            .filter(|&i| less(&v[i], item, i, start))
+            .count()
+}
+
+/// the element carried around a cycle and the place it is written to when dropped
+struct Hole<T> {
+    item: ManuallyDrop<T>,
+    dest: *mut T,
+}
+
+impl<T> Drop for Hole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::copy_nonoverlapping(&*self.item, self.dest, 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::cycle_sort::{cycle_sort, cycle_sort_tracked};
+    use crate::sort_analysis::metrics::measure;
+    use crate::sort_analysis::trace::record;
+
+    #[test]
+    fn test_cycle_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        cycle_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut duplicates = vec![3, 1, 3, 2, 1, 3, 0, 2];
+        cycle_sort(&mut duplicates);
+        assert_eq!(duplicates, vec![0, 1, 1, 2, 2, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_cycle_sort_writes_each_misplaced_element_once() {
+        let mut v = vec![3, 1, 3, 2, 1, 3, 0, 2, 5, 4];
+        let mut sorted = v.clone();
+        sorted.sort();
+        let misplaced = v.iter().zip(sorted.iter()).filter(|(a, b)| a != b).count();
+        let trace = record(&mut v.clone(), cycle_sort_tracked);
+        assert_eq!(trace.replay(), sorted);
+        let metrics = measure(&mut v, cycle_sort_tracked);
+        assert_eq!(v, sorted);
+        assert_eq!((metrics.swaps, metrics.writes), (0, misplaced as u64));
+    }
+}
@@ -0,0 +1,99 @@
+//! # Divide and Conquer Sorts
+//!
+//! Every O(n log n) comparison sort variant of this crate as one enum value, so an analysis
+//! harness can enumerate and run all of them.
+//!
+//! # Example
+//!
+//! ```
+//! use dsa_sport::sort_analysis::divide_conquer::DivideAndConquer;
+//! for algorithm in DivideAndConquer::all() {
+//!     let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+//!     algorithm.sort(&mut v);
+//!     assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13], "{}", algorithm);
+//! }
+//! ```
+
+use crate::sort_analysis::heap_sort::heap_sort_tracked;
+use crate::sort_analysis::intro_sort::intro_sort_tracked;
+use crate::sort_analysis::merge_sort::MergeVariant;
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use crate::sort_analysis::pdq_sort::pdq_sort_tracked;
+use crate::sort_analysis::quick_sort::{quick_sort_with_tracked, Partition, PivotChoice};
+use crate::sort_analysis::tim_sort::tim_sort_tracked;
+use core::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivideAndConquer {
+    Merge(MergeVariant),
+    Quick(Partition, PivotChoice),
+    Heap,
+    Intro,
+    Tim,
+    Pdq,
+}
+
+impl DivideAndConquer {
+    /// every merge variant, every partition with every pivot, heap, intro, tim and pdq sort
+    pub fn all() -> Vec<DivideAndConquer> {
+        let mut all: Vec<DivideAndConquer> = MergeVariant::ALL
+            .iter()
+            .map(|&variant| DivideAndConquer::Merge(variant))
+            .collect();
+        for &partition in Partition::ALL.iter() {
+            for &pivot in PivotChoice::ALL.iter() {
+                all.push(DivideAndConquer::Quick(partition, pivot));
+            }
+        }
+        all.push(DivideAndConquer::Heap);
+        all.push(DivideAndConquer::Intro);
+        all.push(DivideAndConquer::Tim);
+        all.push(DivideAndConquer::Pdq);
+        all
+    }
+
+    pub fn sort<T: PartialOrd>(self, v: &mut [T]) {
+        self.sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+    }
+
+    pub fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(self, v: &mut [T], compare: F) {
+        self.sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+    }
+
+    pub fn sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(self, v: &mut [T], key: F) {
+        self.sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+    }
+
+    pub fn sort_tracked<T, C: Order<T>, O: Observer<T>>(self, v: &mut Tracked<'_, T, C, O>) {
+        match self {
+            DivideAndConquer::Merge(variant) => variant.sort_tracked(v),
+            DivideAndConquer::Quick(partition, pivot) => {
+                quick_sort_with_tracked(v, partition, pivot)
+            }
+            DivideAndConquer::Heap => heap_sort_tracked(v),
+            DivideAndConquer::Intro => intro_sort_tracked(v),
+            DivideAndConquer::Tim => tim_sort_tracked(v),
+            DivideAndConquer::Pdq => pdq_sort_tracked(v),
+        }
+    }
+
+    /// only the merge sorts and tim sort keep equal elements in their order
+    pub fn is_stable(self) -> bool {
+        matches!(self, DivideAndConquer::Merge(_) | DivideAndConquer::Tim)
+    }
+}
+
+impl std::fmt::Display for DivideAndConquer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DivideAndConquer::Merge(variant) => write!(f, "{}", variant),
+            DivideAndConquer::Quick(partition, pivot) => {
+                write!(f, "quick {} {}", partition, pivot)
+            }
+            DivideAndConquer::Heap => write!(f, "heap"),
+            DivideAndConquer::Intro => write!(f, "intro"),
+            DivideAndConquer::Tim => write!(f, "tim"),
+            DivideAndConquer::Pdq => write!(f, "pdq"),
+        }
+    }
+}
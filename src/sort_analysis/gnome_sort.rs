@@ -0,0 +1,58 @@
+//! # Gnome Sort Algorithm
+//!
+//! Gnome sort walks forward while neighbours are in order and, on finding a pair out of order,
+//! swaps it and steps back. It is insertion sort done with a single index and no nested loop,
+//! stable and O(n^2), O(n) on sorted input.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Gnome Sort algorithm, stable
+///
+/// Time complexity: O(n^2), O(n) when sorted.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::gnome_sort::gnome_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn gnome_sort<T: PartialOrd>(v: &mut [T]) {
+    gnome_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`gnome_sort`] with a comparator, like [`slice::sort_by`]
+pub fn gnome_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    gnome_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`gnome_sort`] by a key, like [`slice::sort_by_key`]
+pub fn gnome_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    gnome_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`gnome_sort`] on a [`Tracked`] slice
+pub fn gnome_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let mut pos = 0;
+    while pos < v.len() {
+        if pos == 0 || !v.less(pos, pos - 1) {
+            pos += 1;
+        } else {
+            v.swap(pos - 1, pos);
+            pos -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::gnome_sort::gnome_sort;
+
+    #[test]
+    fn test_gnome_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        gnome_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13])
+    }
+}
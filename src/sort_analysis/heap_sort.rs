@@ -0,0 +1,81 @@
+//! # Heap Sort Algorithm
+//!
+//! Heap sort arranges the slice as a binary max-heap, where `v[i]` is not smaller than its
+//! children `v[2i + 1]` and `v[2i + 2]`, then swaps the maximum at the root to the end and
+//! restores the heap on the rest. It is O(n log n) in every case, in place and not stable.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Heap Sort algorithm, not stable
+///
+/// Time complexity: O(n log n). Extra space: O(1).
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::heap_sort::heap_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn heap_sort<T: PartialOrd>(v: &mut [T]) {
+    heap_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`heap_sort`] with a comparator, like [`slice::sort_by`]
+pub fn heap_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    heap_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`heap_sort`] by a key, like [`slice::sort_by_key`]
+pub fn heap_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    heap_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`heap_sort`] on a [`Tracked`] slice
+pub fn heap_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    // the second half are leaves and already heaps of one element
+    for i in (0..v.len() / 2).rev() {
+        sift_down(v, i);
+    }
+    for end in (1..v.len()).rev() {
+        v.swap(0, end);
+        sift_down(&mut v.slice(0..end), 0);
+        v.pass();
+    }
+}
+
+/// move `v[root]` down until both children are not larger
+fn sift_down<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>, mut root: usize) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= v.len() {
+            return;
+        }
+        if child + 1 < v.len() && v.less(child, child + 1) {
+            child += 1;
+        }
+        if !v.less(root, child) {
+            return;
+        }
+        v.swap(root, child);
+        root = child;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::heap_sort::heap_sort;
+
+    #[test]
+    fn test_heap_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        heap_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut long: Vec<u32> = (0..1000).map(|i| (i * 7919) % 1009).collect();
+        let mut expected = long.clone();
+        expected.sort_unstable();
+        heap_sort(&mut long);
+        assert_eq!(long, expected);
+    }
+}
@@ -0,0 +1,131 @@
+//! # Insertion Sort Algorithm
+//!
+//! Insertion sort grows a sorted prefix by inserting the next element where it belongs. Every
+//! step moves an element past the larger ones before it, so the work is proportional to the
+//! number of inversions: O(n) on sorted input and O(n^2) on reversed input. It is stable, in
+//! place and the usual finishing pass of hybrid sorts on short slices.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Insertion Sort algorithm with a linear scan for the insertion point, stable
+///
+/// Time complexity: O(n^2), O(n) when sorted.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::ins_sort::insertion_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn insertion_sort<T: PartialOrd>(v: &mut [T]) {
+    insertion_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`insertion_sort`] with a comparator, like [`slice::sort_by`]
+pub fn insertion_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    insertion_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`insertion_sort`] by a key, like [`slice::sort_by_key`]
+pub fn insertion_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    insertion_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`insertion_sort`] on a [`Tracked`] slice
+pub fn insertion_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && v.less(j, j - 1) {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Insertion Sort algorithm which finds the insertion point by binary search, stable
+///
+/// The search needs only O(log i) comparisons for the `i`-th element, the elements still have to
+/// be shifted one by one so the moves stay O(n^2).
+///
+/// Time complexity: O(n log n) comparisons, O(n^2) moves.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::ins_sort::binary_insertion_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn binary_insertion_sort<T: PartialOrd>(v: &mut [T]) {
+    binary_insertion_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`binary_insertion_sort`] with a comparator, like [`slice::sort_by`]
+pub fn binary_insertion_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    binary_insertion_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`binary_insertion_sort`] by a key, like [`slice::sort_by_key`]
+pub fn binary_insertion_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    binary_insertion_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`binary_insertion_sort`] on a [`Tracked`] slice
+pub fn binary_insertion_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    binary_insertion_sort_from(v, 1);
+}
+
+/// binary insertion sort of a slice whose first `sorted` elements are already in order
+pub(crate) fn binary_insertion_sort_from<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    sorted: usize,
+) {
+    for i in sorted.max(1)..v.len() {
+        // first position in the sorted prefix holding a larger element, equal ones stay in front
+        let (mut low, mut high) = (0, i);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if v.less(i, mid) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        if low < i {
+            v.rotate_left(low..i + 1, i - low);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::ins_sort::{binary_insertion_sort, insertion_sort};
+
+    #[test]
+    fn test_insertion_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        insertion_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13])
+    }
+
+    #[test]
+    fn test_binary_insertion_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3, 6];
+        binary_insertion_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 6, 8, 11, 13]);
+        // keys compare by their number only, the letter shows the original order
+        #[derive(Debug, PartialEq)]
+        struct Key(i32, char);
+        impl PartialOrd for Key {
+            fn partial_cmp(&self, other: &Key) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+        let mut keys = vec![Key(2, 'a'), Key(1, 'b'), Key(2, 'c'), Key(1, 'd')];
+        binary_insertion_sort(&mut keys);
+        let order: String = keys.iter().map(|k| k.1).collect();
+        assert_eq!(order, "bdac");
+    }
+}
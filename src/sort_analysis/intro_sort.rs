@@ -0,0 +1,114 @@
+//! # Intro Sort Algorithm
+//!
+//! Intro sort (Musser 1997) is quick sort which watches its own recursion depth. Past a limit of
+//! about 2 log n levels the pivots are clearly bad and the remaining part is heap sorted, which
+//! caps the worst case at O(n log n) while keeping quick sort's speed on typical input. Short
+//! parts are left to insertion sort.
+
+use crate::sort_analysis::heap_sort::heap_sort_tracked;
+use crate::sort_analysis::ins_sort::insertion_sort_tracked;
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use crate::sort_analysis::quick_sort::{median_of_three, partition_at_first, Partition};
+use core::cmp::Ordering;
+
+/// parts up to this length are insertion sorted
+const INSERTION_THRESHOLD: usize = 16;
+
+/// Intro Sort algorithm with a depth limit of 2 floor(log2 n), not stable
+///
+/// Time complexity: O(n log n).
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::intro_sort::intro_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn intro_sort<T: PartialOrd>(v: &mut [T]) {
+    intro_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`intro_sort`] with a comparator, like [`slice::sort_by`]
+pub fn intro_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    intro_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`intro_sort`] by a key, like [`slice::sort_by_key`]
+pub fn intro_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    intro_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`intro_sort`] on a [`Tracked`] slice
+pub fn intro_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    intro_sort_with_limit_tracked(v, depth_limit(v.len()));
+}
+
+/// 2 floor(log2 n), zero for an empty slice
+fn depth_limit(len: usize) -> usize {
+    2 * len.checked_ilog2().unwrap_or(0) as usize
+}
+
+/// Intro Sort algorithm which switches to heap sort after `depth_limit` partitions, zero makes
+/// it plain heap sort (for slices longer than the insertion sort threshold)
+///
+/// # Example
+///
+/// ```
+/// let mut v: Vec<u32> = (0..100).rev().collect();
+/// dsa_sport::sort_analysis::intro_sort::intro_sort_with_limit(&mut v, 0);
+/// assert_eq!(v, (0..100).collect::<Vec<_>>())
+///```
+pub fn intro_sort_with_limit<T: PartialOrd>(v: &mut [T], depth_limit: usize) {
+    intro_sort_with_limit_tracked(&mut Tracked::new(v, &mut Natural, &mut ()), depth_limit);
+}
+
+/// [`intro_sort_with_limit`] on a [`Tracked`] slice
+pub fn intro_sort_with_limit_tracked<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    depth_limit: usize,
+) {
+    if v.len() <= INSERTION_THRESHOLD {
+        insertion_sort_tracked(v);
+        return;
+    }
+    if depth_limit == 0 {
+        heap_sort_tracked(v);
+        return;
+    }
+    let last = v.len() - 1;
+    let pivot = median_of_three(v, 0, last / 2, last);
+    v.swap(0, pivot);
+    let (less, greater) = partition_at_first(v, Partition::Hoare);
+    intro_sort_with_limit_tracked(&mut v.slice(0..less), depth_limit - 1);
+    let len = v.len();
+    intro_sort_with_limit_tracked(&mut v.slice(greater..len), depth_limit - 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::intro_sort::{depth_limit, intro_sort, intro_sort_with_limit};
+
+    #[test]
+    fn test_intro_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        intro_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        for limit in 0..4 {
+            let mut long: Vec<u32> = (0..2000).map(|i| (i * 7919) % 1009).collect();
+            let mut expected = long.clone();
+            expected.sort_unstable();
+            intro_sort_with_limit(&mut long, limit);
+            assert_eq!(long, expected);
+        }
+    }
+
+    #[test]
+    fn test_intro_sort_depth_limit_is_twice_floor_log2() {
+        let limits: Vec<usize> = [0, 1, 2, 3, 4, 7, 8, 1000]
+            .iter()
+            .map(|&n| depth_limit(n))
+            .collect();
+        assert_eq!(limits, vec![0, 0, 2, 2, 4, 4, 6, 18]);
+    }
+}
@@ -0,0 +1,430 @@
+//! # Merge Sort Algorithm
+//!
+//! Merge sort splits the slice into halves, sorts them and merges the two sorted runs. The
+//! recursion is log n levels deep and every level merges n elements, so it is O(n log n) in every
+//! case and stable. The price is a buffer for the left run of a merge, n/2 elements when the runs
+//! are halves and up to n - 1 when they are not. The [`in_place_merge_sort`] variant avoids it
+//! with rotations and pays an extra log n factor.
+//!
+//! All variants skip a merge when the two runs are already in order, which makes them O(n) on
+//! sorted input. [`natural_merge_sort`] goes further and merges the ascending runs the input
+//! already has instead of runs of a fixed width.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+use core::ops::Range;
+use core::ptr;
+
+/// The merge sort variants, for harnesses which run all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeVariant {
+    /// recursive halving, [`top_down_merge_sort`]
+    TopDown,
+    /// merging runs of width 1, 2, 4, ... without recursion, [`bottom_up_merge_sort`]
+    BottomUp,
+    /// merging the ascending runs found in the input, [`natural_merge_sort`]
+    Natural,
+    /// no buffer, runs are merged by rotations, [`in_place_merge_sort`]
+    InPlace,
+}
+
+impl MergeVariant {
+    pub const ALL: [MergeVariant; 4] = [
+        MergeVariant::TopDown,
+        MergeVariant::BottomUp,
+        MergeVariant::Natural,
+        MergeVariant::InPlace,
+    ];
+
+    pub fn sort<T: PartialOrd>(self, v: &mut [T]) {
+        self.sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+    }
+
+    pub fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(self, v: &mut [T], compare: F) {
+        self.sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+    }
+
+    pub fn sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(self, v: &mut [T], key: F) {
+        self.sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+    }
+
+    pub fn sort_tracked<T, C: Order<T>, O: Observer<T>>(self, v: &mut Tracked<'_, T, C, O>) {
+        match self {
+            MergeVariant::TopDown => top_down_merge_sort_tracked(v),
+            MergeVariant::BottomUp => bottom_up_merge_sort_tracked(v),
+            MergeVariant::Natural => natural_merge_sort_tracked(v),
+            MergeVariant::InPlace => in_place_merge_sort_tracked(v),
+        }
+    }
+}
+
+impl std::fmt::Display for MergeVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MergeVariant::TopDown => "merge top-down",
+            MergeVariant::BottomUp => "merge bottom-up",
+            MergeVariant::Natural => "merge natural",
+            MergeVariant::InPlace => "merge in-place",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Top-Down Merge Sort algorithm, stable
+///
+/// Time complexity: O(n log n), O(n) when sorted. Extra space: n/2 elements.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::merge_sort::top_down_merge_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn top_down_merge_sort<T: PartialOrd>(v: &mut [T]) {
+    top_down_merge_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`top_down_merge_sort`] with a comparator, like [`slice::sort_by`]
+pub fn top_down_merge_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    top_down_merge_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`top_down_merge_sort`] by a key, like [`slice::sort_by_key`]
+pub fn top_down_merge_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    top_down_merge_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`top_down_merge_sort`] on a [`Tracked`] slice
+pub fn top_down_merge_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let mut buf = Vec::with_capacity(v.len() / 2);
+    top_down_help(v, &mut buf);
+}
+
+fn top_down_help<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>, buf: &mut Vec<T>) {
+    let len = v.len();
+    if len < 2 {
+        return;
+    }
+    let mid = len / 2;
+    top_down_help(&mut v.slice(0..mid), buf);
+    top_down_help(&mut v.slice(mid..len), buf);
+    merge(v, mid, buf);
+}
+
+/// Bottom-Up Merge Sort algorithm, stable
+///
+/// Time complexity: O(n log n), O(n) when sorted. Extra space: the largest power of two below n,
+/// up to n - 1 elements.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::merge_sort::bottom_up_merge_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn bottom_up_merge_sort<T: PartialOrd>(v: &mut [T]) {
+    bottom_up_merge_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`bottom_up_merge_sort`] with a comparator, like [`slice::sort_by`]
+pub fn bottom_up_merge_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    bottom_up_merge_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`bottom_up_merge_sort`] by a key, like [`slice::sort_by_key`]
+pub fn bottom_up_merge_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    bottom_up_merge_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`bottom_up_merge_sort`] on a [`Tracked`] slice
+pub fn bottom_up_merge_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let len = v.len();
+    let mut buf = Vec::with_capacity(len / 2);
+    let mut width = 1;
+    while width < len {
+        let mut start = 0;
+        while start + width < len {
+            let end = (start + 2 * width).min(len);
+            merge(&mut v.slice(start..end), width, &mut buf);
+            start = end;
+        }
+        width *= 2;
+        v.pass();
+    }
+}
+
+/// Natural Merge Sort algorithm, stable
+///
+/// Every round scans the slice for ascending runs and merges them in pairs, until one run is
+/// left. Input made of k runs takes about log2 k rounds, sorted input a single scan.
+///
+/// Time complexity: O(n log k) for k runs, O(n) when sorted. Extra space: the longest left run
+/// of a merge, up to n - 1 elements.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::merge_sort::natural_merge_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn natural_merge_sort<T: PartialOrd>(v: &mut [T]) {
+    natural_merge_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`natural_merge_sort`] with a comparator, like [`slice::sort_by`]
+pub fn natural_merge_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    natural_merge_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`natural_merge_sort`] by a key, like [`slice::sort_by_key`]
+pub fn natural_merge_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    natural_merge_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`natural_merge_sort`] on a [`Tracked`] slice
+pub fn natural_merge_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let len = v.len();
+    let mut buf = Vec::new();
+    loop {
+        let mut merges = 0;
+        let mut start = 0;
+        while start < len {
+            let mid = run_end(v, start);
+            let end = if mid < len { run_end(v, mid) } else { len };
+            merge(&mut v.slice(start..end), mid - start, &mut buf);
+            merges += 1;
+            start = end;
+        }
+        v.pass();
+        if merges <= 1 {
+            return;
+        }
+    }
+}
+
+/// end of the ascending run starting at `start`
+fn run_end<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>, start: usize) -> usize {
+    let mut end = start + 1;
+    while end < v.len() && !v.less(end, end - 1) {
+        end += 1;
+    }
+    end
+}
+
+/// In-Place Merge Sort algorithm, stable
+///
+/// Two runs are merged without a buffer: the middle element of the longer run splits it, a binary
+/// search splits the other run at the same value, and one rotation brings the two inner pieces
+/// into order. Both halves are then merged recursively.
+///
+/// Time complexity: O(n log^2 n), O(n) when sorted. Extra space: O(log n) for the recursion.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::merge_sort::in_place_merge_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn in_place_merge_sort<T: PartialOrd>(v: &mut [T]) {
+    in_place_merge_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`in_place_merge_sort`] with a comparator, like [`slice::sort_by`]
+pub fn in_place_merge_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    in_place_merge_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`in_place_merge_sort`] by a key, like [`slice::sort_by_key`]
+pub fn in_place_merge_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    in_place_merge_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`in_place_merge_sort`] on a [`Tracked`] slice
+pub fn in_place_merge_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let len = v.len();
+    if len < 2 {
+        return;
+    }
+    let mid = len / 2;
+    in_place_merge_sort_tracked(&mut v.slice(0..mid));
+    in_place_merge_sort_tracked(&mut v.slice(mid..len));
+    merge_in_place(v, mid);
+}
+
+/// merge the sorted runs `v[..mid]` and `v[mid..]` by rotations
+fn merge_in_place<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>, mid: usize) {
+    let len = v.len();
+    if mid == 0 || mid == len || !v.less(mid, mid - 1) {
+        return;
+    }
+    if len == 2 {
+        v.swap(0, 1);
+        return;
+    }
+    let (cut1, cut2) = if mid >= len - mid {
+        // right elements smaller than the left pivot go in front of it
+        let cut1 = mid / 2;
+        (cut1, partition_point(v, mid..len, |v, i| v.less(i, cut1)))
+    } else {
+        // left elements not larger than the right pivot stay in front of it
+        let cut2 = mid + (len - mid) / 2;
+        (partition_point(v, 0..mid, |v, i| !v.less(cut2, i)), cut2)
+    };
+    v.rotate_left(cut1..cut2, mid - cut1);
+    let new_mid = cut1 + (cut2 - mid);
+    merge_in_place(&mut v.slice(0..new_mid), cut1);
+    merge_in_place(&mut v.slice(new_mid..len), cut2 - new_mid);
+}
+
+/// first index of a partitioned range for which `pred` is false
+fn partition_point<T, C, O, F>(
+    v: &mut Tracked<'_, T, C, O>,
+    range: Range<usize>,
+    mut pred: F,
+) -> usize
+where
+    C: Order<T>,
+    O: Observer<T>,
+    F: FnMut(&mut Tracked<'_, T, C, O>, usize) -> bool,
+{
+    let (mut low, mut high) = (range.start, range.end);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(v, mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Merge the sorted runs `v[..mid]` and `v[mid..]`. The left run is moved into `buf` and merged
+/// back from the front, the write position can never overtake the unread part of the right run.
+///
+/// Elements of the left run are reported with the index they were read from.
+pub(crate) fn merge<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    mid: usize,
+    buf: &mut Vec<T>,
+) {
+    let len = v.len();
+    if mid == 0 || mid == len || !v.less(mid, mid - 1) {
+        return;
+    }
+    buf.reserve(mid);
+    let bytes = mid * core::mem::size_of::<T>();
+    let (v, offset, order, observer) = v.parts();
+    observer.allocate(bytes);
+    for i in 0..mid {
+        observer.read(offset + i);
+    }
+    // the left run is written back from `tail` on once the right run is used up
+    let tail;
+    unsafe {
+        let v_ptr = v.as_mut_ptr();
+        let buf_ptr = buf.as_mut_ptr();
+        ptr::copy_nonoverlapping(v_ptr, buf_ptr, mid);
+        // if a comparison panics the hole puts the rest of the left run back into `v`
+        let mut hole = Hole {
+            start: buf_ptr,
+            end: buf_ptr.add(mid),
+            dest: v_ptr,
+        };
+        let mut right = v_ptr.add(mid);
+        let right_end = v_ptr.add(len);
+        while hole.start < hole.end && right < right_end {
+            let (r, l) = (right.offset_from(v_ptr), hole.start.offset_from(buf_ptr));
+            observer.compare(offset + r as usize, offset + l as usize);
+            // ties are taken from the left run, which keeps the sort stable
+            if order.less(&*right, &*hole.start) {
+                ptr::copy_nonoverlapping(right, hole.dest, 1);
+                right = right.add(1);
+            } else {
+                ptr::copy_nonoverlapping(hole.start, hole.dest, 1);
+                hole.start = hole.start.add(1);
+            }
+            observer.write(offset + hole.dest.offset_from(v_ptr) as usize, &*hole.dest);
+            hole.dest = hole.dest.add(1);
+        }
+        tail = hole.dest.offset_from(v_ptr) as usize..len - right_end.offset_from(right) as usize;
+    }
+    for i in tail {
+        observer.write(offset + i, &v[i]);
+    }
+    observer.release(bytes);
+}
+
+/// the part of the left run still in the buffer and where it belongs in the slice
+pub(crate) struct Hole<T> {
+    pub(crate) start: *mut T,
+    pub(crate) end: *mut T,
+    pub(crate) dest: *mut T,
+}
+
+impl<T> Drop for Hole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let remaining = self.end.offset_from(self.start) as usize;
+            ptr::copy_nonoverlapping(self.start, self.dest, remaining);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::merge_sort::{natural_merge_sort_tracked, MergeVariant};
+    use crate::sort_analysis::metrics::measure;
+
+    #[test]
+    fn test_merge_sort_variants() {
+        for variant in MergeVariant::ALL.iter() {
+            let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+            variant.sort(&mut v);
+            assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+
+            let mut empty: Vec<String> = vec![];
+            variant.sort(&mut empty);
+            let mut words: Vec<String> = (0..300).map(|i| ((i * 7) % 31).to_string()).collect();
+            let mut expected = words.clone();
+            expected.sort();
+            variant.sort(&mut words);
+            assert_eq!(words, expected, "{}", variant);
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_is_stable() {
+        #[derive(Debug)]
+        struct Key(u8, usize);
+        impl PartialEq for Key {
+            fn eq(&self, other: &Key) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl PartialOrd for Key {
+            fn partial_cmp(&self, other: &Key) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+        for variant in MergeVariant::ALL.iter() {
+            let mut keys: Vec<Key> = (0..500).map(|i| Key((i * 13 % 7) as u8, i)).collect();
+            variant.sort(&mut keys);
+            assert!(keys
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1)));
+        }
+    }
+
+    #[test]
+    fn test_natural_merge_sort_buffers_the_whole_left_run() {
+        let mut v: Vec<u64> = (1..1000).chain(Some(0)).collect();
+        let metrics = measure(&mut v, natural_merge_sort_tracked);
+        assert!(v.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(metrics.aux_memory, 999 * 8);
+    }
+}
@@ -0,0 +1,414 @@
+//! # Sort Metrics
+//!
+//! Big-O tells how the work grows, [`SortMetrics`] counts it. Every sort in `sort_analysis` is
+//! written once against a [`Tracked`] slice, a view of `&mut [T]` whose comparisons, swaps, reads
+//! and writes are reported to an [`Observer`]. The plain entry points like
+//! [`bubble_sort`](crate::sort_analysis::bub_sort::bubble_sort) observe nothing, so the reports
+//! compile away; the `_tracked` forms take the view and can be run through [`measure`]. The
+//! `_by` and `_by_key` forms of the comparison sorts sort in the [`Order`] of [`By`] and
+//! [`ByKey`] instead of [`Natural`].
+//!
+//! The counts follow the usual textbook cost model:
+//!
+//! - a comparison asks the [`Order`] whether one element is less than another
+//! - a swap exchanges two elements of the slice
+//! - a read moves or copies an element out of the slice, into a temporary or buffer, or looks at
+//!   its key without comparing it
+//! - a write stores an element into the slice other than by a swap
+//! - auxiliary memory is the peak number of bytes of buffers and tables the sort allocates, the
+//!   recursion stack is not counted
+//!
+//! # Example
+//!
+//! ```
+//! use dsa_sport::sort_analysis::bub_sort::{bubble_sort_tracked, bubble_sort_improved_tracked};
+//! use dsa_sport::sort_analysis::metrics::measure;
+//! let sorted: Vec<u32> = (0..100).collect();
+//! let naive = measure(&mut sorted.clone(), bubble_sort_tracked);
+//! let improved = measure(&mut sorted.clone(), bubble_sort_improved_tracked);
+//! assert_eq!(naive.comparisons, 100 * 99);
+//! assert_eq!(improved.comparisons, 99);
+//! assert_eq!(naive.swaps + improved.swaps, 0);
+//! ```
+
+use core::cmp::Ordering;
+use core::ops::Range;
+use core::ptr;
+
+/// The order a [`Tracked`] slice is sorted in.
+pub trait Order<T> {
+    /// true if `a` must come before `b`
+    fn less(&mut self, a: &T, b: &T) -> bool;
+}
+
+/// The order of `PartialOrd`, incomparable elements like `NaN` count as equal.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Natural;
+
+impl<T: PartialOrd> Order<T> for Natural {
+    fn less(&mut self, a: &T, b: &T) -> bool {
+        a < b
+    }
+}
+
+/// The order of a comparator, as in [`slice::sort_by`], behind the `_by` entry points.
+#[derive(Debug, Clone, Copy)]
+pub struct By<F>(pub F);
+
+impl<T, F: FnMut(&T, &T) -> Ordering> Order<T> for By<F> {
+    fn less(&mut self, a: &T, b: &T) -> bool {
+        (self.0)(a, b) == Ordering::Less
+    }
+}
+
+/// The order of a key, as in [`slice::sort_by_key`], behind the `_by_key` entry points. The key
+/// is computed anew for every comparison.
+#[derive(Debug, Clone, Copy)]
+pub struct ByKey<F>(pub F);
+
+impl<T, K: Ord, F: FnMut(&T) -> K> Order<T> for ByKey<F> {
+    fn less(&mut self, a: &T, b: &T) -> bool {
+        (self.0)(a) < (self.0)(b)
+    }
+}
+
+/// Receives every operation of a sort on a [`Tracked`] slice. Indices are positions in the whole
+/// slice handed to the sort, also while it works on a part of it. All methods do nothing by
+/// default, `()` is the observer which observes nothing.
+pub trait Observer<T> {
+    /// `v[i]` was compared with `v[j]`
+    fn compare(&mut self, _i: usize, _j: usize) {}
+
+    /// `v[i]` and `v[j]` were swapped
+    fn swap(&mut self, _i: usize, _j: usize) {}
+
+    /// `v[i]` was read outside of a comparison or swap
+    fn read(&mut self, _i: usize) {}
+
+    /// `value` was stored into `v[i]`
+    fn write(&mut self, _i: usize, _value: &T) {}
+
+    /// the sort allocated `bytes` of auxiliary memory
+    fn allocate(&mut self, _bytes: usize) {}
+
+    /// the sort released `bytes` of auxiliary memory
+    fn release(&mut self, _bytes: usize) {}
+
+    /// the sort finished one pass over the slice
+    fn pass(&mut self) {}
+}
+
+impl<T> Observer<T> for () {}
+
+/// Operation counts of one sort.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SortMetrics {
+    pub comparisons: u64,
+    pub swaps: u64,
+    pub reads: u64,
+    pub writes: u64,
+    pub passes: u64,
+    /// peak auxiliary memory in bytes
+    pub aux_memory: usize,
+    /// auxiliary memory in use right now
+    in_use: usize,
+}
+
+impl<T> Observer<T> for SortMetrics {
+    fn compare(&mut self, _i: usize, _j: usize) {
+        self.comparisons += 1;
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) {
+        self.swaps += 1;
+    }
+
+    fn read(&mut self, _i: usize) {
+        self.reads += 1;
+    }
+
+    fn write(&mut self, _i: usize, _value: &T) {
+        self.writes += 1;
+    }
+
+    fn allocate(&mut self, bytes: usize) {
+        self.in_use += bytes;
+        self.aux_memory = self.aux_memory.max(self.in_use);
+    }
+
+    fn release(&mut self, bytes: usize) {
+        self.in_use = self.in_use.saturating_sub(bytes);
+    }
+
+    fn pass(&mut self) {
+        self.passes += 1;
+    }
+}
+
+impl std::fmt::Display for SortMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} comparisons, {} swaps, {} reads, {} writes, {} passes, {} bytes auxiliary memory",
+            self.comparisons, self.swaps, self.reads, self.writes, self.passes, self.aux_memory
+        )
+    }
+}
+
+/// Sort `v` in its natural order and count the work, `sort` is any `_tracked` sort.
+///
+/// # Example
+///
+/// ```
+/// use dsa_sport::sort_analysis::metrics::measure;
+/// use dsa_sport::sort_analysis::merge_sort::top_down_merge_sort_tracked;
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// let metrics = measure(&mut v, top_down_merge_sort_tracked);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13]);
+/// assert_eq!(metrics.swaps, 0);
+/// assert!(metrics.writes > 0 && metrics.aux_memory > 0);
+///```
+pub fn measure<T, S>(v: &mut [T], sort: S) -> SortMetrics
+where
+    T: PartialOrd,
+    S: FnOnce(&mut Tracked<'_, T, Natural, SortMetrics>),
+{
+    let mut metrics = SortMetrics::default();
+    sort(&mut Tracked::new(v, &mut Natural, &mut metrics));
+    metrics
+}
+
+/// A slice whose operations are reported to an [`Observer`], see the [module](self) docs.
+pub struct Tracked<'a, T, C, O> {
+    v: &'a mut [T],
+    /// position of `v[0]` in the whole slice
+    offset: usize,
+    order: &'a mut C,
+    observer: &'a mut O,
+}
+
+impl<'a, T, C: Order<T>, O: Observer<T>> Tracked<'a, T, C, O> {
+    pub fn new(v: &'a mut [T], order: &'a mut C, observer: &'a mut O) -> Self {
+        Tracked {
+            v,
+            offset: 0,
+            order,
+            observer,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+
+    /// the elements, looking at them this way is not counted
+    pub fn as_slice(&self) -> &[T] {
+        self.v
+    }
+
+    /// true if `v[i]` must come before `v[j]`, one comparison
+    pub fn less(&mut self, i: usize, j: usize) -> bool {
+        self.observer.compare(self.offset + i, self.offset + j);
+        self.order.less(&self.v[i], &self.v[j])
+    }
+
+    /// neither element comes before the other, up to two comparisons
+    pub fn equal(&mut self, i: usize, j: usize) -> bool {
+        !self.less(i, j) && !self.less(j, i)
+    }
+
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.observer.swap(self.offset + i, self.offset + j);
+        self.v.swap(i, j);
+    }
+
+    /// look at `v[i]` without comparing it, one read
+    pub fn key(&mut self, i: usize) -> &T {
+        self.observer.read(self.offset + i);
+        &self.v[i]
+    }
+
+    /// Rotate `v[range]` left by `mid` places. It counts as one read into a temporary and a
+    /// write for every element of the range.
+    pub fn rotate_left(&mut self, range: Range<usize>, mid: usize) {
+        self.observer.read(self.offset + range.start);
+        self.v[range.clone()].rotate_left(mid);
+        for i in range {
+            self.observer.write(self.offset + i, &self.v[i]);
+        }
+    }
+
+    /// reverse `v[range]` by swapping its ends towards the middle
+    pub fn reverse(&mut self, range: Range<usize>) {
+        let (mut i, mut j) = (range.start, range.end);
+        while i + 1 < j {
+            j -= 1;
+            self.swap(i, j);
+            i += 1;
+        }
+    }
+
+    /// the part `v[range]`, reporting to the same observer with the same indices
+    pub fn slice(&mut self, range: Range<usize>) -> Tracked<'_, T, C, O> {
+        Tracked {
+            offset: self.offset + range.start,
+            v: &mut self.v[range],
+            order: &mut *self.order,
+            observer: &mut *self.observer,
+        }
+    }
+
+    /// mark the end of a pass over the slice
+    pub fn pass(&mut self) {
+        self.observer.pass();
+    }
+
+    pub fn allocate(&mut self, bytes: usize) {
+        self.observer.allocate(bytes);
+    }
+
+    pub fn release(&mut self, bytes: usize) {
+        self.observer.release(bytes);
+    }
+
+    /// Move every `v[i]` to `v[dest[i]]` through a buffer, `dest` must be a permutation of the
+    /// indices. Nothing in here can panic, so no element is ever lost or dropped twice.
+    pub(crate) fn permute(&mut self, dest: &[usize]) {
+        debug_assert_eq!(self.v.len(), dest.len());
+        let len = self.v.len();
+        let bytes = core::mem::size_of_val(self.v);
+        self.observer.allocate(bytes);
+        let mut buf: Vec<T> = Vec::with_capacity(len);
+        unsafe {
+            let src = self.v.as_mut_ptr();
+            let out = buf.as_mut_ptr();
+            for (i, &target) in dest.iter().enumerate() {
+                ptr::copy_nonoverlapping(src.add(i), out.add(target), 1);
+            }
+            // `buf` keeps length zero, the elements are only moved back and never dropped from it
+            ptr::copy_nonoverlapping(out, src, len);
+        }
+        for i in 0..len {
+            self.observer.read(self.offset + i);
+        }
+        for i in 0..len {
+            self.observer.write(self.offset + i, &self.v[i]);
+        }
+        self.observer.release(bytes);
+    }
+
+    /// The slice, its offset, order and observer at once, for sorts that move elements through
+    /// raw pointers and report the moves themselves.
+    pub(crate) fn parts(&mut self) -> (&mut [T], usize, &mut C, &mut O) {
+        (
+            &mut *self.v,
+            self.offset,
+            &mut *self.order,
+            &mut *self.observer,
+        )
+    }
+}
+
+impl<T: std::fmt::Debug, C, O> std::fmt::Debug for Tracked<'_, T, C, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.v.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::bub_sort::{bubble_sort_improved_tracked, bubble_sort_tracked};
+    use crate::sort_analysis::cycle_sort::cycle_sort_tracked;
+    use crate::sort_analysis::divide_conquer::DivideAndConquer;
+    use crate::sort_analysis::ins_sort::insertion_sort_tracked;
+    use crate::sort_analysis::merge_sort::MergeVariant;
+    use crate::sort_analysis::metrics::{measure, Natural, Observer, SortMetrics, Tracked};
+    use crate::sort_analysis::radix_sort::lsd_radix_sort_tracked;
+    use crate::sort_analysis::sel_sort::selection_sort_tracked;
+
+    #[test]
+    fn test_bubble_sort_comparisons_on_sorted_input() {
+        for n in [1u64, 2, 10, 100, 1000].iter() {
+            let sorted: Vec<u64> = (0..*n).collect();
+            let naive = measure(&mut sorted.clone(), bubble_sort_tracked);
+            let improved = measure(&mut sorted.clone(), bubble_sort_improved_tracked);
+            assert_eq!(naive.comparisons, n * (n - 1));
+            assert_eq!(improved.comparisons, n - 1);
+            assert_eq!(improved.passes, 1);
+        }
+        let mut reversed: Vec<u64> = (0..100).rev().collect();
+        let improved = measure(&mut reversed, bubble_sort_improved_tracked);
+        assert_eq!(improved.swaps, 100 * 99 / 2);
+    }
+
+    #[test]
+    fn test_cost_models_of_other_sorts() {
+        let n = 200u64;
+        let sorted: Vec<u64> = (0..n).collect();
+        let mut shuffled = sorted.clone();
+        crate::prng::XorShift64::new(45).shuffle(&mut shuffled);
+
+        let selection = measure(&mut shuffled.clone(), selection_sort_tracked);
+        assert_eq!(selection.comparisons, n * (n - 1) / 2);
+        assert!(selection.swaps < n);
+        let insertion = measure(&mut sorted.clone(), insertion_sort_tracked);
+        assert_eq!((insertion.comparisons, insertion.swaps), (n - 1, 0));
+        let cycle = measure(&mut shuffled.clone(), cycle_sort_tracked);
+        assert_eq!(cycle.swaps, 0);
+        assert!(cycle.writes <= n);
+
+        for algorithm in DivideAndConquer::all() {
+            let mut v = shuffled.clone();
+            let metrics = measure(&mut v, |v| algorithm.sort_tracked(v));
+            assert_eq!(v, sorted, "{}", algorithm);
+            assert!(metrics.comparisons < n * n / 4, "{}", algorithm);
+            match algorithm {
+                DivideAndConquer::Merge(MergeVariant::TopDown) => {
+                    assert_eq!(metrics.swaps, 0);
+                    assert_eq!(metrics.aux_memory, (n / 2) as usize * 8);
+                }
+                DivideAndConquer::Heap => assert_eq!(metrics.aux_memory, 0),
+                _ => {}
+            }
+        }
+
+        let radix = measure(&mut shuffled.clone(), lsd_radix_sort_tracked);
+        assert_eq!(radix.comparisons, 0);
+        // only the lowest byte of 0..200 differs, one pass reads and writes every element
+        assert_eq!((radix.reads, radix.writes), (8 * n + n, n));
+    }
+
+    #[test]
+    fn test_sub_slices_report_whole_slice_indices() {
+        #[derive(Default)]
+        struct Log(Vec<(usize, usize)>);
+        impl Observer<u8> for Log {
+            fn swap(&mut self, i: usize, j: usize) {
+                self.0.push((i, j));
+            }
+        }
+        let mut v = vec![1u8, 2, 3, 4, 5, 6];
+        let mut log = Log::default();
+        let mut order = Natural;
+        let mut tracked = Tracked::new(&mut v, &mut order, &mut log);
+        tracked.slice(2..6).slice(1..4).reverse(0..3);
+        assert_eq!(format!("{:?}", tracked), "[1, 2, 3, 6, 5, 4]");
+        assert_eq!(log.0, vec![(3, 5)]);
+
+        let mut metrics = SortMetrics::default();
+        let mut tracked = Tracked::new(&mut v, &mut order, &mut metrics);
+        tracked.allocate(64);
+        tracked.release(64);
+        tracked.allocate(16);
+        tracked.rotate_left(0..6, 2);
+        assert_eq!(tracked.as_slice(), &[3, 6, 5, 4, 1, 2]);
+        assert_eq!(
+            (metrics.aux_memory, metrics.reads, metrics.writes),
+            (64, 1, 6)
+        );
+    }
+}
@@ -1 +1,27 @@
+pub mod bench;
 pub mod bub_sort;
+pub mod bucket_sort;
+pub mod cocktail_sort;
+pub mod comb_sort;
+pub mod counting_sort;
+pub mod cycle_sort;
+pub mod divide_conquer;
+pub mod gnome_sort;
+pub mod heap_sort;
+pub mod ins_sort;
+pub mod intro_sort;
+pub mod merge_sort;
+pub mod metrics;
+pub mod odd_even_sort;
+pub mod pancake_sort;
+pub mod pdq_sort;
+pub mod pigeonhole_sort;
+pub mod quick_sort;
+pub mod radix_key;
+pub mod radix_sort;
+pub mod render;
+pub mod sel_sort;
+pub mod shell_sort;
+pub mod tim_sort;
+pub mod trace;
+pub mod verify;
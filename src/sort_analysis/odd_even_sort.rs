@@ -0,0 +1,66 @@
+//! # Odd-Even Transposition Sort Algorithm
+//!
+//! Odd-even transposition sort alternates a phase comparing the pairs `(1, 2), (3, 4), ...` with
+//! a phase comparing `(0, 1), (2, 3), ...`. The pairs of a phase do not overlap, so on a parallel
+//! machine with one processor per pair every phase takes one step and n phases sort the input.
+//! Run sequentially it is O(n^2) and stable.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Odd-Even Transposition Sort algorithm, stable
+///
+/// Time complexity: O(n^2), n parallel phases.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::odd_even_sort::odd_even_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn odd_even_sort<T: PartialOrd>(v: &mut [T]) {
+    odd_even_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`odd_even_sort`] with a comparator, like [`slice::sort_by`]
+pub fn odd_even_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    odd_even_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`odd_even_sort`] by a key, like [`slice::sort_by_key`]
+pub fn odd_even_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    odd_even_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`odd_even_sort`] on a [`Tracked`] slice
+pub fn odd_even_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let mut sorted = false;
+    while !sorted {
+        sorted = true;
+        for start in [1, 0].iter() {
+            for i in (*start..v.len().saturating_sub(1)).step_by(2) {
+                if v.less(i + 1, i) {
+                    v.swap(i, i + 1);
+                    sorted = false;
+                }
+            }
+            v.pass();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::odd_even_sort::odd_even_sort;
+
+    #[test]
+    fn test_odd_even_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        odd_even_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut reversed: Vec<i32> = (0..9).rev().collect();
+        odd_even_sort(&mut reversed);
+        assert_eq!(reversed, (0..9).collect::<Vec<_>>());
+    }
+}
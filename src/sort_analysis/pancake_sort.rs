@@ -0,0 +1,65 @@
+//! # Pancake Sort Algorithm
+//!
+//! Pancake sort may only reverse prefixes of the slice, like flipping the top of a stack of
+//! pancakes with a spatula. Each round flips the largest unsorted element to the front and then
+//! down to the end of the unsorted part, so it needs at most 2n flips but O(n^2) element moves.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Pancake Sort algorithm, not stable
+///
+/// Time complexity: O(n^2), at most 2n flips.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::pancake_sort::pancake_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn pancake_sort<T: PartialOrd>(v: &mut [T]) {
+    pancake_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`pancake_sort`] with a comparator, like [`slice::sort_by`]
+pub fn pancake_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    pancake_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`pancake_sort`] by a key, like [`slice::sort_by_key`]
+pub fn pancake_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    pancake_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`pancake_sort`] on a [`Tracked`] slice
+pub fn pancake_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    for size in (2..=v.len()).rev() {
+        let mut max = 0;
+        for i in 1..size {
+            if v.less(max, i) {
+                max = i;
+            }
+        }
+        if max == size - 1 {
+            continue;
+        }
+        if max > 0 {
+            v.reverse(0..max + 1);
+        }
+        v.reverse(0..size);
+        v.pass();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::pancake_sort::pancake_sort;
+
+    #[test]
+    fn test_pancake_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        pancake_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13])
+    }
+}
@@ -0,0 +1,302 @@
+//! # Pattern-Defeating Quick Sort Algorithm
+//!
+//! Orson Peters' pdqsort (2016, now Rust's unstable sort) is intro sort which also recognises the
+//! patterns that make quick sort slow or that it could finish early:
+//!
+//! - sorted and reversed input: when choosing the pivot needed no swaps the slice is probably
+//!   sorted and a partial insertion sort, which gives up after a few out of order elements, may
+//!   finish it in O(n). When every choice swapped the slice is probably reversed and is reversed
+//!   first
+//! - many equal elements: when the pivot equals the element just before the part, which is the
+//!   pivot of an earlier partition, everything equal to it is split off and never looked at again,
+//!   which makes it O(n k) for k distinct values
+//! - bad pivots: after a partition leaving fewer than 1/8 of the elements on one side a few
+//!   elements are swapped to pseudo random places, and after log n such partitions the rest is
+//!   heap sorted like in intro sort
+//!
+//! This version is simplified: it partitions one element at a time where the real one moves blocks
+//! of offsets to avoid branch mispredictions.
+
+use crate::prng::XorShift64;
+use crate::sort_analysis::heap_sort::heap_sort_tracked;
+use crate::sort_analysis::ins_sort::insertion_sort_tracked;
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// parts up to this length are insertion sorted
+const INSERTION_THRESHOLD: usize = 20;
+/// parts from this length on take the pivot from the medians of three groups of three
+const NINTHER_THRESHOLD: usize = 50;
+/// the pivot choice makes 12 swaps at most, all of them on reversed input
+const MAX_SWAPS: usize = 12;
+
+/// Pattern-Defeating Quick Sort algorithm, not stable
+///
+/// Time complexity: O(n log n), O(n) when sorted or reversed.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::pdq_sort::pdq_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn pdq_sort<T: PartialOrd>(v: &mut [T]) {
+    pdq_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`pdq_sort`] with a comparator, like [`slice::sort_by`]
+pub fn pdq_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    pdq_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`pdq_sort`] by a key, like [`slice::sort_by_key`]
+pub fn pdq_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    pdq_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`pdq_sort`] on a [`Tracked`] slice
+pub fn pdq_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let limit = usize::BITS - v.len().leading_zeros();
+    let len = v.len();
+    recurse(v, 0..len, false, limit);
+}
+
+/// Sort `v[range]`. With `has_pred` the element `v[range.start - 1]` is not greater than any
+/// element of the range, after `limit` unbalanced partitions the range is heap sorted.
+fn recurse<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    range: core::ops::Range<usize>,
+    mut has_pred: bool,
+    mut limit: u32,
+) {
+    let (mut start, mut end) = (range.start, range.end);
+    let mut was_balanced = true;
+    let mut was_partitioned = true;
+    loop {
+        let len = end - start;
+        if len <= INSERTION_THRESHOLD {
+            insertion_sort_tracked(&mut v.slice(start..end));
+            return;
+        }
+        if limit == 0 {
+            heap_sort_tracked(&mut v.slice(start..end));
+            return;
+        }
+        if !was_balanced {
+            break_patterns(&mut v.slice(start..end));
+            limit -= 1;
+        }
+        let (pivot, likely_sorted) = choose_pivot(&mut v.slice(start..end));
+        // the last partition did not move anything and the pivot choice saw sorted samples
+        if was_balanced
+            && was_partitioned
+            && likely_sorted
+            && partial_insertion_sort(&mut v.slice(start..end))
+        {
+            return;
+        }
+        v.swap(start, start + pivot);
+        if has_pred && !v.less(start - 1, start) {
+            // the pivot equals the predecessor, so nothing in the range is smaller
+            start += partition_equal(&mut v.slice(start..end));
+            continue;
+        }
+        let (mid, moved) = partition(&mut v.slice(start..end));
+        was_balanced = mid.min(len - mid - 1) >= len / 8;
+        was_partitioned = !moved;
+        // recurse into the shorter side and loop on the longer one
+        let pivot_at = start + mid;
+        if mid < len - mid - 1 {
+            recurse(v, start..pivot_at, has_pred, limit);
+            start = pivot_at + 1;
+            has_pred = true;
+        } else {
+            recurse(v, pivot_at + 1..end, true, limit);
+            end = pivot_at;
+        }
+    }
+}
+
+/// Partition around the pivot in `v[0]` into smaller elements and elements not smaller. Returns
+/// the final index of the pivot and whether any element had to be swapped.
+fn partition<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) -> (usize, bool) {
+    // v[1..l] is smaller than the pivot and v[r..] is not
+    let (mut l, mut r) = (1, v.len());
+    let mut moved = false;
+    loop {
+        while l < r && v.less(l, 0) {
+            l += 1;
+        }
+        while l < r && !v.less(r - 1, 0) {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        v.swap(l, r - 1);
+        moved = true;
+        l += 1;
+        r -= 1;
+    }
+    v.swap(0, l - 1);
+    (l - 1, moved)
+}
+
+/// Partition around the pivot in `v[0]`, which no element is smaller than, into the elements
+/// equal to it and the larger ones. Returns the number of equal elements.
+fn partition_equal<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) -> usize {
+    let (mut l, mut r) = (1, v.len());
+    loop {
+        while l < r && !v.less(0, l) {
+            l += 1;
+        }
+        while l < r && v.less(0, r - 1) {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        v.swap(l, r - 1);
+        l += 1;
+        r -= 1;
+    }
+    l
+}
+
+/// Index of the pivot and whether the samples were already in order. The samples are three
+/// positions at a quarter, half and three quarters of the slice, each replaced by the median of
+/// itself and its neighbours on long slices. If every comparison swapped the slice is probably
+/// reversed, it is reversed and then counts as likely sorted.
+fn choose_pivot<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) -> (usize, bool) {
+    let len = v.len();
+    let (mut a, mut b, mut c) = (len / 4, len / 4 * 2, len / 4 * 3);
+    let mut swaps = 0;
+    if len >= NINTHER_THRESHOLD {
+        for x in [&mut a, &mut b, &mut c].iter_mut() {
+            let (mut low, mut high) = (**x - 1, **x + 1);
+            sort3(v, &mut low, x, &mut high, &mut swaps);
+        }
+    }
+    sort3(v, &mut a, &mut b, &mut c, &mut swaps);
+    if swaps < MAX_SWAPS {
+        (b, swaps == 0)
+    } else {
+        v.reverse(0..len);
+        (len - 1 - b, true)
+    }
+}
+
+/// order the indices `a`, `b` and `c` by the elements they point to, counting the exchanges
+fn sort3<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    a: &mut usize,
+    b: &mut usize,
+    c: &mut usize,
+    swaps: &mut usize,
+) {
+    let mut sort2 = |v: &mut Tracked<'_, T, C, O>, x: &mut usize, y: &mut usize| {
+        if v.less(*y, *x) {
+            core::mem::swap(x, y);
+            *swaps += 1;
+        }
+    };
+    sort2(v, a, b);
+    sort2(v, b, c);
+    sort2(v, a, b);
+}
+
+/// Insertion sort which gives up after fixing 5 out of order elements, it only starts on slices
+/// of at least 50 elements. Returns whether the slice is sorted.
+fn partial_insertion_sort<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) -> bool {
+    const MAX_STEPS: usize = 5;
+    const SHORTEST_SHIFTING: usize = 50;
+    let len = v.len();
+    let mut i = 1;
+    for _ in 0..MAX_STEPS {
+        while i < len && !v.less(i, i - 1) {
+            i += 1;
+        }
+        if i == len {
+            return true;
+        }
+        if len < SHORTEST_SHIFTING {
+            return false;
+        }
+        // swap the pair and move both elements to their places among the sorted neighbours
+        v.swap(i - 1, i);
+        let mut j = i - 1;
+        while j > 0 && v.less(j, j - 1) {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+        let mut j = i;
+        while j + 1 < len && v.less(j + 1, j) {
+            v.swap(j, j + 1);
+            j += 1;
+        }
+    }
+    false
+}
+
+/// swap the three elements around the middle with pseudo random ones, seeded by the length
+fn break_patterns<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let len = v.len();
+    let mut rng = XorShift64::new(len as u64);
+    let pos = len / 4 * 2;
+    for i in 0..3 {
+        let other = rng.below(len);
+        v.swap(pos - 1 + i, other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::metrics::measure;
+    use crate::sort_analysis::pdq_sort::{pdq_sort, pdq_sort_tracked};
+
+    #[test]
+    fn test_pdq_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        pdq_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut rng = XorShift64::new(50);
+        let inputs: Vec<Vec<u32>> = vec![
+            vec![],
+            vec![1],
+            (0..1000).collect(),
+            (0..1000).rev().collect(),
+            vec![5; 300],
+            (0..1000).map(|i| (i * 7919) % 13).collect(),
+            (0..1000).map(|_| rng.below(1000) as u32).collect(),
+            (0..1000)
+                .map(|i| if i % 100 == 0 { 0 } else { i })
+                .collect(),
+            (0..1000).map(|i| (i % 500) as u32).collect(),
+        ];
+        for input in inputs {
+            let mut v = input.clone();
+            let mut expected = input;
+            expected.sort_unstable();
+            pdq_sort(&mut v);
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn test_patterns_cost_linear_time() {
+        let n = 4096u64;
+        let mut sorted: Vec<u32> = (0..n as u32).collect();
+        assert!(measure(&mut sorted, pdq_sort_tracked).comparisons < n + 32);
+        let mut reversed: Vec<u32> = (0..n as u32).rev().collect();
+        let metrics = measure(&mut reversed, pdq_sort_tracked);
+        assert!(metrics.comparisons < n + 32, "{}", metrics);
+        assert_eq!(reversed, (0..n as u32).collect::<Vec<_>>());
+        let mut equal = vec![7u32; n as usize];
+        assert!(measure(&mut equal, pdq_sort_tracked).comparisons < 3 * n);
+        // a few distinct values, every value is split off by one equal partition
+        let mut few: Vec<u32> = (0..n as u32).map(|i| (i * 7919) % 4).collect();
+        assert!(measure(&mut few, pdq_sort_tracked).comparisons < 16 * n);
+    }
+}
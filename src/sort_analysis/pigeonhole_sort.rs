@@ -0,0 +1,73 @@
+//! # Pigeonhole Sort Algorithm
+//!
+//! Pigeonhole sort puts every element into the hole of its key, one hole for each key from the
+//! smallest to the largest one, and reads the holes back in order. It is counting sort which
+//! remembers the elements instead of only counting them, with the same O(n + k) cost and the same
+//! limits on the keys.
+
+use crate::sort_analysis::counting_sort::small_keys;
+use crate::sort_analysis::metrics::{Natural, Observer, Order, Tracked};
+use crate::sort_analysis::radix_key::RadixKey;
+use core::mem::size_of;
+
+/// Pigeonhole Sort algorithm, stable
+///
+/// Time complexity: O(n + k) for k = max - min + 1. Extra space: O(n + k).
+///
+/// # Panics
+///
+/// Panics if the keys are longer than 8 bytes, of different lengths, or span more keys than fit
+/// in memory.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4u8, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::pigeonhole_sort::pigeonhole_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn pigeonhole_sort<K: RadixKey + PartialOrd>(v: &mut [K]) {
+    pigeonhole_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`pigeonhole_sort`] on a [`Tracked`] slice, the order is not used
+pub fn pigeonhole_sort_tracked<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+) {
+    if v.len() < 2 {
+        return;
+    }
+    let (keys, min, range) = small_keys(v);
+    let tables = range * size_of::<Vec<usize>>() + 3 * keys.len() * size_of::<usize>();
+    v.allocate(tables);
+    let mut holes: Vec<Vec<usize>> = vec![Vec::new(); range];
+    for (i, &key) in keys.iter().enumerate() {
+        holes[(key - min) as usize].push(i);
+    }
+    let mut dest = vec![0; v.len()];
+    for (position, &i) in holes.iter().flatten().enumerate() {
+        dest[i] = position;
+    }
+    v.permute(&dest);
+    v.release(tables);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::pigeonhole_sort::pigeonhole_sort;
+
+    #[test]
+    fn test_pigeonhole_sort() {
+        let mut rng = XorShift64::new(45);
+        let mut v: Vec<i64> = (0..2000).map(|_| rng.below(300) as i64 - 150).collect();
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        pigeonhole_sort(&mut v);
+        assert_eq!(v, expected);
+
+        let mut one = vec![42u32];
+        pigeonhole_sort(&mut one);
+        assert_eq!(one, vec![42]);
+    }
+}
@@ -0,0 +1,289 @@
+//! # Quick Sort Algorithm
+//!
+//! Quick sort picks a pivot, partitions the slice into the elements smaller and larger than it
+//! and sorts both parts. A pivot near the median halves the slice and gives O(n log n), a pivot
+//! that is always the smallest element gives O(n^2), which is what the first element does on
+//! sorted input. How the pivot is chosen is a [`PivotChoice`], how the slice is split a
+//! [`Partition`] scheme, and [`quick_sort_with`] combines any two of them.
+//!
+//! The sort recurses into the smaller part and loops on the larger one, so the stack stays
+//! O(log n) deep even when the running time degrades.
+
+use crate::prng::XorShift64;
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// How a slice is split around the pivot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Partition {
+    /// one scan from the left which swaps every smaller element behind a growing prefix (Lomuto),
+    /// easy to get right but O(n^2) when all elements are equal
+    Lomuto,
+    /// two scans from both ends which swap the pairs that are on the wrong side (Hoare), fewer
+    /// swaps than Lomuto and balanced on equal elements
+    Hoare,
+    /// three parts smaller, equal and larger than the pivot (Dijkstra's Dutch national flag), the
+    /// equal part is never looked at again
+    ThreeWay,
+}
+
+/// Which element becomes the pivot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivotChoice {
+    /// the first element
+    First,
+    /// the median of the first, middle and last element
+    MedianOfThree,
+    /// a pseudo random element, the generator is seeded from the length so runs are repeatable
+    Random,
+    /// Tukey's ninther, the median of the medians of three groups of three spread over the slice
+    Ninther,
+}
+
+impl Partition {
+    pub const ALL: [Partition; 3] = [Partition::Lomuto, Partition::Hoare, Partition::ThreeWay];
+}
+
+impl PivotChoice {
+    pub const ALL: [PivotChoice; 4] = [
+        PivotChoice::First,
+        PivotChoice::MedianOfThree,
+        PivotChoice::Random,
+        PivotChoice::Ninther,
+    ];
+}
+
+impl std::fmt::Display for Partition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Partition::Lomuto => "lomuto",
+            Partition::Hoare => "hoare",
+            Partition::ThreeWay => "three-way",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl std::fmt::Display for PivotChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            PivotChoice::First => "first",
+            PivotChoice::MedianOfThree => "median-of-three",
+            PivotChoice::Random => "random",
+            PivotChoice::Ninther => "ninther",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Quick Sort algorithm with Hoare partition and a median of three pivot, not stable
+///
+/// Time complexity: O(n log n) on average, O(n^2) worst case.
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::quick_sort::quick_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn quick_sort<T: PartialOrd>(v: &mut [T]) {
+    quick_sort_with(v, Partition::Hoare, PivotChoice::MedianOfThree);
+}
+
+/// [`quick_sort`] with a comparator, like [`slice::sort_by`]
+pub fn quick_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    quick_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`quick_sort`] by a key, like [`slice::sort_by_key`]
+pub fn quick_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    quick_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`quick_sort`] on a [`Tracked`] slice
+pub fn quick_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    quick_sort_with_tracked(v, Partition::Hoare, PivotChoice::MedianOfThree);
+}
+
+/// Quick Sort algorithm with a chosen partition scheme and pivot, not stable
+///
+/// # Example
+///
+/// ```
+/// use dsa_sport::sort_analysis::quick_sort::{quick_sort_with, Partition, PivotChoice};
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// quick_sort_with(&mut v, Partition::ThreeWay, PivotChoice::Ninther);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn quick_sort_with<T: PartialOrd>(v: &mut [T], partition: Partition, pivot: PivotChoice) {
+    quick_sort_with_tracked(
+        &mut Tracked::new(v, &mut Natural, &mut ()),
+        partition,
+        pivot,
+    );
+}
+
+/// [`quick_sort_with`] on a [`Tracked`] slice
+pub fn quick_sort_with_tracked<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    partition: Partition,
+    pivot: PivotChoice,
+) {
+    let mut rng = XorShift64::new(v.len() as u64);
+    // the part still to sort is `v[start..end]`
+    let (mut start, mut end) = (0, v.len());
+    while end - start > 1 {
+        let mut part = v.slice(start..end);
+        let chosen = choose_pivot(&mut part, pivot, &mut rng);
+        part.swap(0, chosen);
+        let (less, greater) = partition_at_first(&mut part, partition);
+        if less < part.len() - greater {
+            quick_sort_with_tracked(&mut part.slice(0..less), partition, pivot);
+            start += greater;
+        } else {
+            quick_sort_with_tracked(&mut part.slice(greater..part.len()), partition, pivot);
+            end = start + less;
+        }
+    }
+}
+
+/// Partition around the pivot in `v[0]`. Returns `(less, greater)`: `v[..less]` holds no larger
+/// and `v[greater..]` no smaller element than the pivot, and everything in between is in place.
+pub(crate) fn partition_at_first<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    partition: Partition,
+) -> (usize, usize) {
+    match partition {
+        Partition::Lomuto => {
+            let mut store = 0;
+            for i in 1..v.len() {
+                if v.less(i, 0) {
+                    store += 1;
+                    v.swap(store, i);
+                }
+            }
+            v.swap(0, store);
+            (store, store + 1)
+        }
+        Partition::Hoare => {
+            let (mut i, mut j) = (0, v.len());
+            loop {
+                i += 1;
+                while i < v.len() && v.less(i, 0) {
+                    i += 1;
+                }
+                j -= 1;
+                while v.less(0, j) {
+                    j -= 1;
+                }
+                if i >= j {
+                    break;
+                }
+                v.swap(i, j);
+            }
+            v.swap(0, j);
+            (j, j + 1)
+        }
+        Partition::ThreeWay => {
+            // v[lt..i] equals the pivot and v[lt] always is a copy of it to compare against
+            let (mut lt, mut i, mut gt) = (0, 1, v.len());
+            while i < gt {
+                if v.less(i, lt) {
+                    v.swap(lt, i);
+                    lt += 1;
+                    i += 1;
+                } else if v.less(lt, i) {
+                    gt -= 1;
+                    v.swap(i, gt);
+                } else {
+                    i += 1;
+                }
+            }
+            (lt, gt)
+        }
+    }
+}
+
+/// index of the pivot, `v` holds at least two elements
+pub(crate) fn choose_pivot<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    pivot: PivotChoice,
+    rng: &mut XorShift64,
+) -> usize {
+    let last = v.len() - 1;
+    match pivot {
+        PivotChoice::First => 0,
+        PivotChoice::MedianOfThree => median_of_three(v, 0, last / 2, last),
+        PivotChoice::Random => rng.below(v.len()),
+        PivotChoice::Ninther if v.len() < 9 => median_of_three(v, 0, last / 2, last),
+        PivotChoice::Ninther => {
+            let step = v.len() / 8;
+            let mid = last / 2;
+            let a = median_of_three(v, 0, step, 2 * step);
+            let b = median_of_three(v, mid - step, mid, mid + step);
+            let c = median_of_three(v, last - 2 * step, last - step, last);
+            median_of_three(v, a, b, c)
+        }
+    }
+}
+
+/// index of the median of `v[a]`, `v[b]` and `v[c]`
+pub(crate) fn median_of_three<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    a: usize,
+    b: usize,
+    c: usize,
+) -> usize {
+    if v.less(a, b) {
+        if v.less(b, c) {
+            b
+        } else if v.less(a, c) {
+            c
+        } else {
+            a
+        }
+    } else if v.less(a, c) {
+        a
+    } else if v.less(b, c) {
+        c
+    } else {
+        b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::quick_sort::{quick_sort, quick_sort_with, Partition, PivotChoice};
+
+    #[test]
+    fn test_quick_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        quick_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13])
+    }
+
+    #[test]
+    fn test_every_partition_and_pivot() {
+        let inputs: Vec<Vec<u32>> = vec![
+            vec![],
+            vec![1],
+            (0..1000).collect(),
+            (0..1000).rev().collect(),
+            vec![5; 300],
+            (0..1000).map(|i| (i * 7919) % 13).collect(),
+            (0..1000).map(|i| (i * 7919) % 1009).collect(),
+        ];
+        for &partition in Partition::ALL.iter() {
+            for &pivot in PivotChoice::ALL.iter() {
+                for input in inputs.iter() {
+                    let mut v = input.clone();
+                    let mut expected = input.clone();
+                    expected.sort_unstable();
+                    quick_sort_with(&mut v, partition, pivot);
+                    assert_eq!(v, expected, "{} {}", partition, pivot);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! # Radix Keys
+//!
+//! Counting, radix and pigeonhole sort never compare two elements, they look at the digits of a
+//! key. [`RadixKey`] describes a key as a sequence of bytes, most significant first, which
+//! compare in the same order as the keys themselves. A shorter key that is a prefix of a longer
+//! one comes first, like in a dictionary.
+//!
+//! Unsigned integers are their big-endian bytes. Signed integers flip the sign bit first so that
+//! negative numbers come before positive ones. Byte strings and `str` are their bytes.
+//!
+//! # Example
+//!
+//! ```
+//! use dsa_sport::sort_analysis::radix_key::RadixKey;
+//! assert_eq!(0x1234u16.key_len(), 2);
+//! assert_eq!(0x1234u16.key_byte(0), 0x12);
+//! assert!((-1i8).key_byte(0) < 1i8.key_byte(0));
+//! assert_eq!("ab".key_byte(1), b'b');
+//! ```
+
+use core::cmp::Ordering;
+
+/// A key made of bytes, comparing the bytes lexicographically orders the keys.
+pub trait RadixKey {
+    /// number of bytes of this key
+    fn key_len(&self) -> usize;
+
+    /// byte `index` of the key counted from the most significant one, `index < key_len()`
+    fn key_byte(&self, index: usize) -> u8;
+}
+
+macro_rules! radix_key_unsigned {
+    ($($t:ty),*) => {$(
+        impl RadixKey for $t {
+            fn key_len(&self) -> usize {
+                core::mem::size_of::<$t>()
+            }
+
+            fn key_byte(&self, index: usize) -> u8 {
+                self.to_be_bytes()[index]
+            }
+        }
+    )*};
+}
+
+macro_rules! radix_key_signed {
+    ($($t:ty),*) => {$(
+        impl RadixKey for $t {
+            fn key_len(&self) -> usize {
+                core::mem::size_of::<$t>()
+            }
+
+            fn key_byte(&self, index: usize) -> u8 {
+                let byte = self.to_be_bytes()[index];
+                if index == 0 {
+                    byte ^ 0x80
+                } else {
+                    byte
+                }
+            }
+        }
+    )*};
+}
+
+radix_key_unsigned!(u8, u16, u32, u64, u128, usize);
+radix_key_signed!(i8, i16, i32, i64, i128, isize);
+
+impl RadixKey for [u8] {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self[index]
+    }
+}
+
+impl RadixKey for Vec<u8> {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self[index]
+    }
+}
+
+impl RadixKey for str {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.as_bytes()[index]
+    }
+}
+
+impl RadixKey for String {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.as_bytes()[index]
+    }
+}
+
+impl<K: RadixKey + ?Sized> RadixKey for &K {
+    fn key_len(&self) -> usize {
+        (**self).key_len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        (**self).key_byte(index)
+    }
+}
+
+/// Number of `bits` wide digits in a key, `bits` is 1, 2, 4, 8 or 16.
+pub(crate) fn digit_count<K: RadixKey + ?Sized>(key: &K, bits: u32) -> usize {
+    (key.key_len() * 8).div_ceil(bits as usize)
+}
+
+/// Number of different values [`digit`] returns for `bits` wide digits, the size of a count
+/// table.
+pub(crate) fn digit_values(bits: u32) -> usize {
+    if bits == 16 {
+        256 * 257 + 1
+    } else {
+        (1 << bits) + 1
+    }
+}
+
+/// Digit `position` of a key counted from the most significant one, shifted up by one so that
+/// zero can stand for "the key has already ended". A 16 bit digit of a key with an odd number of
+/// bytes ends after its first byte, so it takes 257 values for the second byte: the end of the
+/// key below all 256 bytes. That keeps `[1]` before `[1, 0]`.
+pub(crate) fn digit<K: RadixKey + ?Sized>(key: &K, position: usize, bits: u32) -> usize {
+    let bit = position * bits as usize;
+    let byte = bit / 8;
+    if byte >= key.key_len() {
+        return 0;
+    }
+    let value = if bits == 16 {
+        let low = if byte + 1 < key.key_len() {
+            key.key_byte(byte + 1) as usize + 1
+        } else {
+            0
+        };
+        key.key_byte(byte) as usize * 257 + low
+    } else {
+        let shift = 8 - bits as usize - bit % 8;
+        (key.key_byte(byte) as usize >> shift) & ((1 << bits) - 1)
+    };
+    value + 1
+}
+
+/// Panics unless a digit is 1, 2, 4, 8 or 16 bits wide.
+pub(crate) fn check_bits(bits: u32) {
+    assert!(
+        [1, 2, 4, 8, 16].contains(&bits),
+        "a digit must be 1, 2, 4, 8 or 16 bits wide, not {}",
+        bits
+    );
+}
+
+/// A key of at most 8 bytes as one number, keys of the same length keep their order.
+pub(crate) fn small_key<K: RadixKey + ?Sized>(key: &K) -> u64 {
+    assert!(
+        key.key_len() <= 8,
+        "counting needs keys of at most 8 bytes, not {}",
+        key.key_len()
+    );
+    (0..key.key_len()).fold(0, |value, i| value << 8 | key.key_byte(i) as u64)
+}
+
+/// Compare two keys byte by byte, a key that runs out first is the smaller one.
+pub(crate) fn compare_keys<K: RadixKey + ?Sized>(a: &K, b: &K) -> Ordering {
+    let common = a.key_len().min(b.key_len());
+    (0..common)
+        .map(|i| a.key_byte(i).cmp(&b.key_byte(i)))
+        .find(|&order| order != Ordering::Equal)
+        .unwrap_or_else(|| a.key_len().cmp(&b.key_len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::radix_key::{digit, digit_values, RadixKey};
+
+    #[test]
+    fn test_key_bytes_keep_the_order() {
+        let ints = [i32::MIN, -70000, -1, 0, 1, 255, 256, i32::MAX];
+        for w in ints.windows(2) {
+            let a: Vec<u8> = (0..4).map(|i| w[0].key_byte(i)).collect();
+            let b: Vec<u8> = (0..4).map(|i| w[1].key_byte(i)).collect();
+            assert!(a < b, "{} {}", w[0], w[1]);
+        }
+        assert_eq!(digit(&0xABu8, 0, 4), 0xA + 1);
+        assert_eq!(digit(&0xABu8, 1, 4), 0xB + 1);
+        assert_eq!(digit(&0xABu8, 2, 4), 0);
+        assert_eq!(digit(&0b1000_0000u8, 0, 1), 2);
+        assert_eq!(digit("abc", 1, 16), b'c' as usize * 257 + 1);
+        assert_eq!(digit(&vec![1u8, 2], 0, 16), 257 + 3 + 1);
+        assert!(digit(&vec![1u8], 0, 16) < digit(&vec![1u8, 0], 0, 16));
+        assert_eq!(digit(&vec![255u8, 255], 0, 16), digit_values(16) - 1);
+    }
+}
@@ -0,0 +1,329 @@
+//! # Radix Sort Algorithm
+//!
+//! Radix sort splits every key into digits of a few bits and sorts digit by digit with a counting
+//! pass, so it handles keys of any range in O(d · (n + r)) for d digits of radix r. The digit
+//! width is configurable: 1, 2, 4, 8 or 16 bits, that is a radix of 2 up to 65536. Wider digits
+//! mean fewer passes over the data but larger count tables.
+//!
+//! LSD (least significant digit first) sorts the whole slice once per digit, starting with the
+//! last one; every pass is stable, so the earlier passes decide the ties of the later ones. MSD
+//! (most significant digit first) sorts by the first digit and recurses into every bucket, so it
+//! stops as soon as the keys differ and suits strings with long or varying lengths.
+//!
+//! Keys are read through [`RadixKey`]. A key that is a prefix of a longer one sorts first.
+
+use crate::sort_analysis::metrics::{Natural, Observer, Order, Tracked};
+use crate::sort_analysis::radix_key::{
+    check_bits, compare_keys, digit, digit_count, digit_values, RadixKey,
+};
+use core::cmp::Ordering;
+use core::mem::size_of;
+
+/// MSD slices up to this length are finished by insertion sort instead of another count table
+const SMALL: usize = 16;
+
+/// LSD Radix Sort algorithm with 8 bit digits, stable
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, -6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::radix_sort::lsd_radix_sort(&mut v);
+/// assert_eq!(v, vec![-6,1,3,4,8,11,13])
+///```
+pub fn lsd_radix_sort<K: RadixKey + PartialOrd>(v: &mut [K]) {
+    lsd_radix_sort_with(v, 8);
+}
+
+/// [`lsd_radix_sort`] on a [`Tracked`] slice, the order is not used
+pub fn lsd_radix_sort_tracked<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+) {
+    lsd_radix_sort_with_tracked(v, 8);
+}
+
+/// LSD Radix Sort algorithm with digits of `bits` bits, stable
+///
+/// Time complexity: O(d · (n + 2^bits)) for keys of d digits. Extra space: O(n + 2^bits).
+///
+/// # Panics
+///
+/// Panics if `bits` is not 1, 2, 4, 8 or 16.
+///
+/// # Example
+///
+/// ```
+/// use dsa_sport::sort_analysis::radix_sort::lsd_radix_sort_with;
+/// let mut v = vec!["pear", "fig", "apple", "figs"];
+/// lsd_radix_sort_with(&mut v, 4);
+/// assert_eq!(v, vec!["apple", "fig", "figs", "pear"])
+///```
+pub fn lsd_radix_sort_with<K: RadixKey + PartialOrd>(v: &mut [K], bits: u32) {
+    lsd_radix_sort_with_tracked(&mut Tracked::new(v, &mut Natural, &mut ()), bits);
+}
+
+/// [`lsd_radix_sort_with`] on a [`Tracked`] slice, the order is not used
+pub fn lsd_radix_sort_with_tracked<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+    bits: u32,
+) {
+    check_bits(bits);
+    let len = v.len();
+    if len < 2 {
+        return;
+    }
+    let passes = v
+        .as_slice()
+        .iter()
+        .map(|key| digit_count(key, bits))
+        .max()
+        .unwrap_or(0);
+    let tables = (digit_values(bits) + 2 * len) * size_of::<usize>();
+    v.allocate(tables);
+    let mut count = vec![0usize; digit_values(bits)];
+    let mut digits = vec![0usize; len];
+    let mut dest = vec![0usize; len];
+    for position in (0..passes).rev() {
+        count.iter_mut().for_each(|slot| *slot = 0);
+        for (i, d) in digits.iter_mut().enumerate() {
+            *d = digit(v.key(i), position, bits);
+            count[*d] += 1;
+        }
+        // a digit shared by every key leaves the order as it is
+        if !count.contains(&len) {
+            bucket_starts(&mut count);
+            for (i, &d) in digits.iter().enumerate() {
+                dest[i] = count[d];
+                count[d] += 1;
+            }
+            v.permute(&dest);
+        }
+        v.pass();
+    }
+    v.release(tables);
+}
+
+/// MSD Radix Sort algorithm with 8 bit digits, stable
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4u32, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::radix_sort::msd_radix_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn msd_radix_sort<K: RadixKey + PartialOrd>(v: &mut [K]) {
+    msd_radix_sort_with(v, 8);
+}
+
+/// [`msd_radix_sort`] on a [`Tracked`] slice, the order is not used
+pub fn msd_radix_sort_tracked<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+) {
+    msd_radix_sort_with_tracked(v, 8);
+}
+
+/// MSD Radix Sort algorithm with digits of `bits` bits, stable
+///
+/// Buckets of at most 16 keys are finished by insertion sort, which keeps equal keys in order
+/// like the distribution into buckets does.
+///
+/// Time complexity: O(d · (n + 2^bits)) for keys of d digits, usually far less because it only
+/// looks at the digits needed to tell the keys apart. Extra space: O(n + d · 2^bits).
+///
+/// # Panics
+///
+/// Panics if `bits` is not 1, 2, 4, 8 or 16.
+///
+/// # Example
+///
+/// ```
+/// use dsa_sport::sort_analysis::radix_sort::msd_radix_sort_with;
+/// let mut v = vec!["pear", "fig", "apple", "figs"];
+/// msd_radix_sort_with(&mut v, 2);
+/// assert_eq!(v, vec!["apple", "fig", "figs", "pear"])
+///```
+pub fn msd_radix_sort_with<K: RadixKey + PartialOrd>(v: &mut [K], bits: u32) {
+    msd_radix_sort_with_tracked(&mut Tracked::new(v, &mut Natural, &mut ()), bits);
+}
+
+/// [`msd_radix_sort_with`] on a [`Tracked`] slice, the order is not used
+pub fn msd_radix_sort_with_tracked<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+    bits: u32,
+) {
+    check_bits(bits);
+    let tables = 2 * v.len() * size_of::<usize>();
+    v.allocate(tables);
+    let mut digits = vec![0usize; v.len()];
+    let mut dest = vec![0usize; v.len()];
+    msd_help(v, &mut digits, &mut dest, 0, bits);
+    v.release(tables);
+}
+
+fn msd_help<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+    digits: &mut [usize],
+    dest: &mut [usize],
+    position: usize,
+    bits: u32,
+) {
+    let len = v.len();
+    if len <= SMALL {
+        insertion_sort_keys(v);
+        return;
+    }
+    let count_bytes = digit_values(bits) * size_of::<usize>();
+    v.allocate(count_bytes);
+    let mut count = vec![0usize; digit_values(bits)];
+    let (digits, dest) = (&mut digits[..len], &mut dest[..len]);
+    for (i, d) in digits.iter_mut().enumerate() {
+        *d = digit(v.key(i), position, bits);
+        count[*d] += 1;
+    }
+    let sizes = count.clone();
+    bucket_starts(&mut count);
+    for (i, &d) in digits.iter().enumerate() {
+        dest[i] = count[d];
+        count[d] += 1;
+    }
+    v.permute(dest);
+    v.release(count_bytes);
+    // bucket zero holds the keys that already ended, they are all equal
+    let mut start = sizes[0];
+    for &size in sizes[1..].iter() {
+        msd_help(
+            &mut v.slice(start..start + size),
+            digits,
+            dest,
+            position + 1,
+            bits,
+        );
+        start += size;
+    }
+}
+
+/// turn the size of every bucket into the index where it starts
+fn bucket_starts(count: &mut [usize]) {
+    let mut start = 0;
+    for slot in count.iter_mut() {
+        let size = *slot;
+        *slot = start;
+        start += size;
+    }
+}
+
+fn insertion_sort_keys<K: RadixKey, C: Order<K>, O: Observer<K>>(v: &mut Tracked<'_, K, C, O>) {
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && key_greater(v, j - 1, j) {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// compare the keys of `v[i]` and `v[j]` byte by byte, one comparison
+fn key_greater<K: RadixKey, C: Order<K>, O: Observer<K>>(
+    v: &mut Tracked<'_, K, C, O>,
+    i: usize,
+    j: usize,
+) -> bool {
+    let (v, offset, _, observer) = v.parts();
+    observer.compare(offset + i, offset + j);
+    compare_keys(&v[i], &v[j]) == Ordering::Greater
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::radix_key::RadixKey;
+    use crate::sort_analysis::radix_sort::{lsd_radix_sort_with, msd_radix_sort_with};
+
+    const BITS: [u32; 5] = [1, 2, 4, 8, 16];
+
+    fn check<K: RadixKey + Ord + Clone + std::fmt::Debug>(input: &[K]) {
+        let mut expected = input.to_vec();
+        expected.sort_unstable();
+        for &bits in BITS.iter() {
+            let mut v = input.to_vec();
+            lsd_radix_sort_with(&mut v, bits);
+            assert_eq!(v, expected, "lsd {} bits", bits);
+            let mut v = input.to_vec();
+            msd_radix_sort_with(&mut v, bits);
+            assert_eq!(v, expected, "msd {} bits", bits);
+        }
+    }
+
+    #[test]
+    fn test_radix_sort_integers() {
+        let mut rng = XorShift64::new(46);
+        check::<u8>(&[]);
+        check(&[7u8]);
+        check(&(0..3000).map(|_| rng.next_u64() as u32).collect::<Vec<_>>());
+        check(&(0..3000).map(|_| rng.next_u64() as i64).collect::<Vec<_>>());
+        check(
+            &(0..3000)
+                .map(|_| rng.below(50) as i16 - 25)
+                .collect::<Vec<_>>(),
+        );
+        check(&[i128::MIN, -1, 0, 1, i128::MAX, -1]);
+        check(&(0..500u64).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_radix_sort_byte_strings() {
+        let mut rng = XorShift64::new(47);
+        let words: Vec<String> = (0..2000)
+            .map(|_| {
+                let len = rng.below(6);
+                (0..len)
+                    .map(|_| (b'a' + rng.below(3) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        check(&words);
+        let bytes: Vec<Vec<u8>> = (0..1000)
+            .map(|_| (0..rng.below(4)).map(|_| rng.below(256) as u8).collect())
+            .collect();
+        check(&bytes);
+        check(&[
+            vec![1, 0],
+            vec![1],
+            vec![0],
+            vec![],
+            vec![1, 0, 0],
+            vec![0, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_lsd_radix_sort_is_stable() {
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        struct Tagged(u16, usize);
+        impl RadixKey for Tagged {
+            fn key_len(&self) -> usize {
+                2
+            }
+            fn key_byte(&self, index: usize) -> u8 {
+                self.0.key_byte(index)
+            }
+        }
+        let mut rng = XorShift64::new(48);
+        for &bits in BITS.iter() {
+            let mut v: Vec<Tagged> = (0..1000)
+                .map(|i| Tagged(rng.below(40) as u16 * 300, i))
+                .collect();
+            lsd_radix_sort_with(&mut v, bits);
+            assert!(v
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radix_sort_rejects_odd_digits() {
+        lsd_radix_sort_with(&mut [3u8, 1], 3);
+    }
+}
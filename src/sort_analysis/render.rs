@@ -0,0 +1,354 @@
+//! # Rendering Sort Traces
+//!
+//! Draws the [`Frame`]s of a [`Trace`] as bar charts, one bar per element and the height given by
+//! a `key` function. The bars of the last event stand out: compared elements are drawn with `?`
+//! in ASCII and orange in SVG, swapped or written elements with `*` and red.
+//!
+//! [`ascii_frames`] gives one string per frame to print in a terminal, [`svg_frame`] one still
+//! picture, and [`animated_svg`] a single SVG file which plays the whole trace in a browser.
+//!
+//! # Example
+//!
+//! ```
+//! use dsa_sport::sort_analysis::bub_sort::bubble_sort_improved_tracked;
+//! use dsa_sport::sort_analysis::render::ascii_frames;
+//! use dsa_sport::sort_analysis::trace::record;
+//! let mut v = vec![2, 3, 1];
+//! let trace = record(&mut v, bubble_sort_improved_tracked);
+//! let frames = ascii_frames(&trace, 3, |&x| x as f64);
+//! assert_eq!(frames[0], " # \n## \n###\n---\ninput");
+//! assert_eq!(frames[3], "  *\n# *\n#**\n---\nswap v[1] v[2]");
+//! assert_eq!(frames.last().unwrap(), "  #\n ##\n###\n---\nend of pass");
+//! ```
+
+use crate::sort_analysis::trace::{Frame, SortEvent, Trace};
+use std::fmt::Debug;
+use std::fmt::Write;
+
+const BAR_WIDTH: f64 = 12.0;
+const CHART_HEIGHT: f64 = 160.0;
+const CAPTION_HEIGHT: f64 = 24.0;
+const PLAIN: &str = "steelblue";
+const COMPARED: &str = "orange";
+const MOVED: &str = "crimson";
+
+/// how a bar is drawn in a frame
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Plain,
+    Compared,
+    Moved,
+}
+
+impl Mark {
+    fn ascii(self) -> char {
+        match self {
+            Mark::Plain => '#',
+            Mark::Compared => '?',
+            Mark::Moved => '*',
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self {
+            Mark::Plain => PLAIN,
+            Mark::Compared => COMPARED,
+            Mark::Moved => MOVED,
+        }
+    }
+}
+
+/// maps keys linearly onto `0.0..=1.0`
+struct Scale {
+    min: f64,
+    max: f64,
+}
+
+impl Scale {
+    fn of<T, K: Fn(&T) -> f64>(values: &[T], key: &K) -> Scale {
+        let keys = values.iter().map(key);
+        let min = keys.clone().fold(f64::INFINITY, f64::min);
+        let max = keys.fold(f64::NEG_INFINITY, f64::max);
+        Scale { min, max }
+    }
+
+    fn unit(&self, x: f64) -> f64 {
+        if self.max > self.min {
+            ((x - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+fn marks<T>(len: usize, event: &Option<SortEvent<T>>) -> Vec<Mark> {
+    let mut marks = vec![Mark::Plain; len];
+    match event {
+        Some(SortEvent::Compare(i, j)) => {
+            marks[*i] = Mark::Compared;
+            marks[*j] = Mark::Compared;
+        }
+        Some(SortEvent::Swap(i, j)) => {
+            marks[*i] = Mark::Moved;
+            marks[*j] = Mark::Moved;
+        }
+        Some(SortEvent::Write(i, _)) => marks[*i] = Mark::Moved,
+        Some(SortEvent::PassBoundary) | None => {}
+    }
+    marks
+}
+
+/// one line describing the event of a frame
+pub fn caption<T: Debug>(event: &Option<SortEvent<T>>) -> String {
+    match event {
+        None => String::from("input"),
+        Some(SortEvent::Compare(i, j)) => format!("compare v[{}] v[{}]", i, j),
+        Some(SortEvent::Swap(i, j)) => format!("swap v[{}] v[{}]", i, j),
+        Some(SortEvent::Write(i, value)) => format!("write v[{}] = {:?}", i, value),
+        Some(SortEvent::PassBoundary) => String::from("end of pass"),
+    }
+}
+
+/// A frame as `rows` lines of bars, a base line and the caption. The smallest key gets one row,
+/// the largest all of them.
+pub fn ascii_frame<T: Debug, K: Fn(&T) -> f64>(frame: &Frame<T>, rows: usize, key: K) -> String {
+    let scale = Scale::of(&frame.values, &key);
+    draw_ascii(frame, rows, &key, &scale)
+}
+
+/// every frame of a trace drawn by [`ascii_frame`] on the scale of the input
+pub fn ascii_frames<T, K>(trace: &Trace<T>, rows: usize, key: K) -> Vec<String>
+where
+    T: Clone + Debug,
+    K: Fn(&T) -> f64,
+{
+    let scale = Scale::of(trace.initial(), &key);
+    trace
+        .frames()
+        .map(|frame| draw_ascii(&frame, rows, &key, &scale))
+        .collect()
+}
+
+fn draw_ascii<T: Debug, K: Fn(&T) -> f64>(
+    frame: &Frame<T>,
+    rows: usize,
+    key: &K,
+    scale: &Scale,
+) -> String {
+    let rows = rows.max(1);
+    let heights: Vec<usize> = frame
+        .values
+        .iter()
+        .map(|value| 1 + (scale.unit(key(value)) * (rows - 1) as f64).round() as usize)
+        .collect();
+    let marks = marks(frame.values.len(), &frame.event);
+    let mut out = String::new();
+    for row in (1..=rows).rev() {
+        for (&height, mark) in heights.iter().zip(marks.iter()) {
+            out.push(if height >= row { mark.ascii() } else { ' ' });
+        }
+        out.push('\n');
+    }
+    out.push_str(&"-".repeat(heights.len()));
+    out.push('\n');
+    out.push_str(&caption(&frame.event));
+    out
+}
+
+/// bar height in pixels, the smallest key still gets a visible bar
+fn bar_height(scale: &Scale, x: f64) -> f64 {
+    4.0 + scale.unit(x) * (CHART_HEIGHT - 4.0)
+}
+
+fn svg_open(out: &mut String, bars: usize) {
+    let width = BAR_WIDTH * bars.max(1) as f64;
+    let height = CHART_HEIGHT + CAPTION_HEIGHT;
+    let _ = writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">",
+        w = width,
+        h = height
+    );
+    let _ = writeln!(
+        out,
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>",
+        width, height
+    );
+}
+
+/// A frame as a still SVG picture with the caption below the bars.
+///
+/// # Example
+///
+/// ```
+/// use dsa_sport::sort_analysis::render::svg_frame;
+/// use dsa_sport::sort_analysis::trace::{Frame, SortEvent};
+/// let frame = Frame { values: vec![3, 1, 2], event: Some(SortEvent::Swap(0, 1)) };
+/// let svg = svg_frame(&frame, |&x| x as f64);
+/// assert!(svg.starts_with("<svg") && svg.trim_end().ends_with("</svg>"));
+/// assert_eq!(svg.matches("fill=\"crimson\"").count(), 2);
+/// assert!(svg.contains("swap v[0] v[1]"));
+/// ```
+pub fn svg_frame<T: Debug, K: Fn(&T) -> f64>(frame: &Frame<T>, key: K) -> String {
+    let scale = Scale::of(&frame.values, &key);
+    let marks = marks(frame.values.len(), &frame.event);
+    let mut out = String::new();
+    svg_open(&mut out, frame.values.len());
+    for (i, (value, mark)) in frame.values.iter().zip(marks.iter()).enumerate() {
+        let height = bar_height(&scale, key(value));
+        let _ = writeln!(
+            out,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>",
+            i as f64 * BAR_WIDTH + 1.0,
+            CHART_HEIGHT - height,
+            BAR_WIDTH - 2.0,
+            height,
+            mark.color()
+        );
+    }
+    let _ = writeln!(
+        out,
+        "<text x=\"4\" y=\"{:.1}\" font-family=\"monospace\" font-size=\"14\">{}</text>",
+        CHART_HEIGHT + CAPTION_HEIGHT - 6.0,
+        escape(&caption(&frame.event))
+    );
+    out.push_str("</svg>\n");
+    out
+}
+
+/// The whole trace as one SVG which shows a frame every `frame_ms` milliseconds and starts over
+/// at the end. Every bar animates its height and color, the browser does the rest.
+///
+/// # Example
+///
+/// ```
+/// use dsa_sport::sort_analysis::bub_sort::bubble_sort_improved_tracked;
+/// use dsa_sport::sort_analysis::render::animated_svg;
+/// use dsa_sport::sort_analysis::trace::record;
+/// let mut v = vec![3, 1, 2];
+/// let trace = record(&mut v, bubble_sort_improved_tracked);
+/// let svg = animated_svg(&trace, |&x| x as f64, 100);
+/// assert_eq!(svg.matches("<animate ").count(), 3 * 3);
+/// ```
+pub fn animated_svg<T, K>(trace: &Trace<T>, key: K, frame_ms: u64) -> String
+where
+    T: Clone + Debug,
+    K: Fn(&T) -> f64,
+{
+    let scale = Scale::of(trace.initial(), &key);
+    let len = trace.initial().len();
+    // heights and colors of every bar through all frames
+    let mut heights: Vec<Vec<f64>> = vec![Vec::new(); len];
+    let mut colors: Vec<Vec<&str>> = vec![Vec::new(); len];
+    let mut frames = 0;
+    for frame in trace.frames() {
+        let marks = marks(len, &frame.event);
+        for (i, value) in frame.values.iter().enumerate() {
+            heights[i].push(bar_height(&scale, key(value)));
+            colors[i].push(marks[i].color());
+        }
+        frames += 1;
+    }
+    let duration = frame_ms * frames as u64;
+    let mut out = String::new();
+    svg_open(&mut out, len);
+    for i in 0..len {
+        let ys: Vec<String> = heights[i]
+            .iter()
+            .map(|h| format!("{:.1}", CHART_HEIGHT - h))
+            .collect();
+        let hs: Vec<String> = heights[i].iter().map(|h| format!("{:.1}", h)).collect();
+        let _ = writeln!(
+            out,
+            "<rect x=\"{:.1}\" y=\"{}\" width=\"{:.1}\" height=\"{}\" fill=\"{}\">",
+            i as f64 * BAR_WIDTH + 1.0,
+            ys[0],
+            BAR_WIDTH - 2.0,
+            hs[0],
+            PLAIN
+        );
+        for (attribute, values) in [
+            ("y", ys.join(";")),
+            ("height", hs.join(";")),
+            ("fill", colors[i].join(";")),
+        ]
+        .iter()
+        {
+            let _ = writeln!(
+                out,
+                "  <animate attributeName=\"{}\" values=\"{}\" dur=\"{}ms\" calcMode=\"discrete\" repeatCount=\"indefinite\"/>",
+                attribute, values, duration
+            );
+        }
+        out.push_str("</rect>\n");
+    }
+    let _ = writeln!(
+        out,
+        "<text x=\"4\" y=\"{:.1}\" font-family=\"monospace\" font-size=\"14\">{} events, {} passes</text>",
+        CHART_HEIGHT + CAPTION_HEIGHT - 6.0,
+        trace.events().len(),
+        trace.passes()
+    );
+    out.push_str("</svg>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::bub_sort::bubble_sort_improved_tracked;
+    use crate::sort_analysis::render::{animated_svg, ascii_frame, ascii_frames, svg_frame};
+    use crate::sort_analysis::trace::{record, Frame, SortEvent};
+
+    #[test]
+    fn test_ascii_frames() {
+        let frame = Frame {
+            values: vec![1.0, 4.0, 2.5, 4.0],
+            event: Some(SortEvent::Compare(1, 3)),
+        };
+        assert_eq!(
+            ascii_frame(&frame, 3, |&x| x),
+            " ? ?\n ?#?\n#?#?\n----\ncompare v[1] v[3]"
+        );
+        let frame = Frame {
+            values: vec![7, 7],
+            event: Some(SortEvent::Write(0, 7)),
+        };
+        assert_eq!(
+            ascii_frame(&frame, 1, |&x| x as f64),
+            "*#\n--\nwrite v[0] = 7"
+        );
+
+        let mut sorted: Vec<u8> = (0..8).collect();
+        let trace = record(&mut sorted, bubble_sort_improved_tracked);
+        let frames = ascii_frames(&trace, 4, |&x| x as f64);
+        assert_eq!(frames.len(), 7 + 1 + 1);
+        assert!(frames[1].ends_with("compare v[1] v[0]"));
+        assert!(frames.iter().all(|f| !f.contains('*')));
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut v = vec!["pear", "fig", "apple"];
+        let trace = record(&mut v, bubble_sort_improved_tracked);
+        let len = |s: &&str| s.len() as f64;
+        let svg = animated_svg(&trace, len, 50);
+        let frames = trace.events().len() + 1;
+        assert!(svg.contains(&format!("dur=\"{}ms\"", 50 * frames)));
+        assert_eq!(svg.matches("<rect").count(), 1 + 3);
+        let first_fill = svg.lines().find(|l| l.contains("\"fill\"")).unwrap();
+        assert_eq!(first_fill.matches(';').count(), frames - 1);
+
+        let frame = Frame {
+            values: vec!["<a>"],
+            event: Some(SortEvent::Write(0, "<a>")),
+        };
+        assert!(svg_frame(&frame, len).contains("write v[0] = &quot;&lt;a&gt;&quot;"));
+    }
+}
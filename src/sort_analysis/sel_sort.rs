@@ -0,0 +1,64 @@
+//! # Selection Sort Algorithm
+//!
+//! Selection sort grows a sorted prefix by swapping the smallest element of the unsorted rest to
+//! its end. It always makes n(n-1)/2 comparisons but at most n-1 swaps, which makes it the
+//! algorithm of choice when writing an element is much more expensive than comparing two.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Selection Sort algorithm, not stable
+///
+/// Time complexity: O(n^2).
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::sel_sort::selection_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn selection_sort<T: PartialOrd>(v: &mut [T]) {
+    selection_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`selection_sort`] with a comparator, like [`slice::sort_by`]
+pub fn selection_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    selection_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`selection_sort`] by a key, like [`slice::sort_by_key`]
+pub fn selection_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    selection_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`selection_sort`] on a [`Tracked`] slice
+pub fn selection_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    for i in 0..v.len() {
+        let mut min = i;
+        for j in i + 1..v.len() {
+            if v.less(j, min) {
+                min = j;
+            }
+        }
+        if min != i {
+            v.swap(i, min);
+        }
+        v.pass();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::sel_sort::selection_sort;
+
+    #[test]
+    fn test_selection_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        selection_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut empty: Vec<i32> = vec![];
+        selection_sort(&mut empty);
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,199 @@
+//! # Shell Sort Algorithm
+//!
+//! Shell sort runs insertion sort on the elements `gap` apart for a decreasing sequence of gaps
+//! ending in 1. The large gaps move elements far in few steps, so the final plain insertion sort
+//! has little left to do. How fast it is depends entirely on the [`GapSequence`], from O(n^2) for
+//! Shell's original halving down to about O(n^(4/3)) for Sedgewick's sequence.
+
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+
+/// Gap sequences for [`shell_sort_with`], every one ends in 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapSequence {
+    /// n/2, n/4, ..., 1 (Shell 1959), O(n^2) worst case
+    Shell,
+    /// 1, 4, 13, 40, ... = (3^k - 1) / 2 up to n/3 (Knuth 1973), O(n^(3/2))
+    Knuth,
+    /// 1, 8, 23, 77, 281, ... = 4^k + 3 * 2^(k-1) + 1 (Sedgewick 1986), O(n^(4/3))
+    Sedgewick,
+    /// 1, 4, 10, 23, 57, 132, 301, 701 found by experiment (Ciura 2001), extended by a factor
+    /// of 2.25
+    Ciura,
+}
+
+impl GapSequence {
+    pub const ALL: [GapSequence; 4] = [
+        GapSequence::Shell,
+        GapSequence::Knuth,
+        GapSequence::Sedgewick,
+        GapSequence::Ciura,
+    ];
+
+    /// the gaps to use on `n` elements, largest first
+    /// ```
+    /// # use dsa_sport::sort_analysis::shell_sort::GapSequence;
+    /// assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+    /// assert_eq!(GapSequence::Knuth.gaps(100), vec![13, 4, 1]);
+    /// assert_eq!(GapSequence::Sedgewick.gaps(100), vec![77, 23, 8, 1]);
+    /// assert_eq!(GapSequence::Ciura.gaps(100), vec![57, 23, 10, 4, 1]);
+    /// ```
+    pub fn gaps(self, n: usize) -> Vec<usize> {
+        let mut gaps = Vec::new();
+        match self {
+            GapSequence::Shell => {
+                let mut gap = n / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                gaps.reverse();
+            }
+            GapSequence::Knuth => {
+                let mut gap = 1;
+                while gap == 1 || gap <= n / 3 {
+                    gaps.push(gap);
+                    gap = 3 * gap + 1;
+                }
+            }
+            GapSequence::Sedgewick => {
+                gaps.push(1);
+                let mut k = 1;
+                loop {
+                    let gap = (1usize << (2 * k)) + 3 * (1usize << (k - 1)) + 1;
+                    if gap >= n {
+                        break;
+                    }
+                    gaps.push(gap);
+                    k += 1;
+                }
+            }
+            GapSequence::Ciura => {
+                gaps.extend_from_slice(&[1, 4, 10, 23, 57, 132, 301, 701]);
+                let mut gap = 701 * 9 / 4;
+                while gap < n {
+                    gaps.push(gap);
+                    gap = gap * 9 / 4;
+                }
+            }
+        }
+        gaps.retain(|&gap| gap == 1 || gap < n);
+        gaps.reverse();
+        if gaps.is_empty() {
+            gaps.push(1);
+        }
+        gaps
+    }
+}
+
+impl std::fmt::Display for GapSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GapSequence::Shell => "Shell",
+            GapSequence::Knuth => "Knuth",
+            GapSequence::Sedgewick => "Sedgewick",
+            GapSequence::Ciura => "Ciura",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Shell Sort algorithm with Ciura's gaps, not stable
+///
+/// Time complexity: about O(n^(4/3)).
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::shell_sort::shell_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn shell_sort<T: PartialOrd>(v: &mut [T]) {
+    shell_sort_with(v, GapSequence::Ciura);
+}
+
+/// [`shell_sort`] with a comparator, like [`slice::sort_by`]
+pub fn shell_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    shell_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`shell_sort`] by a key, like [`slice::sort_by_key`]
+pub fn shell_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    shell_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`shell_sort`] on a [`Tracked`] slice
+pub fn shell_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    shell_sort_with_tracked(v, GapSequence::Ciura);
+}
+
+/// Shell Sort algorithm with a chosen gap sequence, not stable
+///
+/// # Example
+///
+/// ```
+/// use dsa_sport::sort_analysis::shell_sort::{shell_sort_with, GapSequence};
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// shell_sort_with(&mut v, GapSequence::Knuth);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn shell_sort_with<T: PartialOrd>(v: &mut [T], sequence: GapSequence) {
+    shell_sort_with_tracked(&mut Tracked::new(v, &mut Natural, &mut ()), sequence);
+}
+
+/// [`shell_sort_with`] on a [`Tracked`] slice
+pub fn shell_sort_with_tracked<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    sequence: GapSequence,
+) {
+    for gap in sequence.gaps(v.len()) {
+        for i in gap..v.len() {
+            let mut j = i;
+            while j >= gap && v.less(j, j - gap) {
+                v.swap(j - gap, j);
+                j -= gap;
+            }
+        }
+        v.pass();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort_analysis::shell_sort::{shell_sort, shell_sort_with, GapSequence};
+
+    #[test]
+    fn test_shell_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        shell_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13])
+    }
+
+    #[test]
+    fn test_every_gap_sequence() {
+        let sequences = [
+            GapSequence::Shell,
+            GapSequence::Knuth,
+            GapSequence::Sedgewick,
+            GapSequence::Ciura,
+        ];
+        for &sequence in sequences.iter() {
+            for n in 0..3000 {
+                let gaps = sequence.gaps(n);
+                assert_eq!(gaps.last(), Some(&1));
+                assert!(gaps.windows(2).all(|w| w[0] > w[1]));
+                assert!(gaps[0] == 1 || gaps[0] < n);
+            }
+            let mut v: Vec<u32> = (0..2000).map(|i| (i * 7919) % 2003).collect();
+            let mut expected = v.clone();
+            expected.sort_unstable();
+            shell_sort_with(&mut v, sequence);
+            assert_eq!(v, expected);
+        }
+        assert_eq!(
+            GapSequence::Ciura.gaps(2000),
+            vec![1577, 701, 301, 132, 57, 23, 10, 4, 1]
+        );
+    }
+}
@@ -0,0 +1,384 @@
+//! # Tim Sort Algorithm
+//!
+//! Tim Peters wrote TimSort for Python in 2002, Java, Android and Rust's stable sort followed.
+//! It starts from the observation that real data is rarely random but made of runs, and builds
+//! everything around them:
+//!
+//! - run detection: the slice is cut into maximal runs, ascending or strictly descending, the
+//!   descending ones are reversed in place
+//! - minrun: runs shorter than a minimum length between 32 and 64 are extended by binary
+//!   insertion sort, so that n / minrun is a power of two or just below one and the merges stay
+//!   balanced
+//! - merge stack invariants: runs wait on a stack whose lengths, read from the top, grow at least
+//!   like the Fibonacci numbers. That keeps the stack O(log n) deep and merges runs of similar
+//!   length, like merge sort does, while visiting every run only when it is pushed
+//! - galloping: when one run wins seven comparisons in a row the merge switches to exponential
+//!   search and moves whole blocks, so merging runs which barely overlap costs O(log n)
+//!   comparisons instead of O(n)
+//!
+//! This version keeps the ideas readable and leaves out some engineering of the original: the
+//! merge always buffers the left run, where the real one buffers the shorter run and merges from
+//! the back when that is the right one, and galloping always searches from the start of a run.
+
+use crate::sort_analysis::ins_sort::binary_insertion_sort_from;
+use crate::sort_analysis::merge_sort::Hole;
+use crate::sort_analysis::metrics::{By, ByKey, Natural, Observer, Order, Tracked};
+use core::cmp::Ordering;
+use core::ptr;
+
+/// consecutive wins of one run after which a merge starts galloping
+const MIN_GALLOP: usize = 7;
+
+/// Tim Sort algorithm, stable
+///
+/// Time complexity: O(n log n), O(n) when sorted or reversed. Extra space: O(n).
+///
+/// # Example
+///
+/// ```
+/// let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+/// dsa_sport::sort_analysis::tim_sort::tim_sort(&mut v);
+/// assert_eq!(v, vec![1,3,4,6,8,11,13])
+///```
+pub fn tim_sort<T: PartialOrd>(v: &mut [T]) {
+    tim_sort_tracked(&mut Tracked::new(v, &mut Natural, &mut ()));
+}
+
+/// [`tim_sort`] with a comparator, like [`slice::sort_by`]
+pub fn tim_sort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    tim_sort_tracked(&mut Tracked::new(v, &mut By(compare), &mut ()));
+}
+
+/// [`tim_sort`] by a key, like [`slice::sort_by_key`]
+pub fn tim_sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    tim_sort_tracked(&mut Tracked::new(v, &mut ByKey(key), &mut ()));
+}
+
+/// [`tim_sort`] on a [`Tracked`] slice
+pub fn tim_sort_tracked<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>) {
+    let len = v.len();
+    let min_run = min_run(len);
+    let mut stack = RunStack {
+        runs: Vec::new(),
+        buf: Vec::new(),
+        min_gallop: MIN_GALLOP,
+    };
+    let mut start = 0;
+    while start < len {
+        let mut run = count_run(v, start);
+        if run < min_run {
+            let forced = min_run.min(len - start);
+            binary_insertion_sort_from(&mut v.slice(start..start + forced), run);
+            run = forced;
+        }
+        stack.runs.push(Run { start, len: run });
+        start += run;
+        stack.collapse(v);
+    }
+    stack.force_collapse(v);
+}
+
+/// The shortest run worth merging for `n` elements: `n` itself below 64, otherwise a length
+/// between 32 and 64 such that `n / minrun` is a power of two or slightly less than one.
+fn min_run(mut n: usize) -> usize {
+    let mut rest = 0;
+    while n >= 64 {
+        rest |= n & 1;
+        n >>= 1;
+    }
+    n + rest
+}
+
+/// Length of the run starting at `start`. A strictly descending run is reversed, strictly so that
+/// reversing it cannot reorder equal elements.
+fn count_run<T, C: Order<T>, O: Observer<T>>(v: &mut Tracked<'_, T, C, O>, start: usize) -> usize {
+    let len = v.len();
+    if start + 1 >= len {
+        return len - start;
+    }
+    let mut end = start + 2;
+    if v.less(start + 1, start) {
+        while end < len && v.less(end, end - 1) {
+            end += 1;
+        }
+        v.reverse(start..end);
+    } else {
+        while end < len && !v.less(end, end - 1) {
+            end += 1;
+        }
+    }
+    end - start
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    len: usize,
+}
+
+/// The pending runs, the merge buffer and how eager merges are to gallop.
+struct RunStack<T> {
+    runs: Vec<Run>,
+    buf: Vec<T>,
+    min_gallop: usize,
+}
+
+impl<T> RunStack<T> {
+    /// Merge until the invariants hold again for the lengths `.., a, b, c` from the bottom to the
+    /// top: `a > b + c` and `b > c`. Checking `a > b + c` one level further down as well is the
+    /// fix for the bug de Gouw et al. found in 2015 with a formal proof attempt.
+    fn collapse<C: Order<T>, O: Observer<T>>(&mut self, v: &mut Tracked<'_, T, C, O>) {
+        while self.runs.len() > 1 {
+            let n = self.runs.len() - 2;
+            let len = |i: usize| self.runs[i].len;
+            if (n >= 1 && len(n - 1) <= len(n) + len(n + 1))
+                || (n >= 2 && len(n - 2) <= len(n - 1) + len(n))
+            {
+                // merge the middle run with the shorter of its neighbours
+                let at = if len(n - 1) < len(n + 1) { n - 1 } else { n };
+                self.merge_at(v, at);
+            } else if len(n) <= len(n + 1) {
+                self.merge_at(v, n);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// merge everything left on the stack once the input is used up
+    fn force_collapse<C: Order<T>, O: Observer<T>>(&mut self, v: &mut Tracked<'_, T, C, O>) {
+        while self.runs.len() > 1 {
+            let n = self.runs.len() - 2;
+            if n >= 1 && self.runs[n - 1].len < self.runs[n + 1].len {
+                self.merge_at(v, n - 1);
+            } else {
+                self.merge_at(v, n);
+            }
+        }
+    }
+
+    /// Merge the runs `i` and `i + 1` of the stack. The elements at the start of the left run
+    /// which are not greater than the first element of the right run are already in place, as
+    /// are the elements at the end of the right run which are not less than the last element of
+    /// the left run. Galloping finds both, only the rest is merged.
+    fn merge_at<C: Order<T>, O: Observer<T>>(&mut self, v: &mut Tracked<'_, T, C, O>, i: usize) {
+        let (left, right) = (self.runs[i], self.runs[i + 1]);
+        self.runs[i].len += right.len;
+        self.runs.remove(i + 1);
+        let mut v = v.slice(left.start..right.start + right.len);
+        let (mid, len) = (left.len, left.len + right.len);
+        let head = gallop(mid, |k| !v.less(mid, k));
+        if head == mid {
+            return;
+        }
+        let tail = gallop(right.len, |k| !v.less(len - 1 - k, mid - 1));
+        merge_lo(
+            &mut v.slice(head..len - tail),
+            mid - head,
+            &mut self.buf,
+            &mut self.min_gallop,
+        );
+    }
+}
+
+/// Number of leading positions of `0..len` for which `holds` is true, it must be true up to some
+/// position and false from there on. The probes at 0, 1, 3, 7, 15, ... find the answer up to a
+/// factor of two and a binary search narrows it down, so an answer of k costs about 2 log2 k
+/// probes instead of k.
+fn gallop<F: FnMut(usize) -> bool>(len: usize, mut holds: F) -> usize {
+    if len == 0 || !holds(0) {
+        return 0;
+    }
+    // every position below `low` holds, none from `high` on
+    let mut low = 1;
+    let mut probe = 1;
+    while probe < len && holds(probe) {
+        low = probe + 1;
+        probe = 2 * probe + 1;
+    }
+    let mut high = probe.min(len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if holds(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Merge the sorted runs `v[..mid]` and `v[mid..]` through a buffer holding the left run. It takes
+/// one element at a time until one run wins `min_gallop` times in a row and then gallops, moving
+/// the block of elements that win next in one go. Galloping that pays off lowers `min_gallop`,
+/// galloping that does not raises it.
+///
+/// Elements of the left run are reported with the index they were read from, the writes are
+/// reported once the merge is done.
+fn merge_lo<T, C: Order<T>, O: Observer<T>>(
+    v: &mut Tracked<'_, T, C, O>,
+    mid: usize,
+    buf: &mut Vec<T>,
+    min_gallop: &mut usize,
+) {
+    let len = v.len();
+    buf.reserve(mid);
+    let bytes = mid * core::mem::size_of::<T>();
+    let (v, offset, order, observer) = v.parts();
+    observer.allocate(bytes);
+    for i in 0..mid {
+        observer.read(offset + i);
+    }
+    let written;
+    unsafe {
+        let v_ptr = v.as_mut_ptr();
+        let buf_ptr = buf.as_mut_ptr();
+        ptr::copy_nonoverlapping(v_ptr, buf_ptr, mid);
+        // if a comparison panics the hole puts the rest of the left run back into `v`, it always
+        // fills the gap between the merged part and the rest of the right run exactly
+        let mut hole = Hole {
+            start: buf_ptr,
+            end: buf_ptr.add(mid),
+            dest: v_ptr,
+        };
+        let mut right = v_ptr.add(mid);
+        let right_end = v_ptr.add(len);
+        let mut less = |r: *const T, l: *const T| {
+            let (i, j) = (r.offset_from(v_ptr), l.offset_from(buf_ptr));
+            observer.compare(offset + i as usize, offset + j as usize);
+            order.less(&*r, &*l)
+        };
+        let (mut left_wins, mut right_wins) = (0, 0);
+        while hole.start < hole.end && right < right_end {
+            if left_wins < *min_gallop && right_wins < *min_gallop {
+                // ties are taken from the left run, which keeps the sort stable
+                if less(right, hole.start) {
+                    ptr::copy_nonoverlapping(right, hole.dest, 1);
+                    right = right.add(1);
+                    right_wins += 1;
+                    left_wins = 0;
+                } else {
+                    ptr::copy_nonoverlapping(hole.start, hole.dest, 1);
+                    hole.start = hole.start.add(1);
+                    left_wins += 1;
+                    right_wins = 0;
+                }
+                hole.dest = hole.dest.add(1);
+                continue;
+            }
+            let left_len = hole.end.offset_from(hole.start) as usize;
+            let from_left = gallop(left_len, |k| !less(right, hole.start.add(k)));
+            ptr::copy_nonoverlapping(hole.start, hole.dest, from_left);
+            hole.start = hole.start.add(from_left);
+            hole.dest = hole.dest.add(from_left);
+            if hole.start == hole.end {
+                break;
+            }
+            let right_len = right_end.offset_from(right) as usize;
+            let from_right = gallop(right_len, |k| less(right.add(k), hole.start));
+            // the block may overlap its destination, which lies before it
+            ptr::copy(right, hole.dest, from_right);
+            right = right.add(from_right);
+            hole.dest = hole.dest.add(from_right);
+            if from_left >= MIN_GALLOP || from_right >= MIN_GALLOP {
+                *min_gallop = min_gallop.saturating_sub(1).max(1);
+            } else {
+                *min_gallop += 2;
+                left_wins = 0;
+                right_wins = 0;
+            }
+        }
+        written = hole.dest.offset_from(v_ptr) as usize + hole.end.offset_from(hole.start) as usize;
+    }
+    for (i, value) in v[..written].iter().enumerate() {
+        observer.write(offset + i, value);
+    }
+    observer.release(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::metrics::measure;
+    use crate::sort_analysis::tim_sort::{gallop, min_run, tim_sort, tim_sort_tracked};
+
+    #[test]
+    fn test_tim_sort() {
+        let mut v = vec![4, 6, 1, 8, 11, 13, 3];
+        tim_sort(&mut v);
+        assert_eq!(v, vec![1, 3, 4, 6, 8, 11, 13]);
+        let mut rng = XorShift64::new(50);
+        for &n in [0, 1, 63, 64, 65, 1000, 5000].iter() {
+            // runs of random length, ascending, descending and constant, then random noise
+            let mut v: Vec<u32> = Vec::new();
+            while v.len() < n {
+                let run = rng.below(200) + 1;
+                let base = rng.below(1000) as u32;
+                match rng.below(4) {
+                    0 => v.extend((0..run as u32).map(|i| base + i)),
+                    1 => v.extend((0..run as u32).map(|i| base + 1000 - i)),
+                    2 => v.extend(std::iter::repeat_n(base, run)),
+                    _ => v.extend((0..run).map(|_| rng.below(2000) as u32)),
+                }
+            }
+            v.truncate(n);
+            let mut expected = v.clone();
+            expected.sort();
+            tim_sort(&mut v);
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn test_min_run_and_gallop() {
+        assert_eq!(min_run(63), 63);
+        assert_eq!(min_run(64), 32);
+        assert_eq!(min_run(65), 33);
+        assert_eq!(min_run(2048), 32);
+        assert_eq!(min_run(2049), 33);
+        for n in 64..5000 {
+            assert!((32..=64).contains(&min_run(n)));
+        }
+        for len in 0..40 {
+            for answer in 0..=len {
+                let mut probes = 0;
+                let found = gallop(len, |k| {
+                    probes += 1;
+                    k < answer
+                });
+                assert_eq!(found, answer);
+                assert!(probes <= 2 * (usize::BITS - answer.leading_zeros()) as usize + 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_galloping_merges_runs_that_barely_overlap() {
+        // two sorted halves, finding them takes n - 1 comparisons and merging them one element
+        // at a time up to n - 1 more
+        let n = 4096;
+        let halves: Vec<Vec<u32>> = vec![
+            // only the middle overlaps
+            (0..n / 2).chain(n / 2 - 4..n - 4).collect(),
+            // blocks of 128 from both halves take turns
+            (0..n)
+                .filter(|i| i / 128 % 2 == 0)
+                .chain((0..n).filter(|i| i / 128 % 2 == 1))
+                .collect(),
+        ];
+        for mut v in halves {
+            let metrics = measure(&mut v, tim_sort_tracked);
+            assert!(v.windows(2).all(|w| w[0] <= w[1]));
+            assert!(metrics.comparisons < n as u64 + n as u64 / 4, "{}", metrics);
+        }
+        let mut sorted: Vec<u32> = (0..n).collect();
+        assert_eq!(
+            measure(&mut sorted, tim_sort_tracked).comparisons,
+            n as u64 - 1
+        );
+        let mut reversed: Vec<u32> = (0..n).rev().collect();
+        let metrics = measure(&mut reversed, tim_sort_tracked);
+        assert_eq!(metrics.comparisons, n as u64 - 1);
+        assert_eq!(metrics.swaps, n as u64 / 2);
+    }
+}
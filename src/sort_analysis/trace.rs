@@ -0,0 +1,207 @@
+//! # Sort Traces
+//!
+//! A [`Trace`] is an [`Observer`] which writes down every step of a sort as a [`SortEvent`],
+//! together with the input it started from. Replaying the events one by one gives a [`Frame`]
+//! for every step, which the [`render`](crate::sort_analysis::render) module draws as ASCII bar
+//! charts or SVG.
+//!
+//! # Example
+//!
+//! ```
+//! use dsa_sport::sort_analysis::bub_sort::bubble_sort_improved_tracked;
+//! use dsa_sport::sort_analysis::trace::{record, SortEvent};
+//! let mut v = vec![1, 2, 3, 4];
+//! let trace = record(&mut v, bubble_sort_improved_tracked);
+//! assert_eq!(
+//!     trace.events(),
+//!     &[
+//!         SortEvent::Compare(1, 0),
+//!         SortEvent::Compare(2, 1),
+//!         SortEvent::Compare(3, 2),
+//!         SortEvent::PassBoundary,
+//!     ]
+//! );
+//! ```
+
+use crate::sort_analysis::metrics::{Natural, Observer, Tracked};
+
+/// One step of a sort, indices are positions in the sorted slice.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortEvent<T> {
+    /// `v[i]` was compared with `v[j]`, asking whether `v[i]` comes first
+    Compare(usize, usize),
+    Swap(usize, usize),
+    /// a value was stored into `v[i]`
+    Write(usize, T),
+    /// the end of a pass over the slice
+    PassBoundary,
+}
+
+/// The input of a sort and everything the sort did to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace<T> {
+    initial: Vec<T>,
+    events: Vec<SortEvent<T>>,
+}
+
+/// The slice after some steps of a sort and the step that led there, `None` for the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame<T> {
+    pub values: Vec<T>,
+    pub event: Option<SortEvent<T>>,
+}
+
+/// Sort `v` in its natural order and record every step, `sort` is any `_tracked` sort.
+pub fn record<T, S>(v: &mut [T], sort: S) -> Trace<T>
+where
+    T: PartialOrd + Clone,
+    S: FnOnce(&mut Tracked<'_, T, Natural, Trace<T>>),
+{
+    let mut trace = Trace::new(v);
+    sort(&mut Tracked::new(v, &mut Natural, &mut trace));
+    trace
+}
+
+impl<T: Clone> Trace<T> {
+    /// an empty trace of a sort starting from `initial`
+    pub fn new(initial: &[T]) -> Self {
+        Trace {
+            initial: initial.to_vec(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn initial(&self) -> &[T] {
+        &self.initial
+    }
+
+    pub fn events(&self) -> &[SortEvent<T>] {
+        &self.events
+    }
+
+    /// number of pass boundaries
+    pub fn passes(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, SortEvent::PassBoundary))
+            .count()
+    }
+
+    /// the input followed by the slice after every event
+    pub fn frames(&self) -> Frames<'_, T> {
+        Frames {
+            values: self.initial.clone(),
+            events: self.events.iter(),
+            started: false,
+        }
+    }
+
+    /// the slice after the last event, equal to the output of the sort
+    pub fn replay(&self) -> Vec<T> {
+        let mut values = self.initial.clone();
+        for event in self.events.iter() {
+            apply(&mut values, event);
+        }
+        values
+    }
+}
+
+impl<T: Clone> Observer<T> for Trace<T> {
+    fn compare(&mut self, i: usize, j: usize) {
+        self.events.push(SortEvent::Compare(i, j));
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.events.push(SortEvent::Swap(i, j));
+    }
+
+    fn write(&mut self, i: usize, value: &T) {
+        self.events.push(SortEvent::Write(i, value.clone()));
+    }
+
+    fn pass(&mut self) {
+        self.events.push(SortEvent::PassBoundary);
+    }
+}
+
+fn apply<T: Clone>(values: &mut [T], event: &SortEvent<T>) {
+    match event {
+        SortEvent::Swap(i, j) => values.swap(*i, *j),
+        SortEvent::Write(i, value) => values[*i] = value.clone(),
+        SortEvent::Compare(_, _) | SortEvent::PassBoundary => {}
+    }
+}
+
+/// Iterator over the frames of a [`Trace`], every frame clones the slice.
+pub struct Frames<'a, T> {
+    values: Vec<T>,
+    events: std::slice::Iter<'a, SortEvent<T>>,
+    started: bool,
+}
+
+impl<T: Clone> Iterator for Frames<'_, T> {
+    type Item = Frame<T>;
+
+    fn next(&mut self) -> Option<Frame<T>> {
+        if !self.started {
+            self.started = true;
+            return Some(Frame {
+                values: self.values.clone(),
+                event: None,
+            });
+        }
+        let event = self.events.next()?;
+        apply(&mut self.values, event);
+        Some(Frame {
+            values: self.values.clone(),
+            event: Some(event.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::bub_sort::bubble_sort_improved_tracked;
+    use crate::sort_analysis::divide_conquer::DivideAndConquer;
+    use crate::sort_analysis::ins_sort::binary_insertion_sort_tracked;
+    use crate::sort_analysis::radix_sort::msd_radix_sort_tracked;
+    use crate::sort_analysis::trace::{record, SortEvent};
+
+    #[test]
+    fn test_bubble_sort_improved_exits_after_one_pass() {
+        let mut sorted: Vec<u8> = (0..10).collect();
+        let trace = record(&mut sorted, bubble_sort_improved_tracked);
+        assert_eq!(trace.passes(), 1);
+        assert_eq!(trace.events().len(), 10);
+        let mut reversed: Vec<u8> = (0..10).rev().collect();
+        let trace = record(&mut reversed, bubble_sort_improved_tracked);
+        assert!(trace.passes() >= 9);
+        let swaps = trace
+            .events()
+            .iter()
+            .filter(|e| matches!(e, SortEvent::Swap(_, _)))
+            .count();
+        assert_eq!(swaps, 45);
+    }
+
+    #[test]
+    fn test_replay_ends_in_the_sorted_output() {
+        let mut rng = XorShift64::new(46);
+        let mut input: Vec<u32> = (0..300).map(|_| rng.below(100) as u32).collect();
+        input.extend((0..40).rev());
+        for algorithm in DivideAndConquer::all() {
+            let mut v = input.clone();
+            let trace = record(&mut v, |v| algorithm.sort_tracked(v));
+            assert_eq!(trace.initial(), &input[..]);
+            assert_eq!(trace.replay(), v, "{}", algorithm);
+            let frames: Vec<_> = trace.frames().collect();
+            assert_eq!(frames.len(), trace.events().len() + 1);
+            assert_eq!(frames.last().unwrap().values, v);
+        }
+        let mut v = input.clone();
+        assert_eq!(record(&mut v, msd_radix_sort_tracked).replay(), v);
+        let mut v = input;
+        assert_eq!(record(&mut v, binary_insertion_sort_tracked).replay(), v);
+    }
+}
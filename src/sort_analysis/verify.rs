@@ -0,0 +1,886 @@
+//! # Sort Verification
+//!
+//! Checks a sorting function against what it claims to be. The function sorts [`Tagged`]
+//! elements, a key plus the position the element started at, which compare by their key only.
+//! The tags show whether the output is a permutation of the input and whether equal keys kept
+//! their order. Adaptivity and memory are judged from measurements: a sort is adaptive if its
+//! work on sorted input grows in a lower [`Growth`] class than on random input, and in place if
+//! its peak auxiliary memory grows slower than n. Memory is only known for sorts with a tracked
+//! form, any other sort that claims to be in place is reported.
+//!
+//! [`registry`] lists every sort of this crate with the [`Properties`] it claims, the tests fail
+//! as soon as one claim does not hold.
+//!
+//! # Example
+//!
+//! ```
+//! use dsa_sport::sort_analysis::ins_sort::{insertion_sort, insertion_sort_tracked};
+//! use dsa_sport::sort_analysis::metrics::measure;
+//! use dsa_sport::sort_analysis::verify::{Properties, Subject};
+//!
+//! let insertion = Subject::tracked(
+//!     "insertion",
+//!     Properties::NONE.stable().in_place().adaptive(),
+//!     insertion_sort,
+//!     |v| measure(v, insertion_sort_tracked),
+//! );
+//! assert!(insertion.violations().is_empty());
+//! ```
+
+use crate::datastruct::list_struct::LinkedList;
+use crate::prng::XorShift64;
+use crate::sort_analysis::bench::{fit, Distribution, Growth};
+use crate::sort_analysis::bub_sort::{
+    bubble_sort, bubble_sort_improved, bubble_sort_improved_tracked, bubble_sort_tracked,
+};
+use crate::sort_analysis::bucket_sort::{bucket_sort, bucket_sort_tracked};
+use crate::sort_analysis::cocktail_sort::{cocktail_shaker_sort, cocktail_shaker_sort_tracked};
+use crate::sort_analysis::comb_sort::{comb_sort, comb_sort_tracked};
+use crate::sort_analysis::counting_sort::{counting_sort, counting_sort_tracked};
+use crate::sort_analysis::cycle_sort::{cycle_sort, cycle_sort_tracked};
+use crate::sort_analysis::divide_conquer::DivideAndConquer;
+use crate::sort_analysis::gnome_sort::{gnome_sort, gnome_sort_tracked};
+use crate::sort_analysis::ins_sort::{
+    binary_insertion_sort, binary_insertion_sort_tracked, insertion_sort, insertion_sort_tracked,
+};
+use crate::sort_analysis::merge_sort::MergeVariant;
+use crate::sort_analysis::metrics::{measure, SortMetrics};
+use crate::sort_analysis::odd_even_sort::{odd_even_sort, odd_even_sort_tracked};
+use crate::sort_analysis::pancake_sort::{pancake_sort, pancake_sort_tracked};
+use crate::sort_analysis::pigeonhole_sort::{pigeonhole_sort, pigeonhole_sort_tracked};
+use crate::sort_analysis::radix_key::RadixKey;
+use crate::sort_analysis::radix_sort::{
+    lsd_radix_sort, lsd_radix_sort_tracked, msd_radix_sort, msd_radix_sort_tracked,
+};
+use crate::sort_analysis::sel_sort::{selection_sort, selection_sort_tracked};
+use crate::sort_analysis::shell_sort::{shell_sort_with, shell_sort_with_tracked, GapSequence};
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+/// sizes for the correctness and stability checks
+const SIZES: [usize; 7] = [0, 1, 2, 3, 17, 64, 300];
+/// sizes the growth classes are fitted on
+const GROWTH_SIZES: [usize; 4] = [128, 256, 512, 1024];
+
+thread_local! {
+    static COMPARISONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// A key and the position it started at, equality and order look at the key only.
+#[derive(Debug, Clone, Copy)]
+pub struct Tagged {
+    pub key: u64,
+    pub index: usize,
+}
+
+impl Tagged {
+    /// tag every key with its position
+    pub fn tag(keys: &[u64]) -> Vec<Tagged> {
+        keys.iter()
+            .enumerate()
+            .map(|(index, &key)| Tagged { key, index })
+            .collect()
+    }
+}
+
+impl PartialEq for Tagged {
+    fn eq(&self, other: &Tagged) -> bool {
+        COMPARISONS.with(|c| c.set(c.get() + 1));
+        self.key == other.key
+    }
+}
+
+impl PartialOrd for Tagged {
+    fn partial_cmp(&self, other: &Tagged) -> Option<Ordering> {
+        COMPARISONS.with(|c| c.set(c.get() + 1));
+        self.key.partial_cmp(&other.key)
+    }
+}
+
+impl RadixKey for Tagged {
+    fn key_len(&self) -> usize {
+        self.key.key_len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.key.key_byte(index)
+    }
+}
+
+/// the key scaled into `[0, 1)` in the same order, for bucket sort
+impl From<Tagged> for f64 {
+    fn from(tagged: Tagged) -> f64 {
+        tagged.key as f64 / 2f64.powi(64)
+    }
+}
+
+/// Number of comparisons of [`Tagged`] elements on this thread while `f` runs.
+pub fn count_comparisons<F: FnOnce()>(f: F) -> u64 {
+    let before = COMPARISONS.with(Cell::get);
+    f();
+    COMPARISONS.with(Cell::get) - before
+}
+
+/// What a sort claims, or was found, to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Properties {
+    stable: bool,
+    in_place: bool,
+    adaptive: bool,
+}
+
+impl Properties {
+    /// not stable, not in place and not adaptive
+    pub const NONE: Properties = Properties {
+        stable: false,
+        in_place: false,
+        adaptive: false,
+    };
+
+    /// equal keys keep their order
+    pub fn stable(mut self) -> Self {
+        self.stable = true;
+        self
+    }
+
+    /// O(log n) auxiliary memory or less
+    pub fn in_place(mut self) -> Self {
+        self.in_place = true;
+        self
+    }
+
+    /// less work on sorted input, by a whole growth class
+    pub fn adaptive(mut self) -> Self {
+        self.adaptive = true;
+        self
+    }
+
+    pub fn is_stable(self) -> bool {
+        self.stable
+    }
+
+    pub fn is_in_place(self) -> bool {
+        self.in_place
+    }
+
+    pub fn is_adaptive(self) -> bool {
+        self.adaptive
+    }
+}
+
+impl std::fmt::Display for Properties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = [
+            (self.stable, "stable"),
+            (self.in_place, "in place"),
+            (self.adaptive, "adaptive"),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|&(_, name)| name)
+        .collect();
+        if names.is_empty() {
+            write!(f, "-")
+        } else {
+            write!(f, "{}", names.join(", "))
+        }
+    }
+}
+
+/// The properties observed on a sort, memory is unknown for sorts without a tracked form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Findings {
+    pub stable: bool,
+    pub in_place: Option<bool>,
+    pub adaptive: bool,
+}
+
+/// Ways a sort can fail its verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// the output of this input is out of order
+    Unsorted {
+        distribution: Distribution,
+        n: usize,
+    },
+    /// the output of this input lost, duplicated or changed elements
+    NotAPermutation {
+        distribution: Distribution,
+        n: usize,
+    },
+    /// a claimed property was found not to hold, or an unclaimed one to hold
+    Claim {
+        property: &'static str,
+        claimed: bool,
+    },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::Unsorted { distribution, n } => {
+                write!(f, "{} input of {} elements is not sorted", distribution, n)
+            }
+            Violation::NotAPermutation { distribution, n } => write!(
+                f,
+                "output of {} input of {} elements is not a permutation of it",
+                distribution, n
+            ),
+            Violation::Claim {
+                property,
+                claimed: true,
+            } => write!(f, "claims to be {} but is not", property),
+            Violation::Claim {
+                property,
+                claimed: false,
+            } => write!(f, "is {} but does not claim it", property),
+        }
+    }
+}
+
+impl std::error::Error for Violation {}
+
+/// Check that `sort` sorts every [`Distribution`] at a range of sizes into a permutation of the
+/// input.
+pub fn check_sorts<S: Fn(&mut [Tagged])>(sort: S) -> Result<(), Violation> {
+    let mut rng = XorShift64::new(48);
+    for &distribution in Distribution::ALL.iter() {
+        for &n in SIZES.iter() {
+            let input = Tagged::tag(&distribution.generate(n, &mut rng));
+            let mut output = input.clone();
+            sort(&mut output);
+            let mut seen = vec![false; n];
+            for element in output.iter() {
+                let index = element.index;
+                if index >= n || seen[index] || input[index].key != element.key {
+                    return Err(Violation::NotAPermutation { distribution, n });
+                }
+                seen[index] = true;
+            }
+            if output.windows(2).any(|w| w[0].key > w[1].key) {
+                return Err(Violation::Unsorted { distribution, n });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether `sort` kept equal keys in their order on inputs full of duplicates, only meaningful
+/// for a sort which passed [`check_sorts`].
+pub fn is_stable<S: Fn(&mut [Tagged])>(sort: S) -> bool {
+    let mut rng = XorShift64::new(48);
+    let mut inputs: Vec<Vec<u64>> = (0..4)
+        .map(|_| Distribution::FewUnique(8).generate(300, &mut rng))
+        .collect();
+    inputs.push((0..300).rev().map(|k| k / 4).collect());
+    inputs.push(Distribution::Sawtooth(20).generate(300, &mut rng));
+    inputs.iter().all(|keys| {
+        let mut v = Tagged::tag(keys);
+        sort(&mut v);
+        v.windows(2)
+            .all(|w| w[0].key != w[1].key || w[0].index < w[1].index)
+    })
+}
+
+/// Whether the work of `measure`, comparisons plus swaps, reads and writes, grows in a lower
+/// class on sorted input than on random input.
+pub fn is_adaptive<M: Fn(&mut [Tagged]) -> SortMetrics>(measure: M) -> bool {
+    let growth = |distribution: Distribution| {
+        let mut rng = XorShift64::new(48);
+        let points: Vec<(usize, f64)> = GROWTH_SIZES
+            .iter()
+            .map(|&n| {
+                let mut v = Tagged::tag(&distribution.generate(n, &mut rng));
+                let m = measure(&mut v);
+                (n, (m.comparisons + m.swaps + m.reads + m.writes) as f64)
+            })
+            .collect();
+        fit(&points).map(|fit| fit.growth)
+    };
+    growth(Distribution::Sorted) < growth(Distribution::Random)
+}
+
+/// Whether the peak auxiliary memory of `measure` stays zero or grows no faster than log n, on
+/// random, sorted and sawtooth input. Runs decide the buffer of the adaptive sorts, so random
+/// input alone can hide it.
+pub fn is_in_place<M: Fn(&mut [Tagged]) -> SortMetrics>(measure: M) -> bool {
+    let mut rng = XorShift64::new(48);
+    let distributions = [
+        Distribution::Random,
+        Distribution::Sorted,
+        Distribution::Sawtooth(8),
+    ];
+    distributions.iter().all(|&distribution| {
+        let points: Vec<(usize, f64)> = GROWTH_SIZES
+            .iter()
+            .map(|&n| {
+                let mut v = Tagged::tag(&distribution.generate(n, &mut rng));
+                (n, measure(&mut v).aux_memory as f64)
+            })
+            .collect();
+        fit(&points).is_none_or(|fit| fit.growth <= Growth::Logarithmic)
+    })
+}
+
+type SortFn = Box<dyn Fn(&mut [Tagged])>;
+type MeasureFn = Box<dyn Fn(&mut [Tagged]) -> SortMetrics>;
+
+/// A sort and the properties it claims.
+pub struct Subject {
+    name: String,
+    claims: Properties,
+    sort: SortFn,
+    measure: Option<MeasureFn>,
+}
+
+impl Subject {
+    /// A sort without a tracked form, its work is the number of comparisons and its memory is
+    /// not measured, so it cannot claim to be in place.
+    pub fn new<S>(name: impl Into<String>, claims: Properties, sort: S) -> Self
+    where
+        S: Fn(&mut [Tagged]) + 'static,
+    {
+        Subject {
+            name: name.into(),
+            claims,
+            sort: Box::new(sort),
+            measure: None,
+        }
+    }
+
+    /// `measure` is usually [`measure`] of the `_tracked` form of `sort`
+    pub fn tracked<S, M>(name: impl Into<String>, claims: Properties, sort: S, measure: M) -> Self
+    where
+        S: Fn(&mut [Tagged]) + 'static,
+        M: Fn(&mut [Tagged]) -> SortMetrics + 'static,
+    {
+        Subject {
+            measure: Some(Box::new(measure)),
+            ..Subject::new(name, claims, sort)
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn claims(&self) -> Properties {
+        self.claims
+    }
+
+    /// check the output and then observe every property
+    pub fn findings(&self) -> Result<Findings, Violation> {
+        check_sorts(&self.sort)?;
+        let stable = is_stable(&self.sort);
+        let (in_place, adaptive) = match &self.measure {
+            Some(measure) => (Some(is_in_place(measure)), is_adaptive(measure)),
+            None => (
+                None,
+                is_adaptive(|v: &mut [Tagged]| {
+                    let mut metrics = SortMetrics::default();
+                    metrics.comparisons = count_comparisons(|| (self.sort)(v));
+                    metrics
+                }),
+            ),
+        };
+        Ok(Findings {
+            stable,
+            in_place,
+            adaptive,
+        })
+    }
+
+    /// everything that is wrong with the sort, empty if it sorts and every claim holds
+    pub fn violations(&self) -> Vec<Violation> {
+        let findings = match self.findings() {
+            Ok(findings) => findings,
+            Err(violation) => return vec![violation],
+        };
+        let mut violations = Vec::new();
+        let mut compare = |property, claimed, found| {
+            if claimed != found {
+                violations.push(Violation::Claim { property, claimed });
+            }
+        };
+        compare("stable", self.claims.stable, findings.stable);
+        compare("adaptive", self.claims.adaptive, findings.adaptive);
+        // memory is only measured through a tracked form, without one the claim cannot hold
+        compare(
+            "in place",
+            self.claims.in_place,
+            findings.in_place.unwrap_or(false),
+        );
+        violations
+    }
+}
+
+impl std::fmt::Debug for Subject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subject")
+            .field("name", &self.name)
+            .field("claims", &self.claims)
+            .finish()
+    }
+}
+
+/// Stretch the keys over the whole `u64` range for the sort and shrink them back, bucket sort
+/// expects its input spread over `[0, 1)`.
+fn spread<S: FnMut(&mut [Tagged])>(v: &mut [Tagged], mut sort: S) {
+    let max = v.iter().map(|t| t.key).max().unwrap_or(0);
+    let scale = u64::MAX / (max + 1);
+    for t in v.iter_mut() {
+        t.key *= scale;
+    }
+    sort(v);
+    for t in v.iter_mut() {
+        t.key /= scale;
+    }
+}
+
+/// sort a slice through a [`LinkedList`] sort
+fn through_list(v: &mut [Tagged], sort: fn(&mut LinkedList<Tagged>)) {
+    let mut list: LinkedList<Tagged> = v.iter().copied().collect();
+    sort(&mut list);
+    for (slot, &element) in v.iter_mut().zip(list.iter()) {
+        *slot = element;
+    }
+}
+
+/// Every sort of [`sort_analysis`](super) and the sorts of [`LinkedList`] with the properties
+/// their documentation claims.
+pub fn registry() -> Vec<Subject> {
+    let none = Properties::NONE;
+    let mut all = vec![
+        Subject::tracked("bubble", none.stable().in_place(), bubble_sort, |v| {
+            measure(v, bubble_sort_tracked)
+        }),
+        Subject::tracked(
+            "bubble improved",
+            none.stable().in_place().adaptive(),
+            bubble_sort_improved,
+            |v| measure(v, bubble_sort_improved_tracked),
+        ),
+        Subject::tracked("selection", none.in_place(), selection_sort, |v| {
+            measure(v, selection_sort_tracked)
+        }),
+        Subject::tracked(
+            "insertion",
+            none.stable().in_place().adaptive(),
+            insertion_sort,
+            |v| measure(v, insertion_sort_tracked),
+        ),
+        Subject::tracked(
+            "binary insertion",
+            none.stable().in_place().adaptive(),
+            binary_insertion_sort,
+            |v| measure(v, binary_insertion_sort_tracked),
+        ),
+        Subject::tracked(
+            "cocktail shaker",
+            none.stable().in_place().adaptive(),
+            cocktail_shaker_sort,
+            |v| measure(v, cocktail_shaker_sort_tracked),
+        ),
+        Subject::tracked("comb", none.in_place(), comb_sort, |v| {
+            measure(v, comb_sort_tracked)
+        }),
+        Subject::tracked(
+            "gnome",
+            none.stable().in_place().adaptive(),
+            gnome_sort,
+            |v| measure(v, gnome_sort_tracked),
+        ),
+        Subject::tracked(
+            "odd-even",
+            none.stable().in_place().adaptive(),
+            odd_even_sort,
+            |v| measure(v, odd_even_sort_tracked),
+        ),
+        Subject::tracked("cycle", none.in_place(), cycle_sort, |v| {
+            measure(v, cycle_sort_tracked)
+        }),
+        Subject::tracked("pancake", none.in_place(), pancake_sort, |v| {
+            measure(v, pancake_sort_tracked)
+        }),
+    ];
+    for &sequence in GapSequence::ALL.iter() {
+        all.push(Subject::tracked(
+            format!("shell {}", sequence),
+            none.in_place(),
+            move |v| shell_sort_with(v, sequence),
+            move |v| measure(v, |v| shell_sort_with_tracked(v, sequence)),
+        ));
+    }
+    for algorithm in DivideAndConquer::all() {
+        let claims = match algorithm {
+            DivideAndConquer::Merge(MergeVariant::InPlace) => none.stable().in_place().adaptive(),
+            DivideAndConquer::Merge(_) | DivideAndConquer::Tim => none.stable().adaptive(),
+            DivideAndConquer::Pdq => none.in_place().adaptive(),
+            _ => none.in_place(),
+        };
+        all.push(Subject::tracked(
+            algorithm.to_string(),
+            claims,
+            move |v| algorithm.sort(v),
+            move |v| measure(v, |v| algorithm.sort_tracked(v)),
+        ));
+    }
+    all.push(Subject::tracked(
+        "counting",
+        none.stable(),
+        counting_sort,
+        |v| measure(v, counting_sort_tracked),
+    ));
+    all.push(Subject::tracked(
+        "pigeonhole",
+        none.stable(),
+        pigeonhole_sort,
+        |v| measure(v, pigeonhole_sort_tracked),
+    ));
+    all.push(Subject::tracked(
+        "lsd radix",
+        none.stable(),
+        lsd_radix_sort,
+        |v| measure(v, lsd_radix_sort_tracked),
+    ));
+    all.push(Subject::tracked(
+        "msd radix",
+        none.stable(),
+        msd_radix_sort,
+        |v| measure(v, msd_radix_sort_tracked),
+    ));
+    all.push(Subject::tracked(
+        "bucket",
+        none.stable(),
+        |v| spread(v, bucket_sort),
+        |v| {
+            let mut metrics = SortMetrics::default();
+            spread(v, |v| metrics = measure(v, bucket_sort_tracked));
+            metrics
+        },
+    ));
+    // the list sorts relink members instead of moving elements, there is no tracked form to
+    // measure their memory with
+    all.push(Subject::new(
+        "LinkedList::bubble_sort",
+        none.stable(),
+        |v| through_list(v, LinkedList::bubble_sort),
+    ));
+    all.push(Subject::new("LinkedList::merge_sort", none.stable(), |v| {
+        through_list(v, LinkedList::merge_sort)
+    }));
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prng::XorShift64;
+    use crate::sort_analysis::bub_sort::{
+        bubble_sort_by, bubble_sort_by_key, bubble_sort_improved_by, bubble_sort_improved_by_key,
+    };
+    use crate::sort_analysis::bucket_sort::bucket_sort;
+    use crate::sort_analysis::cocktail_sort::{
+        cocktail_shaker_sort_by, cocktail_shaker_sort_by_key,
+    };
+    use crate::sort_analysis::comb_sort::{comb_sort_by, comb_sort_by_key};
+    use crate::sort_analysis::counting_sort::counting_sort;
+    use crate::sort_analysis::cycle_sort::{cycle_sort_by, cycle_sort_by_key};
+    use crate::sort_analysis::divide_conquer::DivideAndConquer;
+    use crate::sort_analysis::gnome_sort::{gnome_sort_by, gnome_sort_by_key};
+    use crate::sort_analysis::heap_sort::{heap_sort_by, heap_sort_by_key};
+    use crate::sort_analysis::ins_sort::{
+        binary_insertion_sort_by, binary_insertion_sort_by_key, insertion_sort_by,
+        insertion_sort_by_key,
+    };
+    use crate::sort_analysis::intro_sort::{
+        intro_sort_by, intro_sort_by_key, intro_sort_with_limit,
+    };
+    use crate::sort_analysis::merge_sort::{
+        bottom_up_merge_sort_by, bottom_up_merge_sort_by_key, in_place_merge_sort_by,
+        in_place_merge_sort_by_key, natural_merge_sort_by, natural_merge_sort_by_key,
+        top_down_merge_sort_by, top_down_merge_sort_by_key,
+    };
+    use crate::sort_analysis::metrics::measure;
+    use crate::sort_analysis::odd_even_sort::{odd_even_sort_by, odd_even_sort_by_key};
+    use crate::sort_analysis::pancake_sort::{pancake_sort_by, pancake_sort_by_key};
+    use crate::sort_analysis::pdq_sort::{pdq_sort_by, pdq_sort_by_key};
+    use crate::sort_analysis::pigeonhole_sort::pigeonhole_sort;
+    use crate::sort_analysis::quick_sort::{
+        quick_sort_by, quick_sort_by_key, quick_sort_with, Partition, PivotChoice,
+    };
+    use crate::sort_analysis::radix_sort::{lsd_radix_sort_with, msd_radix_sort_with};
+    use crate::sort_analysis::sel_sort::{
+        selection_sort, selection_sort_by, selection_sort_by_key, selection_sort_tracked,
+    };
+    use crate::sort_analysis::shell_sort::{
+        shell_sort_by, shell_sort_by_key, shell_sort_with, GapSequence,
+    };
+    use crate::sort_analysis::tim_sort::{tim_sort_by, tim_sort_by_key};
+    use crate::sort_analysis::verify::{
+        check_sorts, is_stable, registry, Properties, Subject, Tagged, Violation,
+    };
+    use std::cmp::Ordering;
+
+    /// a value and its position in the input
+    type Element<V> = (V, usize);
+    type Compare<V> = fn(&Element<V>, &Element<V>) -> Ordering;
+    type Key<V> = fn(&Element<V>) -> i64;
+    type Form<V> = (
+        &'static str,
+        bool,
+        fn(&mut [Element<V>], Compare<V>),
+        fn(&mut [Element<V>], Key<V>),
+    );
+
+    /// every `_by` and `_by_key` form and whether it is stable
+    fn forms<V>() -> Vec<Form<V>> {
+        macro_rules! forms {
+            ($($stable:expr, $by:ident, $by_key:ident;)*) => {
+                vec![$((
+                    stringify!($by),
+                    $stable,
+                    $by as fn(&mut [Element<V>], Compare<V>),
+                    $by_key as fn(&mut [Element<V>], Key<V>),
+                )),*]
+            };
+        }
+        forms![
+            true, bubble_sort_by, bubble_sort_by_key;
+            true, bubble_sort_improved_by, bubble_sort_improved_by_key;
+            false, selection_sort_by, selection_sort_by_key;
+            true, insertion_sort_by, insertion_sort_by_key;
+            true, binary_insertion_sort_by, binary_insertion_sort_by_key;
+            false, shell_sort_by, shell_sort_by_key;
+            true, cocktail_shaker_sort_by, cocktail_shaker_sort_by_key;
+            false, comb_sort_by, comb_sort_by_key;
+            true, gnome_sort_by, gnome_sort_by_key;
+            true, odd_even_sort_by, odd_even_sort_by_key;
+            false, cycle_sort_by, cycle_sort_by_key;
+            false, pancake_sort_by, pancake_sort_by_key;
+            false, heap_sort_by, heap_sort_by_key;
+            false, quick_sort_by, quick_sort_by_key;
+            false, intro_sort_by, intro_sort_by_key;
+            true, top_down_merge_sort_by, top_down_merge_sort_by_key;
+            true, bottom_up_merge_sort_by, bottom_up_merge_sort_by_key;
+            true, natural_merge_sort_by, natural_merge_sort_by_key;
+            true, in_place_merge_sort_by, in_place_merge_sort_by_key;
+            true, tim_sort_by, tim_sort_by_key;
+            false, pdq_sort_by, pdq_sort_by_key;
+        ]
+    }
+
+    /// `output` must hold the elements of `expected`, which `slice::sort_by` produced, in an
+    /// order `compare` agrees with, a stable sort in exactly the same order
+    fn agrees<V>(
+        output: &[Element<V>],
+        expected: &[Element<V>],
+        stable: bool,
+        compare: impl Fn(&Element<V>, &Element<V>) -> Ordering,
+    ) -> bool {
+        if stable {
+            return output.iter().map(|e| e.1).eq(expected.iter().map(|e| e.1));
+        }
+        let mut positions: Vec<usize> = output.iter().map(|e| e.1).collect();
+        positions.sort_unstable();
+        positions.iter().copied().eq(0..expected.len())
+            && output
+                .iter()
+                .zip(expected)
+                .all(|(a, b)| compare(a, b) == Ordering::Equal)
+    }
+
+    /// run every form on `trials` inputs of length 0 to 40 against `slice::sort_by`
+    fn run_forms<V: Copy + std::fmt::Debug>(
+        trials: usize,
+        mut value: impl FnMut(&mut XorShift64) -> V,
+        compares: &[Compare<V>],
+        keys: &[Key<V>],
+    ) {
+        let mut rng = XorShift64::new(49);
+        for trial in 0..trials {
+            let input: Vec<Element<V>> = (0..trial % 41).map(|i| (value(&mut rng), i)).collect();
+            for &compare in compares {
+                let mut expected = input.clone();
+                expected.sort_by(compare);
+                for &(name, stable, by, _) in forms().iter() {
+                    let mut v = input.clone();
+                    by(&mut v, compare);
+                    assert!(
+                        agrees(&v, &expected, stable, compare),
+                        "{} on {:?}",
+                        name,
+                        input
+                    );
+                }
+                for algorithm in DivideAndConquer::all() {
+                    let mut v = input.clone();
+                    algorithm.sort_by(&mut v, compare);
+                    let stable = algorithm.is_stable();
+                    assert!(
+                        agrees(&v, &expected, stable, compare),
+                        "{} on {:?}",
+                        algorithm,
+                        input
+                    );
+                }
+            }
+            for &key in keys {
+                let mut expected = input.clone();
+                expected.sort_by_key(key);
+                let compare = |a: &Element<V>, b: &Element<V>| key(a).cmp(&key(b));
+                for &(name, stable, _, by_key) in forms().iter() {
+                    let mut v = input.clone();
+                    by_key(&mut v, key);
+                    assert!(
+                        agrees(&v, &expected, stable, compare),
+                        "{}_key on {:?}",
+                        name,
+                        input
+                    );
+                }
+                for algorithm in DivideAndConquer::all() {
+                    let mut v = input.clone();
+                    algorithm.sort_by_key(&mut v, key);
+                    let stable = algorithm.is_stable();
+                    assert!(
+                        agrees(&v, &expected, stable, compare),
+                        "{} on {:?}",
+                        algorithm,
+                        input
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_claim_holds() {
+        let mut failures = Vec::new();
+        for subject in registry() {
+            for violation in subject.violations() {
+                failures.push(format!("{}: {}", subject.name(), violation));
+            }
+        }
+        assert!(failures.is_empty(), "{:#?}", failures);
+    }
+
+    #[test]
+    fn test_wrong_claims_are_found() {
+        let claims = Properties::NONE.stable().in_place();
+        let selection = Subject::new("selection", claims, selection_sort);
+        assert_eq!(
+            selection.violations(),
+            vec![
+                Violation::Claim {
+                    property: "stable",
+                    claimed: true
+                },
+                Violation::Claim {
+                    property: "in place",
+                    claimed: true
+                }
+            ]
+        );
+        let measured = Subject::tracked("selection", claims, selection_sort, |v| {
+            measure(v, selection_sort_tracked)
+        });
+        assert_eq!(measured.violations().len(), 1);
+        let reverse = |v: &mut [Tagged]| v.reverse();
+        assert!(matches!(
+            check_sorts(reverse),
+            Err(Violation::Unsorted { .. })
+        ));
+        let forget = |v: &mut [Tagged]| {
+            v.sort_by_key(|t| t.key);
+            if v.len() > 1 {
+                v[0] = v[v.len() - 1];
+            }
+        };
+        assert!(matches!(
+            check_sorts(forget),
+            Err(Violation::NotAPermutation { .. })
+        ));
+        assert!(is_stable(|v: &mut [Tagged]| v.sort_by_key(|t| t.key)));
+        assert!(!is_stable(
+            |v: &mut [Tagged]| v.sort_unstable_by_key(|t| (t.key, usize::MAX - t.index))
+        ));
+    }
+
+    #[test]
+    fn test_by_forms_agree_with_slice_sort_by_on_floats() {
+        const SPECIAL: [f64; 10] = [
+            f64::NEG_INFINITY,
+            -1e300,
+            -1.5,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE,
+            0.5,
+            1.5,
+            1e300,
+            f64::INFINITY,
+        ];
+        let value = |rng: &mut XorShift64| {
+            if rng.below(2) == 0 {
+                SPECIAL[rng.below(SPECIAL.len())]
+            } else {
+                (rng.next_f64() * 8.0 - 4.0).round() / 2.0
+            }
+        };
+        let ascending: Compare<f64> = |a, b| a.0.partial_cmp(&b.0).unwrap();
+        let descending: Compare<f64> = |a, b| b.0.partial_cmp(&a.0).unwrap();
+        let magnitude: Compare<f64> = |a, b| a.0.abs().partial_cmp(&b.0.abs()).unwrap();
+        let floor: Key<f64> = |e| e.0.floor() as i64;
+        let negated: Key<f64> = |e| (e.0 as i64).saturating_neg();
+        run_forms(
+            120,
+            value,
+            &[ascending, descending, magnitude],
+            &[floor, negated],
+        );
+    }
+
+    #[test]
+    fn test_by_forms_agree_with_slice_sort_by_on_integers() {
+        let value = |rng: &mut XorShift64| rng.below(20) as i32 - 10;
+        let ascending: Compare<i32> = |a, b| a.0.cmp(&b.0);
+        let descending: Compare<i32> = |a, b| b.0.cmp(&a.0);
+        let parity: Key<i32> = |e| (e.0 % 2).into();
+        run_forms(120, value, &[ascending, descending], &[parity]);
+    }
+
+    #[test]
+    fn test_every_entry_point_is_total() {
+        for v in [vec![], vec![7u32]].iter() {
+            let check = |sort: &dyn Fn(&mut [u32])| {
+                let mut w = v.clone();
+                sort(&mut w);
+                assert_eq!(&w, v);
+            };
+            for &sequence in GapSequence::ALL.iter() {
+                check(&|w| shell_sort_with(w, sequence));
+            }
+            for &partition in Partition::ALL.iter() {
+                for &pivot in PivotChoice::ALL.iter() {
+                    check(&|w| quick_sort_with(w, partition, pivot));
+                }
+            }
+            check(&|w| intro_sort_with_limit(w, 0));
+            for &bits in [1, 2, 4, 8, 16].iter() {
+                check(&|w| lsd_radix_sort_with(w, bits));
+                check(&|w| msd_radix_sort_with(w, bits));
+            }
+            check(&counting_sort);
+            check(&pigeonhole_sort);
+            let mut floats: Vec<f64> = v.iter().map(|&x| f64::from(x) / 10.0).collect();
+            bucket_sort(&mut floats);
+            assert_eq!(floats.len(), v.len());
+            for &(name, _, by, by_key) in forms::<u32>().iter() {
+                let mut w: Vec<Element<u32>> = v.iter().map(|&x| (x, 0)).collect();
+                by(&mut w, |a, b| a.0.cmp(&b.0));
+                by_key(&mut w, |e| e.0.into());
+                assert_eq!(w.len(), v.len(), "{}", name);
+            }
+        }
+    }
+}